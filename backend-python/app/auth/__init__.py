@@ -3,6 +3,7 @@
 from app.auth.jwt import create_access_token, decode_token, get_user_id_from_token
 from app.auth.middleware import CurrentUser, OptionalUser, get_current_user, get_optional_user
 from app.auth.password import hash_password, verify_password
+from app.auth.refresh import issue_refresh_token, revoke_refresh_token, rotate_refresh_token
 
 __all__ = [
     "hash_password",
@@ -10,6 +11,9 @@ __all__ = [
     "create_access_token",
     "decode_token",
     "get_user_id_from_token",
+    "issue_refresh_token",
+    "rotate_refresh_token",
+    "revoke_refresh_token",
     "get_current_user",
     "get_optional_user",
     "CurrentUser",
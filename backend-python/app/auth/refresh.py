@@ -0,0 +1,74 @@
+"""Refresh token issue, rotation and revocation."""
+
+import hashlib
+import secrets
+from datetime import datetime, timedelta, timezone
+from uuid import UUID
+
+from sqlalchemy import select, update
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.config import get_settings
+from app.models import RefreshToken
+
+
+def hash_refresh_token(token: str) -> str:
+    """Hash stored in place of the token itself."""
+    return hashlib.sha256(token.encode("utf-8")).hexdigest()
+
+
+async def issue_refresh_token(db: AsyncSession, user_id: UUID) -> str:
+    """Create a refresh token for the user and return its plaintext (shown only once)."""
+    token = secrets.token_urlsafe(48)
+    db.add(
+        RefreshToken(
+            user_id=user_id,
+            token_hash=hash_refresh_token(token),
+            expires_at=datetime.now(timezone.utc)
+            + timedelta(days=get_settings().refresh_token_expiry_days),
+        )
+    )
+    await db.flush()
+    return token
+
+
+async def rotate_refresh_token(db: AsyncSession, token: str) -> tuple[UUID, str] | None:
+    """Revoke a valid refresh token and issue its replacement.
+
+    Returns (user_id, new token), or None when the token is unknown, expired or revoked.
+    Presenting an already rotated token revokes all of the user's refresh tokens, since
+    it means the token was copied.
+    """
+    stored = await db.scalar(
+        select(RefreshToken).where(RefreshToken.token_hash == hash_refresh_token(token))
+    )
+    now = datetime.now(timezone.utc)
+    if stored is None or stored.expires_at <= now:
+        return None
+    if stored.revoked_at is not None:
+        await revoke_user_refresh_tokens(db, stored.user_id)
+        return None
+
+    stored.revoked_at = now
+    return stored.user_id, await issue_refresh_token(db, stored.user_id)
+
+
+async def revoke_refresh_token(db: AsyncSession, token: str) -> bool:
+    """Revoke a refresh token; returns False if it was unknown or already revoked."""
+    stored = await db.scalar(
+        select(RefreshToken).where(RefreshToken.token_hash == hash_refresh_token(token))
+    )
+    if stored is None or stored.revoked_at is not None:
+        return False
+    stored.revoked_at = datetime.now(timezone.utc)
+    await db.flush()
+    return True
+
+
+async def revoke_user_refresh_tokens(db: AsyncSession, user_id: UUID) -> None:
+    """Revoke every active refresh token belonging to the user."""
+    await db.execute(
+        update(RefreshToken)
+        .where(RefreshToken.user_id == user_id, RefreshToken.revoked_at.is_(None))
+        .values(revoked_at=datetime.now(timezone.utc))
+    )
@@ -25,6 +25,7 @@ class Settings(BaseSettings):
     jwt_secret: str = "dev-secret-change-in-production"
     jwt_algorithm: str = "HS256"
     jwt_expiry_hours: int = 24
+    refresh_token_expiry_days: int = 30
 
     # Encryption (for storing API keys)
     encryption_key: str = "dev-encryption-key-change-in-prod"
@@ -33,12 +34,22 @@ class Settings(BaseSettings):
     cors_allowed_origins: str = "*"
 
     # AI Providers
-    default_ai_provider: Literal["claude", "openai", "ollama"] = "openai"
+    default_ai_provider: Literal["claude", "openai", "gemini", "ollama"] = "openai"
     anthropic_api_key: str | None = None
     openai_api_key: str | None = None
     openai_model: str = "gpt-5.2-thinking"
+    claude_model: str = "claude-3-sonnet-20240229"
+    gemini_api_key: str | None = None
+    gemini_model: str = "gemini-1.5-flash"
     ollama_base_url: str = "http://localhost:11434"
     ollama_model: str = "llama2"
+    ai_max_concurrent_calls: int = 4  # Per provider; further calls queue for a free slot
+    # USD per million tokens as model=input:output, comma-separated; unlisted models have no estimate
+    ai_token_prices: str = (
+        "gpt-5.2-thinking=1.25:10,gpt-4o-mini=0.15:0.6,"
+        "claude-3-sonnet-20240229=3:15,claude-3-5-haiku-20241022=0.8:4,"
+        "gemini-1.5-flash=0.075:0.3"
+    )
 
     # OpenAI handles both transcription (Whisper) and question generation
     # openai_api_key is defined in AI Providers section above
@@ -49,6 +60,9 @@ class Settings(BaseSettings):
     minio_secret_key: str = "minioadmin"
     minio_bucket: str = "quiz-avatars"
     minio_use_ssl: bool = False
+    s3_timeout_seconds: float = 10.0  # Per attempt, for each S3 operation
+    s3_max_retries: int = 2  # Further attempts after a transient S3 failure
+    s3_retry_backoff_seconds: float = 0.5  # Doubles after each failed attempt
 
     # Server
     host: str = "0.0.0.0"
@@ -59,6 +73,65 @@ class Settings(BaseSettings):
 
     # Quiz timing
     answer_timeout_grace_ms: int = 500  # 500ms grace period for answer submission timing
+    timing_histogram_bucket_ms: int = 1000  # Default bucket width for question response time histograms
+
+    # Numeric questions: award decaying partial credit up to twice the tolerance (else zero)
+    numeric_partial_credit: bool = True
+
+    # Wrong answers lose wrong_answer_penalty points when negative scoring is allowed; totals never drop below 0
+    allow_negative_scoring: bool = False
+    wrong_answer_penalty: int = 0
+
+    # Participant activity indicators
+    selecting_broadcast_interval_ms: int = 1000  # Minimum gap between selecting broadcasts per user
+    buzz_answer_window_seconds: int = 10  # How long the buzz winner has to answer in buzzer rounds
+    countdown_tick_seconds: int = 5  # Audio-cue events get a tick for each of a question's final N seconds
+    countdown_sync_interval_seconds: float = 0  # Broadcast time_remaining this often while a question is open; 0 disables
+    allow_answer_changes: bool = False  # Participants may resubmit until the reveal (not in lock or buzzer rounds); points are recomputed
+    # Starting a segment with no questions: "error", "skip" to the next segment that has some,
+    # or "wait" in waiting_for_questions until questions arrive (only while it is still recording)
+    empty_segment_start: Literal["error", "skip", "wait"] = "error"
+    auto_reveal_on_time_up: bool = False  # Reveal the answer when the question timer runs out
+    auto_reveal_when_all_answered: bool = False  # Reveal the answer once everyone expected to answer has
+    normalize_answer_distribution: bool = True  # Reveal distributions group answers differing only in case/whitespace
+    reveal_leaderboard_top_n: int = 10  # Later reveals send the top N plus entries that changed; 0 always sends full leaderboards
+
+    # WebSocket reconnection
+    ws_reconnect_token_ttl_seconds: int = 300  # Lifetime of reconnect tokens issued on join
+    ws_reconnect_window_seconds: float = 5.0  # Dropped clients still count as present this long before they're announced as left
+    ws_heartbeat_interval_seconds: int = 15  # How often the server pings each connection
+    ws_heartbeat_timeout_seconds: int = 30  # Connections that haven't answered a ping for this long are closed
+    ws_heartbeat_check_interval_seconds: int = 10  # How often connections are checked for missed pongs; 0 disables
+    ws_controller_cache_ttl_seconds: int = 30  # Reuse host/presenter checks for control messages; 0 disables
+    ws_send_timeout_seconds: float = 5.0  # Broadcast sends slower than this count as lag and trigger a resync; 0 disables
+    ws_max_connections: int = 0  # Upgrades beyond this many open WebSockets are refused with a 503; 0 means unlimited
+    ws_replay_buffer_size: int = 200  # Recent broadcasts kept per event so reconnecting clients can replay what they missed
+
+    # Maintenance
+    admin_usernames: str = ""  # Comma-separated usernames allowed to call /api/admin endpoints
+    orphan_cleanup_interval_seconds: int = 3600  # 0 disables the periodic orphan prune
+    join_code_ttl_hours: int = 72  # Events never started within this window stop accepting joins; 0 disables
+    event_duration_check_interval_seconds: int = 60  # How often live events are checked against max_duration_seconds; 0 disables
+
+    # Transcript storage
+    transcript_store_retries: int = 2  # Extra insert attempts for a transcript chunk before buffering it for the next store
+    transcript_store_retry_delay_ms: int = 200
+
+    # Question generation
+    max_question_text_length: int = 300
+    max_answer_length: int = 120
+    question_length_policy: Literal["truncate", "reject"] = "truncate"  # For text over the limits
+    enable_ai_quality_scoring: bool = False  # Default for events that don't set their own
+    ai_quality_min_score: float = 0.6  # Generated questions scoring below this are dropped
+    manual_question_generation_cooldown_seconds: int = 120  # Pause auto-generation after a manual question
+    auto_generate_fake_answers: bool = False  # Fill in distractors for manual questions that have none
+    fake_answer_cache_ttl_seconds: int = 86400  # How long identical questions reuse generated distractors
+    fake_answer_shortfall_retries: int = 1  # Extra AI calls when fewer distractors come back than requested
+    fallback_question_after_failures: int = 0  # Insert a fallback question after this many empty generations in a row; 0 disables
+    fallback_question_bank_path: str = ""  # JSON file of fallback questions; empty uses the built-in bank
+
+    # Presenter handoff
+    announce_upcoming_presenter: bool = True  # Preview the next presenter on a segment's final question
 
     # Mega quiz configuration
     mega_quiz_single_segment_mode: Literal["remix", "skip"] = "remix"
@@ -70,6 +143,22 @@ class Settings(BaseSettings):
             return ["*"]
         return [origin.strip() for origin in self.cors_allowed_origins.split(",")]
 
+    @property
+    def admin_usernames_list(self) -> list[str]:
+        """Parse admin usernames from comma-separated string."""
+        return [name.strip() for name in self.admin_usernames.split(",") if name.strip()]
+
+    @property
+    def ai_token_price_table(self) -> dict[str, tuple[float, float]]:
+        """Parse ai_token_prices into model -> (input, output) USD per million tokens."""
+        table = {}
+        for entry in self.ai_token_prices.split(","):
+            model, _, prices = entry.strip().partition("=")
+            input_price, _, output_price = prices.partition(":")
+            if model and input_price and output_price:
+                table[model.strip()] = (float(input_price), float(output_price))
+        return table
+
     @property
     def is_production(self) -> bool:
         """Check if running in production mode."""
@@ -1,5 +1,6 @@
 """FastAPI application entry point."""
 
+import asyncio
 import logging
 from contextlib import asynccontextmanager
 from typing import Annotated, Any
@@ -10,19 +11,91 @@ from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from app.config import get_settings
-from app.database import close_db, get_db
+from app.database import async_session_maker, close_db, get_db
+from app.services.event_expiry import delete_expired_events, find_overdue_events
+from app.services.orphan_cleanup import prune_orphaned_rows
 
 
 settings = get_settings()
 
 
+async def _orphan_cleanup_loop(interval_seconds: int) -> None:
+    """Periodically prune rows left behind by deleted segments and expired unused events."""
+    while True:
+        await asyncio.sleep(interval_seconds)
+        try:
+            async with async_session_maker() as db:
+                removed = await prune_orphaned_rows(db)
+            if any(removed.values()):
+                logging.info(f"Pruned orphaned rows: {removed}")
+        except Exception as e:
+            logging.error(f"Orphan cleanup failed: {e}")
+        try:
+            async with async_session_maker() as db:
+                expired = await delete_expired_events(db)
+            if expired:
+                logging.info(f"Deleted {expired} expired unused events")
+        except Exception as e:
+            logging.error(f"Expired event cleanup failed: {e}")
+
+
+async def _heartbeat_reaper_loop(interval_seconds: int) -> None:
+    """Periodically close WebSocket connections that stopped answering pings."""
+    from app.ws.hub import hub
+
+    while True:
+        await asyncio.sleep(interval_seconds)
+        try:
+            reaped = await hub.cleanup_stale_connections()
+            if reaped:
+                logging.info(f"Closed unresponsive connections: {reaped}")
+        except Exception as e:
+            logging.error(f"Heartbeat check failed: {e}")
+
+
+async def _event_duration_loop(interval_seconds: int) -> None:
+    """Periodically finalize live events that have run past their max duration."""
+    from app.ws.game_handler import finalize_overdue_event
+
+    while True:
+        await asyncio.sleep(interval_seconds)
+        try:
+            async with async_session_maker() as db:
+                for event in await find_overdue_events(db):
+                    logging.info(f"Finalizing event {event.id} after exceeding its max duration")
+                    await finalize_overdue_event(db, event)
+        except Exception as e:
+            logging.error(f"Event duration check failed: {e}")
+
+
 @asynccontextmanager
 async def lifespan(app: FastAPI):
     """Application lifespan handler for startup/shutdown."""
     # Startup
     settings.validate_production_settings() if settings.is_production else None
+    cleanup_task = None
+    if settings.orphan_cleanup_interval_seconds > 0:
+        cleanup_task = asyncio.create_task(
+            _orphan_cleanup_loop(settings.orphan_cleanup_interval_seconds)
+        )
+    duration_task = None
+    if settings.event_duration_check_interval_seconds > 0:
+        duration_task = asyncio.create_task(
+            _event_duration_loop(settings.event_duration_check_interval_seconds)
+        )
+    heartbeat_task = None
+    if settings.ws_heartbeat_check_interval_seconds > 0:
+        heartbeat_task = asyncio.create_task(
+            _heartbeat_reaper_loop(settings.ws_heartbeat_check_interval_seconds)
+        )
     yield
     # Shutdown
+    if cleanup_task:
+        cleanup_task.cancel()
+    if duration_task:
+        duration_task.cancel()
+    if heartbeat_task:
+        heartbeat_task.cancel()
     await close_db()
 
 
@@ -70,7 +143,7 @@ async def root_health() -> dict[str, str]:
 
 
 # Import and include routers
-from app.routes import auth, events, join, leaderboard, questions, segments
+from app.routes import admin, auth, events, join, leaderboard, questions, segments
 from app.ws import game_router
 
 app.include_router(auth.router, prefix="/api/auth", tags=["auth"])
@@ -79,6 +152,7 @@ app.include_router(join.router, prefix="/api", tags=["join"])
 app.include_router(segments.router, prefix="/api", tags=["segments"])
 app.include_router(questions.router, prefix="/api", tags=["questions"])
 app.include_router(leaderboard.router, prefix="/api", tags=["leaderboard"])
+app.include_router(admin.router, prefix="/api", tags=["admin"])
 app.include_router(game_router, prefix="/api", tags=["websocket"])
 
 
@@ -1,12 +1,30 @@
 """Database models."""
 
+from app.models.ai_usage import AIUsage
 from app.models.audio_chunk import AudioChunk
 from app.models.canvas import CanvasStroke
-from app.models.event import Event, EventMode, EventStatus, Segment, SegmentStatus
+from app.models.event import (
+    DONE_SEGMENT_STATUSES,
+    Event,
+    EventMode,
+    EventStatus,
+    EventVisibility,
+    NumericScoringMode,
+    RevealDetail,
+    RevealOrder,
+    Segment,
+    SegmentStatus,
+    SegmentWinnerOrder,
+    TimerDisplayMode,
+    can_transition_segment,
+)
+from app.models.invite import EventInvite
 from app.models.join_attempt import JoinAttempt, JoinAttemptStatus
-from app.models.participant import EventParticipant, JoinStatus, SegmentScore
+from app.models.participant import EventParticipant, JoinStatus, ParticipantAnswer, SegmentScore
 from app.models.processing_log import ProcessingLog
-from app.models.question import PresentationTranscript, Question
+from app.models.question import PresentationTranscript, Question, QuestionType, ToleranceMode
+from app.models.refresh_token import RefreshToken
+from app.models.team import Team
 from app.models.user import AvatarType, User, UserRole
 
 __all__ = [
@@ -14,25 +32,43 @@ __all__ = [
     "User",
     "UserRole",
     "AvatarType",
+    "RefreshToken",
     # Event
     "Event",
     "EventMode",
     "EventStatus",
+    "EventVisibility",
+    "RevealOrder",
+    "NumericScoringMode",
+    "RevealDetail",
+    "SegmentWinnerOrder",
+    "TimerDisplayMode",
     "Segment",
     "SegmentStatus",
+    "DONE_SEGMENT_STATUSES",
+    "can_transition_segment",
     # Participant
     "EventParticipant",
     "JoinStatus",
     "SegmentScore",
+    "ParticipantAnswer",
+    # Team
+    "Team",
+    # Invite
+    "EventInvite",
     # Join Attempt
     "JoinAttempt",
     "JoinAttemptStatus",
     # Question
     "Question",
+    "QuestionType",
+    "ToleranceMode",
     "PresentationTranscript",
     # Canvas
     "CanvasStroke",
     # Audio
     "AudioChunk",
     "ProcessingLog",
+    # AI usage
+    "AIUsage",
 ]
@@ -4,7 +4,8 @@ from datetime import datetime
 from enum import Enum
 from uuid import UUID, uuid4
 
-from sqlalchemy import Boolean, DateTime, ForeignKey, Integer, String, Text, func
+from sqlalchemy import BigInteger, Boolean, DateTime, Float, ForeignKey, Integer, String, Text, UniqueConstraint, func
+from sqlalchemy.dialects.postgresql import JSONB
 from sqlalchemy.orm import Mapped, mapped_column, relationship
 
 from app.database import Base
@@ -24,6 +25,50 @@ class EventStatus(str, Enum):
     FINISHED = "finished"
 
 
+class RevealOrder(str, Enum):
+    """Ordering of the answer distribution in reveal payloads."""
+
+    OPTION = "option"
+    COUNT = "count"
+
+
+class SegmentWinnerOrder(str, Enum):
+    """Ordering of segment winners in the event_complete payload."""
+
+    SEGMENT = "segment"
+    SCORE = "score"
+
+
+class RevealDetail(str, Enum):
+    """How much the reveal tells the room."""
+
+    FULL = "full"  # distribution plus correct answer
+    CORRECT_ONLY = "correct_only"  # correct answer without the distribution
+    PER_USER = "per_user"  # each participant privately learns only whether they were right
+
+
+class TimerDisplayMode(str, Enum):
+    """How clients show the question timer; the server timer itself is unchanged."""
+
+    COUNT_DOWN = "count_down"  # time remaining
+    COUNT_UP = "count_up"  # time elapsed
+
+
+class EventVisibility(str, Enum):
+    """Who can find and join an event."""
+
+    PUBLIC = "public"  # listed in discovery and joinable by code
+    UNLISTED = "unlisted"  # joinable by code only
+    PRIVATE = "private"  # joinable by invite only
+
+
+class NumericScoringMode(str, Enum):
+    """How numeric (estimation) questions are scored."""
+
+    TOLERANCE = "tolerance"
+    PROXIMITY = "proximity"
+
+
 class SegmentStatus(str, Enum):
     """Segment statuses."""
 
@@ -33,12 +78,82 @@ class SegmentStatus(str, Enum):
     QUIZ_READY = "quiz_ready"
     QUIZZING = "quizzing"
     COMPLETED = "completed"
+    SKIPPED = "skipped"  # Never run; counts as done for event completion
+
+    @property
+    def is_done(self) -> bool:
+        """True for statuses that no longer hold up event completion."""
+        return self in DONE_SEGMENT_STATUSES
+
+    def can_transition_to(self, target: "SegmentStatus") -> bool:
+        """Return True if a segment in this status may move to the target status.
+
+        Staying in the same status is always allowed so repeated requests are idempotent.
+        Leaving COMPLETED is only possible through the explicit resume flow.
+        """
+        if self == target:
+            return True
+        return target in SEGMENT_STATUS_TRANSITIONS.get(self, frozenset())
+
+
+DONE_SEGMENT_STATUSES: frozenset[SegmentStatus] = frozenset(
+    {SegmentStatus.COMPLETED, SegmentStatus.SKIPPED}
+)
+
+SEGMENT_STATUS_TRANSITIONS: dict[SegmentStatus, frozenset[SegmentStatus]] = {
+    # Manually added or imported questions let a segment be quizzed without recording
+    SegmentStatus.PENDING: frozenset(
+        {
+            SegmentStatus.RECORDING,
+            SegmentStatus.QUIZ_READY,
+            SegmentStatus.QUIZZING,
+            SegmentStatus.COMPLETED,
+            SegmentStatus.SKIPPED,
+        }
+    ),
+    SegmentStatus.RECORDING: frozenset(
+        {
+            SegmentStatus.RECORDING_PAUSED,
+            SegmentStatus.QUIZ_READY,
+            SegmentStatus.COMPLETED,
+            SegmentStatus.SKIPPED,
+        }
+    ),
+    SegmentStatus.RECORDING_PAUSED: frozenset(
+        {SegmentStatus.RECORDING, SegmentStatus.QUIZ_READY, SegmentStatus.COMPLETED, SegmentStatus.SKIPPED}
+    ),
+    SegmentStatus.QUIZ_READY: frozenset(
+        {SegmentStatus.RECORDING, SegmentStatus.QUIZZING, SegmentStatus.COMPLETED, SegmentStatus.SKIPPED}
+    ),
+    SegmentStatus.QUIZZING: frozenset({SegmentStatus.QUIZ_READY, SegmentStatus.COMPLETED}),
+    SegmentStatus.COMPLETED: frozenset(),
+    SegmentStatus.SKIPPED: frozenset(),
+}
+
+
+def can_transition_segment(current: str | None, target: str) -> bool:
+    """Check a transition between raw status strings as stored on the Segment row.
+
+    Unknown target values are rejected; an unknown current value is treated as PENDING.
+    """
+    try:
+        target_status = SegmentStatus(target)
+    except ValueError:
+        return False
+    try:
+        current_status = SegmentStatus(current) if current else SegmentStatus.PENDING
+    except ValueError:
+        current_status = SegmentStatus.PENDING
+    return current_status.can_transition_to(target_status)
 
 
 class Event(Base):
     """Event database model."""
 
     __tablename__ = "events"
+    __table_args__ = (
+        UniqueConstraint("host_id", "idempotency_key", name="uq_event_host_idempotency_key"),
+    )
 
     id: Mapped[UUID] = mapped_column(primary_key=True, default=uuid4)
     host_id: Mapped[UUID] = mapped_column(ForeignKey("users.id"), index=True)
@@ -51,10 +166,68 @@ class Event(Base):
     time_per_question: Mapped[int] = mapped_column(Integer, default=30)
     questions_to_generate: Mapped[int] = mapped_column(Integer, default=5)
     question_gen_interval_seconds: Mapped[int | None] = mapped_column(Integer, nullable=True)
+    reveal_order: Mapped[str] = mapped_column(String(20), default=RevealOrder.OPTION.value)
+    segment_winner_order: Mapped[str] = mapped_column(
+        String(20), default=SegmentWinnerOrder.SEGMENT.value
+    )
+    numeric_scoring_mode: Mapped[str] = mapped_column(
+        String(20), default=NumericScoringMode.TOLERANCE.value
+    )
+    reveal_detail: Mapped[str] = mapped_column(String(20), default=RevealDetail.FULL.value)
+    # Per-event override of settings.enable_ai_quality_scoring (None uses the global default)
+    enable_ai_quality_scoring: Mapped[bool | None] = mapped_column(Boolean, nullable=True)
+    # Per-event override of settings.ai_quality_min_score (None uses the global default)
+    question_quality_threshold: Mapped[float | None] = mapped_column(Float, nullable=True)
+    # Provider model for this event's AI calls, e.g. "gpt-4o" (None uses the provider's setting)
+    llm_model: Mapped[str | None] = mapped_column(String(100), nullable=True)
+    # Per-event overrides of settings.allow_negative_scoring / wrong_answer_penalty (None uses the global default)
+    allow_negative_scoring: Mapped[bool | None] = mapped_column(Boolean, nullable=True)
+    wrong_answer_penalty: Mapped[int | None] = mapped_column(Integer, nullable=True)
+    # When False, transcripts are only used in memory for question generation and never saved
+    store_transcripts: Mapped[bool] = mapped_column(Boolean, default=True)
+    # Mask emails and phone numbers in transcripts before storing them or sending them to AI
+    redact_pii: Mapped[bool] = mapped_column(Boolean, default=False)
+    visibility: Mapped[str] = mapped_column(String(20), default=EventVisibility.UNLISTED.value)
+    # Withhold leaderboards from a segment's final reveal until the host releases them
+    freeze_final_leaderboard: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Don't broadcast who has answered while a question is open; participation shows at reveal
+    hide_answer_progress: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Privately tell each answerer whether they were right as soon as they answer
+    instant_answer_feedback: Mapped[bool] = mapped_column(Boolean, default=False)
+    # New joiners wait in a pending state until the host approves them
+    require_join_approval: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Buzzer rounds: only the first participant to buzz may answer each question
+    buzzer_mode: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Server broadcasts countdown/time-up/reveal cues so clients play effects in sync
+    audio_cues: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Answers are picked provisionally and only count once the participant locks them in
+    require_answer_lock: Mapped[bool] = mapped_column(Boolean, default=False)
+    # The presenter answers and is scored like any participant instead of only running the quiz
+    host_can_answer: Mapped[bool] = mapped_column(Boolean, default=False)
+    # Participants pick a team and reveals/leaderboards include team standings
+    team_mode: Mapped[bool] = mapped_column(Boolean, default=False)
+    timer_display_mode: Mapped[str] = mapped_column(
+        String(20), default=TimerDisplayMode.COUNT_DOWN.value
+    )
+    # Seeds option shuffles and distractor ordering so reruns are identical (None is random)
+    rng_seed: Mapped[int | None] = mapped_column(BigInteger, nullable=True)
+    # Logo and colors clients use to theme the room; validated by EventBranding
+    branding: Mapped[dict | None] = mapped_column(JSONB, nullable=True)
     join_locked: Mapped[bool] = mapped_column(Boolean, default=False)
     join_locked_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     previous_status: Mapped[str | None] = mapped_column(String(50), nullable=True)
     ended_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
+    # When the first quiz started; max_duration_seconds counts from here
+    started_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
+    # Live events running longer than this are finalized automatically (None is unlimited)
+    max_duration_seconds: Mapped[int | None] = mapped_column(Integer, nullable=True)
+    # Join code stops working after this if the event was never started (None never expires)
+    expires_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
+    # Client-supplied Idempotency-Key from the creating request; replays return this event
+    idempotency_key: Mapped[str | None] = mapped_column(String(255), nullable=True)
+    # Unpublished edits saved by the client; promoted onto the event by publish
+    draft: Mapped[dict | None] = mapped_column(JSONB, nullable=True)
+    draft_updated_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     created_at: Mapped[datetime] = mapped_column(DateTime(timezone=True), server_default=func.now())
 
     # Relationships
@@ -79,12 +252,30 @@ class Segment(Base):
     )
     title: Mapped[str | None] = mapped_column(String(255), nullable=True)
     order_index: Mapped[int] = mapped_column(Integer, default=0)
+    # Multiplier applied to this segment's scores in the master leaderboard
+    weight: Mapped[float] = mapped_column(Float, default=1.0)
+    # Which quiz controls the segment presenter may use; the host can always use all of them
+    can_advance: Mapped[bool] = mapped_column(Boolean, default=True)
+    can_reveal: Mapped[bool] = mapped_column(Boolean, default=True)
+    can_end: Mapped[bool] = mapped_column(Boolean, default=True)
+    # AI summary of the stored transcript, cached by the summary endpoint
+    summary: Mapped[str | None] = mapped_column(Text, nullable=True)
+    # Presenter's slides/notes, given to the AI as context alongside the transcript
+    notes: Mapped[str | None] = mapped_column(Text, nullable=True)
+    # Per-segment override of event.store_transcripts (None follows the event)
+    store_transcripts: Mapped[bool | None] = mapped_column(Boolean, nullable=True)
     status: Mapped[str] = mapped_column(String(50), default=SegmentStatus.PENDING.value)
     recording_started_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     recording_ended_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     quiz_started_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     previous_status: Mapped[str | None] = mapped_column(String(50), nullable=True)
     ended_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
+    # Automatic generation pauses for a cooldown after a host adds a question by hand
+    last_manual_question_at: Mapped[datetime | None] = mapped_column(
+        DateTime(timezone=True), nullable=True
+    )
+    # Generation attempts in a row that produced no usable question
+    generation_failures: Mapped[int] = mapped_column(Integer, default=0)
     created_at: Mapped[datetime] = mapped_column(DateTime(timezone=True), server_default=func.now())
 
     # Relationships
@@ -17,6 +17,8 @@ class JoinStatus(str, Enum):
     WAITING_FOR_SEGMENT = "waiting_for_segment"
     ACTIVE_IN_QUIZ = "active_in_quiz"
     SEGMENT_COMPLETE = "segment_complete"
+    PENDING_APPROVAL = "pending_approval"  # Waiting for the host in events that require approval
+    REJECTED = "rejected"  # Turned away by the host; may not rejoin
 
 
 class EventParticipant(Base):
@@ -45,6 +47,10 @@ class EventParticipant(Base):
     join_status: Mapped[str] = mapped_column(
         String(50), default=JoinStatus.JOINED.value
     )
+    # Team chosen at join time in team-mode events
+    team_id: Mapped[UUID | None] = mapped_column(
+        ForeignKey("teams.id", ondelete="SET NULL"), nullable=True, index=True
+    )
 
     # Relationships
     event: Mapped["Event"] = relationship(back_populates="participants")
@@ -63,9 +69,35 @@ class SegmentScore(Base):
     score: Mapped[int] = mapped_column(Integer, default=0)
     questions_answered: Mapped[int] = mapped_column(Integer, default=0)
     questions_correct: Mapped[int] = mapped_column(Integer, default=0)
+    # Consecutive correct answers up to now; a wrong or missed answer resets it
+    current_streak: Mapped[int] = mapped_column(Integer, default=0)
     total_response_time_ms: Mapped[int] = mapped_column(BigInteger, default=0)
     created_at: Mapped[datetime] = mapped_column(DateTime(timezone=True), server_default=func.now())
 
 
+class ParticipantAnswer(Base):
+    """A participant's scored answer to a single question."""
+
+    __tablename__ = "participant_answers"
+    __table_args__ = (
+        UniqueConstraint("question_id", "participant_id", name="uq_participant_answer_question"),
+    )
+
+    id: Mapped[UUID] = mapped_column(primary_key=True, default=uuid4)
+    event_id: Mapped[UUID] = mapped_column(ForeignKey("events.id", ondelete="CASCADE"), index=True)
+    segment_id: Mapped[UUID] = mapped_column(ForeignKey("segments.id", ondelete="CASCADE"))
+    question_id: Mapped[UUID] = mapped_column(ForeignKey("questions.id", ondelete="CASCADE"))
+    participant_id: Mapped[UUID] = mapped_column(
+        ForeignKey("event_participants.id", ondelete="CASCADE"), index=True
+    )
+    selected_answer: Mapped[str] = mapped_column(String(500))
+    is_correct: Mapped[bool] = mapped_column(Boolean, default=False)
+    points: Mapped[int] = mapped_column(Integer, default=0)
+    response_time_ms: Mapped[int | None] = mapped_column(Integer, nullable=True)
+    answered_at: Mapped[datetime] = mapped_column(DateTime(timezone=True), server_default=func.now())
+    # False once the participant's score was reset; kept as history but not in totals
+    counts_toward_score: Mapped[bool] = mapped_column(Boolean, default=True)
+
+
 # Import at the bottom to avoid circular imports
 from app.models.event import Event  # noqa: E402, F401
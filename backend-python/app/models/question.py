@@ -1,6 +1,7 @@
 """Question and transcript database models."""
 
 from datetime import datetime
+from enum import Enum
 from uuid import UUID, uuid4
 
 from sqlalchemy import Boolean, DateTime, Float, ForeignKey, Integer, String, Text, func
@@ -10,6 +11,20 @@ from sqlalchemy.orm import Mapped, mapped_column, relationship
 from app.database import Base
 
 
+class QuestionType(str, Enum):
+    """Question answer types."""
+
+    MULTIPLE_CHOICE = "multiple_choice"
+    NUMERIC = "numeric"
+
+
+class ToleranceMode(str, Enum):
+    """How a numeric question's tolerance is interpreted."""
+
+    ABSOLUTE = "absolute"
+    PERCENT = "percent"
+
+
 class Question(Base):
     """Question database model."""
 
@@ -19,11 +34,26 @@ class Question(Base):
     segment_id: Mapped[UUID] = mapped_column(ForeignKey("segments.id"), index=True)
     question_text: Mapped[str] = mapped_column(Text)
     correct_answer: Mapped[str] = mapped_column(String(500))
+    question_type: Mapped[str] = mapped_column(
+        String(50), default=QuestionType.MULTIPLE_CHOICE.value
+    )
+    # Numeric questions: acceptable distance from the correct answer
+    tolerance: Mapped[float | None] = mapped_column(Float, nullable=True)
+    tolerance_mode: Mapped[str | None] = mapped_column(String(20), nullable=True)
+    # Bonus questions multiply the points earned for a correct answer
+    bonus_multiplier: Mapped[float] = mapped_column(Float, default=1.0)
+    # Seconds to answer this question; None uses the event's time_per_question
+    time_limit_seconds: Mapped[int | None] = mapped_column(Integer, nullable=True)
     fake_answers: Mapped[list[str] | None] = mapped_column(JSONB, nullable=True)
+    # Topics for per-tag accuracy and category leaderboards, normalized to lowercase
+    tags: Mapped[list[str] | None] = mapped_column(JSONB, nullable=True)
     order_index: Mapped[int] = mapped_column(Integer, default=0)
     is_ai_generated: Mapped[bool | None] = mapped_column(Boolean, nullable=True)
     source_transcript: Mapped[str | None] = mapped_column(Text, nullable=True)
+    explanation: Mapped[str | None] = mapped_column(Text, nullable=True)
     quality_score: Mapped[float | None] = mapped_column(Float, nullable=True)
+    # Thrown out mid-quiz by the presenter; excluded from scoring, stats and later replays
+    is_discarded: Mapped[bool] = mapped_column(Boolean, default=False)
     generated_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), nullable=True)
     created_at: Mapped[datetime | None] = mapped_column(DateTime(timezone=True), server_default=func.now())
 
@@ -1,8 +1,9 @@
 """API routes."""
 
-from app.routes import auth, events, join, leaderboard, questions, segments
+from app.routes import admin, auth, events, join, leaderboard, questions, segments
 
 __all__ = [
+    "admin",
     "auth",
     "events",
     "join",
@@ -0,0 +1,49 @@
+"""Admin maintenance routes."""
+
+from typing import Annotated
+
+from fastapi import APIRouter, Depends, HTTPException, status
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.auth import CurrentUser
+from app.config import get_settings
+from app.database import get_db
+from app.models import User
+from app.schemas import MergeUsersRequest
+from app.services.account_merge import merge_users
+from app.services.orphan_cleanup import prune_orphaned_rows
+
+router = APIRouter()
+
+
+@router.post("/admin/cleanup", response_model=dict[str, int])
+async def cleanup_orphans(
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> dict[str, int]:
+    """Prune rows left behind by deleted segments."""
+    if current_user.username not in get_settings().admin_usernames_list:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Admin access required")
+    return await prune_orphaned_rows(db)
+
+
+@router.post("/admin/users/merge", response_model=dict[str, int])
+async def merge_user_accounts(
+    request: MergeUsersRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> dict[str, int]:
+    """Merge a duplicate account into another, combining their scores, and delete it."""
+    if current_user.username not in get_settings().admin_usernames_list:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Admin access required")
+    if request.source_user_id == request.target_user_id:
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST, detail="Cannot merge a user into itself"
+        )
+
+    source = await db.get(User, request.source_user_id)
+    target = await db.get(User, request.target_user_id)
+    if source is None or target is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="User not found")
+
+    return await merge_users(db, source, target)
@@ -7,12 +7,22 @@ from fastapi import APIRouter, Depends, HTTPException, status
 from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
 
-from app.auth import CurrentUser, create_access_token, hash_password, verify_password
+from app.auth import (
+    CurrentUser,
+    create_access_token,
+    hash_password,
+    issue_refresh_token,
+    revoke_refresh_token,
+    rotate_refresh_token,
+    verify_password,
+)
 from app.database import get_db
 from app.models import User, UserRole
 from app.schemas.auth import (
     AuthResponse,
     LoginRequest,
+    RefreshResponse,
+    RefreshTokenRequest,
     RegisterRequest,
     UpdateProfileRequest,
     UserResponse,
@@ -49,11 +59,12 @@ async def register(
     db.add(user)
     await db.flush()
 
-    # Create token
+    # Create tokens
     token = create_access_token(user.id, user.role)
 
     return AuthResponse(
         token=token,
+        refresh_token=await issue_refresh_token(db, user.id),
         user=UserResponse.model_validate(user),
     )
 
@@ -77,10 +88,42 @@ async def login(
 
     return AuthResponse(
         token=token,
+        refresh_token=await issue_refresh_token(db, user.id),
         user=UserResponse.model_validate(user),
     )
 
 
+@router.post("/refresh", response_model=RefreshResponse)
+async def refresh(
+    request: RefreshTokenRequest,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> RefreshResponse:
+    """Exchange a refresh token for a new access token, rotating the refresh token."""
+    rotated = await rotate_refresh_token(db, request.refresh_token)
+    user = await db.get(User, rotated[0]) if rotated else None
+    if user is None:
+        # Keep any reuse revocation
+        await db.commit()
+        raise HTTPException(
+            status_code=status.HTTP_401_UNAUTHORIZED,
+            detail="Invalid or expired refresh token",
+        )
+
+    return RefreshResponse(
+        token=create_access_token(user.id, user.role),
+        refresh_token=rotated[1],
+    )
+
+
+@router.post("/logout", status_code=status.HTTP_204_NO_CONTENT)
+async def logout(
+    request: RefreshTokenRequest,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> None:
+    """Revoke a refresh token so it can no longer be used."""
+    await revoke_refresh_token(db, request.refresh_token)
+
+
 @router.get("/me", response_model=UserResponse)
 async def get_current_user_profile(current_user: CurrentUser) -> UserResponse:
     """Get the current authenticated user's profile."""
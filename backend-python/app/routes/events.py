@@ -3,19 +3,59 @@
 import secrets
 from datetime import datetime, timezone
 from typing import Annotated
-from uuid import uuid4
+from uuid import UUID, uuid4
 
-from fastapi import APIRouter, Depends, HTTPException, status
+from fastapi import APIRouter, Depends, Header, HTTPException, Query, status
+from pydantic import ValidationError
 from fastapi.responses import Response
-from sqlalchemy import func, select
+from sqlalchemy import and_, func, or_, select
 from sqlalchemy.ext.asyncio import AsyncSession
 
-from app.auth import CurrentUser
+from app.auth import CurrentUser, OptionalUser
 from app.database import get_db
-from app.models import Event, EventMode, EventStatus
-from app.schemas import CreateEventRequest, EventResponse, UpdateEventRequest
+from app.models import (
+    AIUsage,
+    CanvasStroke,
+    Event,
+    EventMode,
+    EventInvite,
+    EventParticipant,
+    EventStatus,
+    EventVisibility,
+    NumericScoringMode,
+    ParticipantAnswer,
+    Question,
+    RevealDetail,
+    RevealOrder,
+    Segment,
+    SegmentWinnerOrder,
+    Team,
+    TimerDisplayMode,
+)
+from app.schemas import (
+    AIModelUsage,
+    AIUsageResponse,
+    AssignPresentersRequest,
+    CanvasStrokePageResponse,
+    CanvasStrokeResponse,
+    CreateEventRequest,
+    CreateInviteRequest,
+    CreateTeamRequest,
+    EventConfigResponse,
+    EventDraftResponse,
+    EventInviteResponse,
+    EventResponse,
+    EventStateResponse,
+    HostEventConfigResponse,
+    MistakeResponse,
+    SaveEventDraftRequest,
+    SegmentResponse,
+    TeamResponse,
+    UpdateEventRequest,
+)
+from app.services.event_expiry import default_expires_at
 from app.services.export import export_event_data, export_to_json, export_to_csv
-from app.ws.hub import hub
+from app.ws.hub import QUESTION_OPEN_PHASES, hub
 
 router = APIRouter()
 EVENT_RESUME_DEBOUNCE: dict[str, datetime] = {}
@@ -39,13 +79,47 @@ async def list_events(
     return [EventResponse.model_validate(e) for e in events]
 
 
+@router.get("/events/public", response_model=list[EventResponse])
+async def list_public_events(
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> list[EventResponse]:
+    """Discovery list of public events that have not finished."""
+    result = await db.execute(
+        select(Event)
+        .where(
+            Event.visibility == EventVisibility.PUBLIC.value,
+            Event.status != EventStatus.FINISHED.value,
+        )
+        .order_by(Event.created_at.desc())
+    )
+    events = result.scalars().all()
+    return [EventResponse.model_validate(e) for e in events]
+
+
 @router.post("/quizzes", response_model=EventResponse, status_code=status.HTTP_201_CREATED)
 async def create_event(
     request: CreateEventRequest,
     current_user: CurrentUser,
     db: Annotated[AsyncSession, Depends(get_db)],
+    response: Response,
+    idempotency_key: Annotated[str | None, Header(alias="Idempotency-Key", max_length=255)] = None,
 ) -> EventResponse:
-    """Create a new event."""
+    """Create a new event.
+
+    A repeated request with the same Idempotency-Key returns the originally created event.
+    """
+    if idempotency_key:
+        result = await db.execute(
+            select(Event).where(
+                Event.host_id == current_user.id,
+                Event.idempotency_key == idempotency_key,
+            )
+        )
+        existing = result.scalar_one_or_none()
+        if existing:
+            response.status_code = status.HTTP_200_OK
+            return EventResponse.model_validate(existing)
+
     event = Event(
         id=uuid4(),
         host_id=current_user.id,
@@ -58,6 +132,33 @@ async def create_event(
         time_per_question=request.time_per_question or 30,
         questions_to_generate=request.questions_to_generate or 5,
         question_gen_interval_seconds=request.question_gen_interval_seconds,
+        reveal_order=request.reveal_order or RevealOrder.OPTION.value,
+        segment_winner_order=request.segment_winner_order or SegmentWinnerOrder.SEGMENT.value,
+        numeric_scoring_mode=request.numeric_scoring_mode or NumericScoringMode.TOLERANCE.value,
+        reveal_detail=request.reveal_detail or RevealDetail.FULL.value,
+        freeze_final_leaderboard=bool(request.freeze_final_leaderboard),
+        hide_answer_progress=bool(request.hide_answer_progress),
+        instant_answer_feedback=bool(request.instant_answer_feedback),
+        require_join_approval=bool(request.require_join_approval),
+        buzzer_mode=bool(request.buzzer_mode),
+        audio_cues=bool(request.audio_cues),
+        require_answer_lock=bool(request.require_answer_lock),
+        host_can_answer=bool(request.host_can_answer),
+        team_mode=bool(request.team_mode),
+        timer_display_mode=request.timer_display_mode or TimerDisplayMode.COUNT_DOWN.value,
+        max_duration_seconds=request.max_duration_seconds or None,
+        rng_seed=request.rng_seed,
+        enable_ai_quality_scoring=request.enable_ai_quality_scoring,
+        question_quality_threshold=request.question_quality_threshold,
+        llm_model=request.llm_model,
+        allow_negative_scoring=request.allow_negative_scoring,
+        wrong_answer_penalty=request.wrong_answer_penalty,
+        store_transcripts=request.store_transcripts is not False,
+        redact_pii=bool(request.redact_pii),
+        visibility=request.visibility or EventVisibility.UNLISTED.value,
+        branding=request.branding.model_dump(exclude_none=True) if request.branding else None,
+        expires_at=default_expires_at(),
+        idempotency_key=idempotency_key,
     )
     db.add(event)
     await db.flush()
@@ -67,25 +168,31 @@ async def create_event(
 @router.get("/events/{event_id}/segments")
 async def list_event_segments(
     event_id: str,
-    current_user: CurrentUser,
+    current_user: OptionalUser,
     db: Annotated[AsyncSession, Depends(get_db)],
+    session_token: Annotated[str | None, Header(alias="X-Session-Token")] = None,
 ) -> list[dict]:
-    """List all segments for an event."""
+    """List all segments for an event.
+
+    The host gets every column; participants (by session token) get the participant view.
+    """
     from app.models import Segment
-    from app.schemas import SegmentResponse
+    from app.schemas import ParticipantSegmentResponse, SegmentResponse
     
     result = await db.execute(select(Event).where(Event.id == event_id))
     event = result.scalar_one_or_none()
     if not event:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
-    if event.host_id != current_user.id:
+    is_host = current_user is not None and event.host_id == current_user.id
+    if not is_host and not await _get_requesting_participant(db, event, session_token):
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
     
     segments_result = await db.execute(
         select(Segment).where(Segment.event_id == event_id).order_by(Segment.order_index)
     )
     segments = segments_result.scalars().all()
-    return [SegmentResponse.model_validate(s).model_dump() for s in segments]
+    response_model = SegmentResponse if is_host else ParticipantSegmentResponse
+    return [response_model.model_validate(s).model_dump() for s in segments]
 
 
 @router.get("/quizzes/{event_id}", response_model=EventResponse)
@@ -104,6 +211,29 @@ async def get_event(
     return EventResponse.model_validate(event)
 
 
+@router.get("/events/{event_id}/config")
+async def get_event_config(
+    event_id: str,
+    current_user: OptionalUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    session_token: Annotated[str | None, Header(alias="X-Session-Token")] = None,
+) -> dict:
+    """All of an event's settings in one document for client initialization.
+
+    The host gets every setting; participants (by session token) get the safe subset.
+    """
+    result = await db.execute(select(Event).where(Event.id == event_id))
+    event = result.scalar_one_or_none()
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+    is_host = current_user is not None and event.host_id == current_user.id
+    if not is_host and not await _get_requesting_participant(db, event, session_token):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
+
+    response_model = HostEventConfigResponse if is_host else EventConfigResponse
+    return response_model.model_validate(event).model_dump()
+
+
 @router.put("/quizzes/{event_id}", response_model=EventResponse)
 async def update_event(
     event_id: str,
@@ -119,6 +249,14 @@ async def update_event(
     if event.host_id != current_user.id:
         raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
 
+    _apply_event_update(event, request)
+
+    await db.flush()
+    return EventResponse.model_validate(event)
+
+
+def _apply_event_update(event: Event, request: UpdateEventRequest) -> None:
+    """Copy the fields set on an update request onto the event."""
     if request.title is not None:
         event.title = request.title
     if request.description is not None:
@@ -133,7 +271,126 @@ async def update_event(
         event.questions_to_generate = request.questions_to_generate
     if request.question_gen_interval_seconds is not None:
         event.question_gen_interval_seconds = request.question_gen_interval_seconds
+    if request.reveal_order is not None:
+        event.reveal_order = request.reveal_order
+    if request.segment_winner_order is not None:
+        event.segment_winner_order = request.segment_winner_order
+    if request.numeric_scoring_mode is not None:
+        event.numeric_scoring_mode = request.numeric_scoring_mode
+    if request.reveal_detail is not None:
+        event.reveal_detail = request.reveal_detail
+    if request.freeze_final_leaderboard is not None:
+        event.freeze_final_leaderboard = request.freeze_final_leaderboard
+    if request.hide_answer_progress is not None:
+        event.hide_answer_progress = request.hide_answer_progress
+    if request.instant_answer_feedback is not None:
+        event.instant_answer_feedback = request.instant_answer_feedback
+    if request.require_join_approval is not None:
+        event.require_join_approval = request.require_join_approval
+    if request.buzzer_mode is not None:
+        event.buzzer_mode = request.buzzer_mode
+    if request.audio_cues is not None:
+        event.audio_cues = request.audio_cues
+    if request.require_answer_lock is not None:
+        event.require_answer_lock = request.require_answer_lock
+    if request.host_can_answer is not None:
+        event.host_can_answer = request.host_can_answer
+    if request.team_mode is not None:
+        event.team_mode = request.team_mode
+    if request.timer_display_mode is not None:
+        event.timer_display_mode = request.timer_display_mode
+    if request.max_duration_seconds is not None:
+        event.max_duration_seconds = request.max_duration_seconds or None
+    if "rng_seed" in request.model_fields_set:
+        # An explicit null goes back to unseeded shuffles
+        event.rng_seed = request.rng_seed
+    if request.enable_ai_quality_scoring is not None:
+        event.enable_ai_quality_scoring = request.enable_ai_quality_scoring
+    if "question_quality_threshold" in request.model_fields_set:
+        # An explicit null goes back to the global threshold
+        event.question_quality_threshold = request.question_quality_threshold
+    if "llm_model" in request.model_fields_set:
+        # An explicit null goes back to the provider's configured model
+        event.llm_model = request.llm_model
+    if request.allow_negative_scoring is not None:
+        event.allow_negative_scoring = request.allow_negative_scoring
+    if "wrong_answer_penalty" in request.model_fields_set:
+        # An explicit null goes back to the global penalty
+        event.wrong_answer_penalty = request.wrong_answer_penalty
+    if request.store_transcripts is not None:
+        event.store_transcripts = request.store_transcripts
+    if request.redact_pii is not None:
+        event.redact_pii = request.redact_pii
+    if request.visibility is not None:
+        event.visibility = request.visibility
+    if request.branding is not None:
+        event.branding = request.branding.model_dump(exclude_none=True)
 
+
+async def _get_hosted_event(db: AsyncSession, event_id: str, user_id) -> Event:
+    """Load an event and ensure the user is its host."""
+    result = await db.execute(select(Event).where(Event.id == event_id))
+    event = result.scalar_one_or_none()
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+    if event.host_id != user_id:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
+    return event
+
+
+@router.patch("/quizzes/{event_id}/draft", response_model=EventDraftResponse)
+async def save_event_draft(
+    event_id: str,
+    request: SaveEventDraftRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> EventDraftResponse:
+    """Store an in-progress draft without touching the published event (client auto-save)."""
+    event = await _get_hosted_event(db, event_id, current_user.id)
+
+    event.draft = request.draft
+    event.draft_updated_at = datetime.now(timezone.utc)
+    await db.flush()
+    return EventDraftResponse(
+        event_id=event.id, draft=event.draft, draft_updated_at=event.draft_updated_at
+    )
+
+
+@router.get("/quizzes/{event_id}/draft", response_model=EventDraftResponse)
+async def get_event_draft(
+    event_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> EventDraftResponse:
+    """Retrieve the saved draft for an event, if any."""
+    event = await _get_hosted_event(db, event_id, current_user.id)
+    return EventDraftResponse(
+        event_id=event.id, draft=event.draft, draft_updated_at=event.draft_updated_at
+    )
+
+
+@router.post("/quizzes/{event_id}/draft/publish", response_model=EventResponse)
+async def publish_event_draft(
+    event_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> EventResponse:
+    """Promote the saved draft onto the live event and clear it."""
+    event = await _get_hosted_event(db, event_id, current_user.id)
+    if not event.draft:
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="No draft to publish")
+
+    try:
+        update = UpdateEventRequest.model_validate(event.draft)
+    except ValidationError as e:
+        raise HTTPException(
+            status_code=status.HTTP_422_UNPROCESSABLE_ENTITY,
+            detail=f"Draft is not a valid event update: {e.errors()}",
+        )
+
+    _apply_event_update(event, update)
+    event.draft = None
+    event.draft_updated_at = None
     await db.flush()
     return EventResponse.model_validate(event)
 
@@ -257,6 +514,217 @@ async def clear_event_resume_state(
     return EventResponse.model_validate(event)
 
 
+@router.post(
+    "/events/{event_id}/invites",
+    response_model=EventInviteResponse,
+    status_code=status.HTTP_201_CREATED,
+)
+async def create_event_invite(
+    event_id: str,
+    request: CreateInviteRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> EventInviteResponse:
+    """Create an invite token allowing up to max_uses new participants to join (host only)."""
+    event = await _get_hosted_event(db, event_id, current_user.id)
+
+    invite = EventInvite(
+        id=uuid4(),
+        event_id=event.id,
+        token=secrets.token_urlsafe(24),
+        max_uses=request.max_uses,
+        use_count=0,
+    )
+    db.add(invite)
+    await db.flush()
+    return EventInviteResponse.model_validate(invite)
+
+
+@router.post(
+    "/events/{event_id}/teams",
+    response_model=TeamResponse,
+    status_code=status.HTTP_201_CREATED,
+)
+async def create_team(
+    event_id: str,
+    request: CreateTeamRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> TeamResponse:
+    """Add a team to a team-mode event (host only)."""
+    event = await _get_hosted_event(db, event_id, current_user.id)
+    if not event.team_mode:
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST, detail="Event is not in team mode"
+        )
+
+    name = request.name.strip()
+    existing = await db.scalar(
+        select(Team.id).where(Team.event_id == event.id, Team.name == name)
+    )
+    if existing:
+        raise HTTPException(
+            status_code=status.HTTP_409_CONFLICT, detail="A team with that name already exists"
+        )
+
+    team = Team(id=uuid4(), event_id=event.id, name=name)
+    db.add(team)
+    await db.flush()
+    return TeamResponse.model_validate(team)
+
+
+@router.get("/events/{event_id}/teams", response_model=list[TeamResponse])
+async def list_teams(
+    event_id: str,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> list[TeamResponse]:
+    """List an event's teams so joiners can pick one."""
+    result = await db.execute(
+        select(Team).where(Team.event_id == event_id).order_by(Team.created_at, Team.name)
+    )
+    return [TeamResponse.model_validate(team) for team in result.scalars().all()]
+
+
+@router.get("/events/{event_id}/canvas", response_model=CanvasStrokePageResponse)
+async def list_canvas_strokes(
+    event_id: UUID,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    after: UUID | None = None,
+    limit: Annotated[int, Query(ge=1, le=500)] = 100,
+) -> CanvasStrokePageResponse:
+    """Page through an event's canvas strokes, oldest first.
+
+    Strokes are ordered by creation time with the id as a tiebreaker, so passing the
+    last stroke of a page as ``after`` continues without gaps or overlap.
+    """
+    event = await db.get(Event, event_id)
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+
+    query = select(CanvasStroke).where(CanvasStroke.event_id == event.id)
+    if after is not None:
+        cursor = await db.get(CanvasStroke, after)
+        if cursor is None or cursor.event_id != event.id:
+            raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Unknown stroke cursor")
+        query = query.where(
+            or_(
+                CanvasStroke.created_at > cursor.created_at,
+                and_(CanvasStroke.created_at == cursor.created_at, CanvasStroke.id > cursor.id),
+            )
+        )
+    result = await db.execute(
+        query.order_by(CanvasStroke.created_at, CanvasStroke.id).limit(limit + 1)
+    )
+    strokes = result.scalars().all()
+    page = strokes[:limit]
+    return CanvasStrokePageResponse(
+        strokes=[CanvasStrokeResponse.model_validate(stroke) for stroke in page],
+        has_more=len(strokes) > limit,
+        next_cursor=page[-1].id if page else None,
+    )
+
+
+@router.put("/events/{event_id}/presenters", response_model=list[SegmentResponse])
+async def assign_presenters(
+    event_id: str,
+    request: AssignPresentersRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> list[SegmentResponse]:
+    """Assign presenters to several segments at once (host only).
+
+    Every target must be a participant in the event; nothing changes if any
+    segment or user is invalid.
+    """
+    event = await _get_hosted_event(db, event_id, current_user.id)
+
+    result = await db.execute(
+        select(Segment).where(
+            Segment.event_id == event.id, Segment.id.in_(request.assignments.keys())
+        )
+    )
+    segments = {segment.id: segment for segment in result.scalars().all()}
+    missing_segments = set(request.assignments) - set(segments)
+    if missing_segments:
+        raise HTTPException(
+            status_code=status.HTTP_404_NOT_FOUND,
+            detail=f"Segment not found in event: {sorted(str(s) for s in missing_segments)[0]}",
+        )
+
+    result = await db.execute(
+        select(EventParticipant.user_id).where(
+            EventParticipant.event_id == event.id,
+            EventParticipant.user_id.in_(set(request.assignments.values())),
+        )
+    )
+    participant_ids = set(result.scalars().all())
+    non_participants = set(request.assignments.values()) - participant_ids
+    if non_participants:
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST,
+            detail=f"User is not a participant in this event: {sorted(str(u) for u in non_participants)[0]}",
+        )
+
+    for segment_id, user_id in request.assignments.items():
+        segments[segment_id].presenter_user_id = user_id
+    await db.flush()
+    hub.invalidate_controller_cache(event.id)
+
+    session = hub.event_sessions.get(event.id)
+    if session and session.game_state.current_segment_id in request.assignments:
+        session.game_state.current_presenter_id = request.assignments[
+            session.game_state.current_segment_id
+        ]
+
+    return [
+        SegmentResponse.model_validate(segments[segment_id])
+        for segment_id in request.assignments
+    ]
+
+
+@router.get("/events/{event_id}/ai-usage", response_model=AIUsageResponse)
+async def get_event_ai_usage(
+    event_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> AIUsageResponse:
+    """AI token usage for the event with an estimated cost (host only)."""
+    from app.services.ai.usage import estimate_cost
+
+    event = await _get_hosted_event(db, event_id, current_user.id)
+
+    result = await db.execute(
+        select(
+            AIUsage.provider,
+            AIUsage.model,
+            func.count(AIUsage.id),
+            func.coalesce(func.sum(AIUsage.input_tokens), 0),
+            func.coalesce(func.sum(AIUsage.output_tokens), 0),
+        )
+        .where(AIUsage.event_id == event.id)
+        .group_by(AIUsage.provider, AIUsage.model)
+        .order_by(AIUsage.provider, AIUsage.model)
+    )
+    models = [
+        AIModelUsage(
+            provider=provider,
+            model=model,
+            calls=calls,
+            input_tokens=input_tokens,
+            output_tokens=output_tokens,
+            estimated_cost_usd=estimate_cost(model, input_tokens, output_tokens),
+        )
+        for provider, model, calls, input_tokens, output_tokens in result.all()
+    ]
+    return AIUsageResponse(
+        event_id=event.id,
+        input_tokens=sum(m.input_tokens for m in models),
+        output_tokens=sum(m.output_tokens for m in models),
+        estimated_cost_usd=sum(m.estimated_cost_usd or 0 for m in models),
+        models=models,
+    )
+
+
 @router.post("/events/{event_id}/join/lock", status_code=status.HTTP_200_OK)
 async def lock_event_join(
     event_id: str,
@@ -354,6 +822,178 @@ async def get_event_join_status(
     }
 
 
+@router.post("/events/{event_id}/start", status_code=status.HTTP_200_OK)
+async def start_event_game_rest(
+    event_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> dict:
+    """Start the quiz for the active segment without a WebSocket connection."""
+    from uuid import UUID
+
+    from app.ws.game_handler import start_event_game
+
+    await _get_hosted_event(db, event_id, current_user.id)
+    error = await start_event_game(db, UUID(event_id), current_user.id)
+    if error:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail=error)
+
+    session = await hub.get_or_create_session(UUID(event_id))
+    return {
+        "segment_id": str(session.game_state.current_segment_id),
+        "phase": session.game_state.quiz_phase.value,
+    }
+
+
+@router.post("/events/{event_id}/end", status_code=status.HTTP_200_OK)
+async def end_event_game_rest(
+    event_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> dict:
+    """End the quiz for the current segment without a WebSocket connection."""
+    from uuid import UUID
+
+    from app.ws.game_handler import end_event_game
+
+    await _get_hosted_event(db, event_id, current_user.id)
+    error = await end_event_game(db, UUID(event_id), current_user.id)
+    if error:
+        raise HTTPException(status_code=status.HTTP_409_CONFLICT, detail=error)
+
+    session = await hub.get_or_create_session(UUID(event_id))
+    return {
+        "segment_id": str(session.game_state.current_segment_id),
+        "phase": session.game_state.quiz_phase.value,
+    }
+
+
+async def _get_requesting_participant(
+    db: AsyncSession, event: Event, session_token: str | None
+) -> EventParticipant | None:
+    """Resolve the participant making the request from their join session token."""
+    if not session_token:
+        return None
+    result = await db.execute(
+        select(EventParticipant).where(
+            EventParticipant.event_id == event.id,
+            EventParticipant.session_token == session_token,
+        )
+    )
+    return result.scalar_one_or_none()
+
+
+@router.get("/events/{event_id}/state", response_model=EventStateResponse)
+async def get_event_state(
+    event_id: str,
+    current_user: OptionalUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    session_token: Annotated[str | None, Header(alias="X-Session-Token")] = None,
+) -> EventStateResponse:
+    """Current phase and question for a participant (by session token) or the host.
+
+    The correct answer is never included.
+    """
+    from app.ws.game_handler import _build_question_payload
+    from app.ws.messages import QuizPhase
+
+    result = await db.execute(select(Event).where(Event.id == event_id))
+    event = result.scalar_one_or_none()
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+
+    participant = await _get_requesting_participant(db, event, session_token)
+    participant_id = participant.id if participant else None
+    is_host = current_user is not None and current_user.id == event.host_id
+    if participant_id is None and not is_host:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not a participant in this event")
+
+    session = hub.event_sessions.get(event.id)
+    if not session:
+        return EventStateResponse(phase=QuizPhase.NOT_STARTED.value, question_index=0, total_questions=0)
+
+    state = session.game_state
+    response = EventStateResponse(
+        phase=state.quiz_phase.value,
+        question_index=state.current_question_index,
+        total_questions=state.total_questions,
+    )
+    if state.quiz_phase not in QUESTION_OPEN_PHASES or state.current_question_index >= len(state.questions):
+        return response
+
+    question_data = state.questions[state.current_question_index]
+    question = _build_question_payload(
+        question_id=question_data["id"],
+        question_text=question_data["text"],
+        correct_answer=question_data["correct_answer"],
+        fake_answers=question_data.get("fake_answers", []),
+        total_questions=state.total_questions,
+        time_limit=state.time_limit_seconds,
+        index=state.current_question_index,
+        question_type=question_data.get("question_type", "multiple_choice"),
+        bonus_multiplier=question_data.get("bonus_multiplier"),
+        question_started_at=state.question_started_at,
+        timer_display_mode=state.timer_display_mode,
+    )
+    response.question_id = question.question_id
+    response.question_text = question.text
+    response.question_type = question.question_type
+    response.answers = question.answers
+    response.answer_pending = (
+        participant_id is not None and participant_id not in state.answers_received
+    )
+    if state.question_started_at:
+        elapsed = (datetime.now(timezone.utc) - state.question_started_at).total_seconds()
+        response.time_remaining_seconds = max(state.time_limit_seconds - elapsed, 0.0)
+    return response
+
+
+@router.get("/events/{event_id}/my-mistakes", response_model=list[MistakeResponse])
+async def get_my_mistakes(
+    event_id: str,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    session_token: Annotated[str | None, Header(alias="X-Session-Token")] = None,
+) -> list[MistakeResponse]:
+    """Questions the requesting participant answered incorrectly, with the correct answers."""
+    result = await db.execute(select(Event).where(Event.id == event_id))
+    event = result.scalar_one_or_none()
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+
+    participant = await _get_requesting_participant(db, event, session_token)
+    if not participant:
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not a participant in this event")
+
+    filters = [
+        ParticipantAnswer.event_id == event.id,
+        ParticipantAnswer.participant_id == participant.id,
+        ParticipantAnswer.is_correct.is_(False),
+    ]
+    # Answers are scored on submission; hold back the open question until it is revealed
+    session = hub.event_sessions.get(event.id)
+    if session and session.game_state.quiz_phase in QUESTION_OPEN_PHASES:
+        if session.game_state.current_question_id:
+            filters.append(ParticipantAnswer.question_id != session.game_state.current_question_id)
+
+    result = await db.execute(
+        select(ParticipantAnswer, Question)
+        .join(Question, ParticipantAnswer.question_id == Question.id)
+        .where(*filters)
+        .order_by(ParticipantAnswer.answered_at)
+    )
+    return [
+        MistakeResponse(
+            question_id=question.id,
+            question_text=question.question_text,
+            correct_answer=question.correct_answer,
+            selected_answer=answer.selected_answer,
+            explanation=question.explanation,
+            answered_at=answer.answered_at,
+        )
+        for answer, question in result.all()
+    ]
+
+
 @router.get("/events/{event_id}/export")
 async def export_event(
     event_id: str,
@@ -7,24 +7,84 @@ from uuid import UUID, uuid4, uuid5, NAMESPACE_DNS
 
 from fastapi import APIRouter, Depends, HTTPException, status
 from fastapi.responses import JSONResponse
-from sqlalchemy import func, select
+from sqlalchemy import func, select, update
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from app.auth import CurrentUser
 from app.database import get_db
-from app.models import Event, EventParticipant, JoinAttempt, JoinAttemptStatus, JoinStatus
+from app.models import (
+    Event,
+    EventInvite,
+    EventParticipant,
+    EventVisibility,
+    JoinAttempt,
+    JoinAttemptStatus,
+    JoinStatus,
+)
 from app.schemas import (
     EventParticipantResponse,
     EventResponse,
     JoinEventRequest,
     JoinEventResponse,
 )
+from app.services.event_expiry import is_join_code_expired
 from app.services.join_queue import join_queue
-from app.ws.hub import hub
+from app.ws.hub import QUESTION_OPEN_PHASES, hub
 
 router = APIRouter()
 
 
+def _ensure_not_expired(event: Event) -> None:
+    """Reject join codes of events that were never started before they expired."""
+    if is_join_code_expired(event):
+        raise HTTPException(
+            status_code=status.HTTP_410_GONE,
+            detail="This join code has expired",
+        )
+
+
+async def _ensure_joinable_by_code(
+    db: AsyncSession, event: Event, invite_token: str | None
+) -> UUID | None:
+    """Private events are invite-only, so their join code alone is not enough.
+
+    Returns the invite to consume on join, or None for events that don't need one.
+    """
+    if event.visibility != EventVisibility.PRIVATE.value:
+        return None
+    if not invite_token:
+        raise HTTPException(
+            status_code=status.HTTP_403_FORBIDDEN,
+            detail="This event is invite-only",
+        )
+    result = await db.execute(
+        select(EventInvite).where(
+            EventInvite.event_id == event.id,
+            EventInvite.token == invite_token,
+        )
+    )
+    invite = result.scalar_one_or_none()
+    if not invite or invite.use_count >= invite.max_uses:
+        raise HTTPException(
+            status_code=status.HTTP_403_FORBIDDEN,
+            detail="Invalid or used-up invite",
+        )
+    return invite.id
+
+
+async def _consume_invite(db: AsyncSession, invite_id: UUID) -> bool:
+    """Count a join against the invite; False if its uses ran out in the meantime."""
+    result = await db.execute(
+        update(EventInvite)
+        .where(EventInvite.id == invite_id, EventInvite.use_count < EventInvite.max_uses)
+        .values(
+            use_count=EventInvite.use_count + 1,
+            last_used_at=datetime.now(timezone.utc),
+        )
+    )
+    return result.rowcount == 1
+
+
 async def _get_unique_display_name(
     db: AsyncSession, event_id: UUID, base_name: str
 ) -> str:
@@ -56,12 +116,15 @@ async def _get_unique_display_name(
 async def get_event_by_code(
     code: str,
     db: Annotated[AsyncSession, Depends(get_db)],
+    invite: str | None = None,
 ) -> EventResponse:
     """Get event by join code (public)."""
     result = await db.execute(select(Event).where(Event.join_code == code.upper()))
     event = result.scalar_one_or_none()
     if not event:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+    _ensure_not_expired(event)
+    await _ensure_joinable_by_code(db, event, invite)
     return EventResponse.model_validate(event)
 
 
@@ -71,6 +134,7 @@ async def _execute_join(
     device_id: UUID,
     join_start_time: datetime,
     db: AsyncSession,
+    invite_id: UUID | None = None,
 ) -> JoinEventResponse:
     """Execute the actual join logic (called from queue)."""
 
@@ -148,6 +212,15 @@ async def _execute_join(
     )
     existing = result.scalar_one_or_none()
 
+    if existing and existing.join_status == JoinStatus.REJECTED.value:
+        join_attempt.status = JoinAttemptStatus.FAILED.value
+        join_attempt.completed_at = datetime.now(timezone.utc)
+        await db.commit()
+        raise HTTPException(
+            status_code=status.HTTP_403_FORBIDDEN,
+            detail="The host declined your request to join this event",
+        )
+
     if existing:
         # Rejoin existing participant in the same event
         existing.last_heartbeat = datetime.now(timezone.utc)
@@ -165,6 +238,17 @@ async def _execute_join(
             session_token=token,
             display_name=existing.display_name,
             is_rejoining=True,
+            pending_approval=existing.join_status == JoinStatus.PENDING_APPROVAL.value,
+        )
+
+    # New participants spend one use of the invite; rejoining devices don't
+    if invite_id and not await _consume_invite(db, invite_id):
+        join_attempt.status = JoinAttemptStatus.FAILED.value
+        join_attempt.completed_at = datetime.now(timezone.utc)
+        await db.commit()
+        raise HTTPException(
+            status_code=status.HTTP_403_FORBIDDEN,
+            detail="Invalid or used-up invite",
         )
 
     # New participant - handle duplicate names
@@ -172,8 +256,6 @@ async def _execute_join(
     display_name = await _get_unique_display_name(db, event.id, base_name)
 
     # Determine initial join status based on event state
-    from app.models import JoinStatus
-
     initial_status = JoinStatus.JOINED.value
     is_late = False
 
@@ -200,7 +282,7 @@ async def _execute_join(
                 initial_status = JoinStatus.WAITING_FOR_SEGMENT.value
                 is_late = True
             # If joining during an active question or reveal, wait for next question
-            elif current_phase in (QuizPhase.SHOWING_QUESTION, QuizPhase.REVEALING_ANSWER):
+            elif current_phase in QUESTION_OPEN_PHASES or current_phase == QuizPhase.REVEALING_ANSWER:
                 initial_status = JoinStatus.WAITING_FOR_SEGMENT.value
                 is_late = True
         else:
@@ -208,6 +290,10 @@ async def _execute_join(
             initial_status = JoinStatus.WAITING_FOR_SEGMENT.value
             is_late = True
 
+    # Exclusive events hold new joiners until the host approves them
+    if event.require_join_approval:
+        initial_status = JoinStatus.PENDING_APPROVAL.value
+
     session_token = secrets.token_urlsafe(32)
     participant = EventParticipant(
         id=uuid4(),
@@ -236,6 +322,7 @@ async def _execute_join(
         session_token=session_token,
         display_name=participant.display_name,
         is_rejoining=False,
+        pending_approval=initial_status == JoinStatus.PENDING_APPROVAL.value,
     )
 
 
@@ -243,6 +330,7 @@ async def _execute_join(
 async def join_event(
     request: JoinEventRequest,
     db: Annotated[AsyncSession, Depends(get_db)],
+    invite: str | None = None,
 ) -> JoinEventResponse:
     """Join an event as anonymous participant (with queue and race condition protection)."""
     join_start_time = datetime.now(timezone.utc)
@@ -258,10 +346,14 @@ async def join_event(
     event = result.scalar_one_or_none()
     if not event:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+    _ensure_not_expired(event)
+    invite_id = await _ensure_joinable_by_code(db, event, invite)
     
     # Use join queue to prevent race conditions for simultaneous scans
     async def execute_join_with_db(db_session: AsyncSession) -> JoinEventResponse:
-        return await _execute_join(request, event, device_id, join_start_time, db_session)
+        return await _execute_join(
+            request, event, device_id, join_start_time, db_session, invite_id=invite_id
+        )
     
     return await join_queue.enqueue_join(event.id, device_id, execute_join_with_db, db)
 
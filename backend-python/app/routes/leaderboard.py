@@ -1,14 +1,19 @@
 """Leaderboard routes."""
 
 from typing import Annotated
+from uuid import UUID
 
-from fastapi import APIRouter, Depends
+from fastapi import APIRouter, Depends, HTTPException, status
 from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
 
+from app.auth import CurrentUser
 from app.database import get_db
-from app.models import EventParticipant, SegmentScore
-from app.schemas import LeaderboardEntry
+from app.models import Event, EventParticipant, SegmentScore
+from app.schemas import EventParticipantResponse, LeaderboardEntry
+from app.services.question_tags import tag_scores
+from app.services.scoring import get_weighted_event_scores, reset_participant_score
+from app.ws.hub import hub
 
 router = APIRouter()
 
@@ -17,17 +22,23 @@ router = APIRouter()
 async def get_event_leaderboard(
     event_id: str,
     db: Annotated[AsyncSession, Depends(get_db)],
+    tag: str | None = None,
 ) -> list[LeaderboardEntry]:
-    """Get master leaderboard for an event."""
+    """Get master leaderboard for an event, weighting each segment's scores.
+
+    With ``tag`` it is a category leaderboard: points earned on questions with that tag.
+    """
     result = await db.execute(
-        select(EventParticipant)
-        .where(EventParticipant.event_id == event_id)
-        .order_by(
-            EventParticipant.total_score.desc(),
-            EventParticipant.total_response_time_ms.asc(),
-        )
+        select(EventParticipant).where(EventParticipant.event_id == event_id)
+    )
+    if tag:
+        weighted_scores = await tag_scores(db, UUID(event_id), tag)
+    else:
+        weighted_scores = await get_weighted_event_scores(db, UUID(event_id))
+    participants = sorted(
+        result.scalars().all(),
+        key=lambda p: (-weighted_scores.get(p.id, 0), p.total_response_time_ms),
     )
-    participants = result.scalars().all()
 
     return [
         LeaderboardEntry(
@@ -35,7 +46,7 @@ async def get_event_leaderboard(
             user_id=p.id,
             username=p.display_name,
             avatar_url=p.avatar_url,
-            score=p.total_score,
+            score=weighted_scores.get(p.id, 0),
             is_late_joiner=p.is_late_joiner,
             response_time_ms=p.total_response_time_ms,
         )
@@ -72,3 +83,44 @@ async def get_segment_leaderboard(
         )
         for i, (score, participant) in enumerate(rows)
     ]
+
+
+@router.post(
+    "/events/{event_id}/participants/{participant_id}/reset-score",
+    response_model=EventParticipantResponse,
+)
+async def reset_score(
+    event_id: UUID,
+    participant_id: UUID,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> EventParticipantResponse:
+    """Zero a participant's scores for an event and broadcast the updated leaderboard."""
+    from app.ws.game_handler import _get_event_leaderboard, _get_team_leaderboard
+    from app.ws.messages import LeaderboardMessage
+
+    event = await db.get(Event, event_id)
+    if not event or event.host_id != current_user.id:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+
+    result = await db.execute(
+        select(EventParticipant).where(
+            EventParticipant.id == participant_id,
+            EventParticipant.event_id == event_id,
+        )
+    )
+    participant = result.scalar_one_or_none()
+    if not participant:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Participant not found")
+
+    await reset_participant_score(db, participant)
+
+    session = hub.event_sessions.get(event_id)
+    rankings = await _get_event_leaderboard(db, event_id, session)
+    team_rankings = await _get_team_leaderboard(db, event_id) if event.team_mode else []
+    await hub.broadcast(
+        event_id,
+        LeaderboardMessage(rankings=rankings, team_rankings=team_rankings).model_dump(mode="json"),
+    )
+
+    return EventParticipantResponse.model_validate(participant)
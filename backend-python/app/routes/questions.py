@@ -1,39 +1,177 @@
 """Question routes."""
 
-from typing import Annotated
-from uuid import uuid4
+from datetime import datetime, timezone
+from typing import Annotated, Literal
+from uuid import UUID, uuid4
 
-from fastapi import APIRouter, Depends, HTTPException, status
-from sqlalchemy import func, select
+from fastapi import APIRouter, Depends, Header, HTTPException, Query, Response, status
+from sqlalchemy import delete, func, or_, select
 from sqlalchemy.ext.asyncio import AsyncSession
 
-from app.auth import CurrentUser
+from app.auth import CurrentUser, OptionalUser
 from app.database import get_db
-from app.models import Event, Question, Segment
+from app.models import Event, ParticipantAnswer, Question, Segment
+from app.config import get_settings
+from app.services.export import export_segment_questions, export_to_json, questions_to_csv
+from app.services.question_limits import QuestionTooLongError, enforce_question_limits
+from app.services.question_tags import normalize_tags, tag_accuracy
+from app.services.response_timing import percentile, timing_histogram
 from app.schemas import (
+    BulkDeleteQuestionsRequest,
+    BulkDeleteResult,
     BulkImportQuestionsRequest,
     BulkImportResult,
     CreateQuestionRequest,
+    FakeAnswerPreviewResponse,
+    ParticipantQuestionPageResponse,
+    ParticipantQuestionResponse,
+    QuestionPageResponse,
     QuestionResponse,
+    QuestionTimingResponse,
+    TagAccuracyResponse,
     UpdateQuestionRequest,
 )
 
 router = APIRouter()
 
 
-@router.get("/segments/{segment_id}/questions", response_model=list[QuestionResponse])
+def _limit_question_lengths(
+    question_text: str, correct_answer: str, fake_answers: list[str] | None
+) -> tuple[str, str, list[str] | None]:
+    """Apply the configured text limits, turning a rejection into a 422."""
+    try:
+        return enforce_question_limits(question_text, correct_answer, fake_answers)
+    except QuestionTooLongError as e:
+        raise HTTPException(status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=str(e))
+
+
+async def _fill_fake_answers(
+    question_text: str,
+    correct_answer: str,
+    fake_answers: list[str] | None,
+    rng_seed: int | None = None,
+    model: str | None = None,
+) -> list[str] | None:
+    """Generate (or reuse cached) distractors when enabled and none were supplied."""
+    if fake_answers or not get_settings().auto_generate_fake_answers:
+        return fake_answers
+    from app.services.ai.fake_answers import get_or_generate_fake_answers
+
+    try:
+        return (
+            await get_or_generate_fake_answers(
+                question_text, correct_answer, rng_seed=rng_seed, model=model
+            )
+            or fake_answers
+        )
+    except Exception:
+        return fake_answers
+
+
+async def _start_if_waiting(db: AsyncSession, segment: Segment) -> None:
+    """Start the segment's quiz if its room was waiting for questions to arrive."""
+    from app.ws.game_handler import start_waiting_segment
+
+    await start_waiting_segment(db, segment.event_id, segment.id)
+
+
+@router.get(
+    "/segments/{segment_id}/questions",
+    response_model=QuestionPageResponse | ParticipantQuestionPageResponse,
+)
 async def get_questions(
     segment_id: str,
+    current_user: OptionalUser,
     db: Annotated[AsyncSession, Depends(get_db)],
-) -> list[QuestionResponse]:
-    """Get all questions for a segment."""
+    origin: Literal["manual", "generated"] | None = None,
+    limit: Annotated[int, Query(ge=1, le=500)] = 100,
+    offset: Annotated[int, Query(ge=0)] = 0,
+    session_token: Annotated[str | None, Header(alias="X-Session-Token")] = None,
+) -> QuestionPageResponse | ParticipantQuestionPageResponse:
+    """Get a page of a segment's questions, optionally only manual or only AI-generated ones.
+
+    The host and presenter get full questions; participants (by session token) get
+    the participant view without correct or fake answers.
+    """
+    from app.routes.events import _get_requesting_participant
+
+    result = await db.execute(
+        select(Segment, Event).join(Event, Segment.event_id == Event.id).where(Segment.id == segment_id)
+    )
+    row = result.one_or_none()
+    if not row:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+    segment, event = row
+    is_controller = current_user is not None and current_user.id in (
+        event.host_id,
+        segment.presenter_user_id,
+    )
+    if not is_controller and not await _get_requesting_participant(db, event, session_token):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
+
+    filters = [Question.segment_id == segment_id]
+    if origin == "generated":
+        filters.append(Question.is_ai_generated.is_(True))
+    elif origin == "manual":
+        filters.append(or_(Question.is_ai_generated.is_(False), Question.is_ai_generated.is_(None)))
+
+    total = await db.scalar(select(func.count()).select_from(Question).where(*filters))
     result = await db.execute(
         select(Question)
-        .where(Question.segment_id == segment_id)
+        .where(*filters)
         .order_by(Question.order_index)
+        .limit(limit)
+        .offset(offset)
     )
     questions = result.scalars().all()
-    return [QuestionResponse.model_validate(q) for q in questions]
+    if not is_controller:
+        return ParticipantQuestionPageResponse(
+            items=[ParticipantQuestionResponse.model_validate(q) for q in questions],
+            total=total or 0,
+            limit=limit,
+            offset=offset,
+        )
+    return QuestionPageResponse(
+        items=[QuestionResponse.model_validate(q) for q in questions],
+        total=total or 0,
+        limit=limit,
+        offset=offset,
+    )
+
+
+@router.get("/segments/{segment_id}/questions/export")
+async def export_questions(
+    segment_id: UUID,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    format: Literal["json", "csv"] = "json",
+) -> Response:
+    """Download a segment's questions with their answers as JSON or CSV (host or presenter only)."""
+    result = await db.execute(
+        select(Segment)
+        .join(Event, Segment.event_id == Event.id)
+        .where(
+            Segment.id == segment_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+
+    questions = await export_segment_questions(db, segment.id)
+    if format == "csv":
+        content = questions_to_csv(questions)
+        media_type = "text/csv"
+    else:
+        content = export_to_json({"segment_id": str(segment.id), "questions": questions})
+        media_type = "application/json"
+    filename = f"segment_{segment.id}_questions.{format}"
+    return Response(
+        content=content,
+        media_type=media_type,
+        headers={"Content-Disposition": f'attachment; filename="{filename}"'},
+    )
 
 
 @router.post("/segments/{segment_id}/questions", response_model=QuestionResponse, status_code=status.HTTP_201_CREATED)
@@ -61,17 +199,38 @@ async def create_question(
         )
         order_index = result.scalar() or 0
 
+    question_text, correct_answer, fake_answers = _limit_question_lengths(
+        request.question_text, request.correct_answer, request.fake_answers
+    )
+    if request.question_type == "multiple_choice":
+        rng_seed, llm_model = (
+            await db.execute(
+                select(Event.rng_seed, Event.llm_model).where(Event.id == segment.event_id)
+            )
+        ).one()
+        fake_answers = await _fill_fake_answers(
+            question_text, correct_answer, fake_answers, rng_seed, llm_model
+        )
     question = Question(
         id=uuid4(),
         segment_id=segment.id,
-        question_text=request.question_text,
-        correct_answer=request.correct_answer,
-        fake_answers=request.fake_answers,
+        question_text=question_text,
+        correct_answer=correct_answer,
+        fake_answers=fake_answers,
+        tags=normalize_tags(request.tags),
+        time_limit_seconds=request.time_limit_seconds,
+        explanation=request.explanation,
         order_index=order_index,
         is_ai_generated=False,
+        question_type=request.question_type,
+        tolerance=request.tolerance,
+        tolerance_mode=request.tolerance_mode,
+        bonus_multiplier=request.bonus_multiplier,
     )
     db.add(question)
+    segment.last_manual_question_at = datetime.now(timezone.utc)
     await db.flush()
+    await _start_if_waiting(db, segment)
     return QuestionResponse.model_validate(question)
 
 
@@ -98,20 +257,35 @@ async def bulk_import_questions(
     )
     start_index = result.scalar() or 0
 
+    rng_seed, llm_model = (
+        await db.execute(select(Event.rng_seed, Event.llm_model).where(Event.id == segment.event_id))
+    ).one()
     imported_questions = []
     for i, item in enumerate(request.questions):
+        question_text, correct_answer, _ = _limit_question_lengths(
+            item.question_text, item.correct_answer, None
+        )
         question = Question(
             id=uuid4(),
             segment_id=segment.id,
-            question_text=item.question_text,
-            correct_answer=item.correct_answer,
+            question_text=question_text,
+            correct_answer=correct_answer,
+            fake_answers=await _fill_fake_answers(
+                question_text, correct_answer, None, rng_seed, llm_model
+            ),
+            tags=normalize_tags(item.tags),
+            time_limit_seconds=item.time_limit_seconds,
             order_index=start_index + i,
             is_ai_generated=False,
         )
         db.add(question)
         imported_questions.append(question)
 
+    if imported_questions:
+        segment.last_manual_question_at = datetime.now(timezone.utc)
     await db.flush()
+    if imported_questions:
+        await _start_if_waiting(db, segment)
 
     return BulkImportResult(
         imported=len(imported_questions),
@@ -120,6 +294,321 @@ async def bulk_import_questions(
     )
 
 
+@router.post("/segments/{segment_id}/import-quiz/{quiz_id}", response_model=BulkImportResult)
+async def import_quiz_questions(
+    segment_id: str,
+    quiz_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> BulkImportResult:
+    """Copy every question of one of the user's saved quizzes onto the end of a segment."""
+    # Host or assigned presenter may import
+    result = await db.execute(
+        select(Segment)
+        .join(Event)
+        .where(
+            Segment.id == segment_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+
+    # The source quiz must belong to the importing user
+    quiz = await db.scalar(
+        select(Event).where(Event.id == quiz_id, Event.host_id == current_user.id)
+    )
+    if not quiz:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Quiz not found")
+
+    result = await db.execute(
+        select(Question)
+        .join(Segment)
+        .where(Segment.event_id == quiz.id, Question.is_discarded.is_(False))
+        .order_by(Segment.order_index, Question.order_index, Question.created_at)
+    )
+    source_questions = result.scalars().all()
+
+    result = await db.execute(
+        select(func.coalesce(func.max(Question.order_index), -1) + 1)
+        .where(Question.segment_id == segment.id)
+    )
+    start_index = result.scalar() or 0
+
+    imported_questions = []
+    for i, source in enumerate(source_questions):
+        question = Question(
+            id=uuid4(),
+            segment_id=segment.id,
+            question_text=source.question_text,
+            correct_answer=source.correct_answer,
+            question_type=source.question_type,
+            tolerance=source.tolerance,
+            tolerance_mode=source.tolerance_mode,
+            bonus_multiplier=source.bonus_multiplier,
+            time_limit_seconds=source.time_limit_seconds,
+            fake_answers=list(source.fake_answers) if source.fake_answers else source.fake_answers,
+            tags=list(source.tags) if source.tags else source.tags,
+            explanation=source.explanation,
+            order_index=start_index + i,
+            is_ai_generated=source.is_ai_generated,
+            quality_score=source.quality_score,
+        )
+        db.add(question)
+        imported_questions.append(question)
+
+    if imported_questions:
+        segment.last_manual_question_at = datetime.now(timezone.utc)
+    await db.flush()
+    if imported_questions:
+        await _start_if_waiting(db, segment)
+
+    return BulkImportResult(
+        imported=len(imported_questions),
+        failed=0,
+        questions=[QuestionResponse.model_validate(q) for q in imported_questions],
+    )
+
+
+@router.delete("/segments/{segment_id}/questions", response_model=BulkDeleteResult)
+async def bulk_delete_questions(
+    segment_id: str,
+    request: BulkDeleteQuestionsRequest,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> BulkDeleteResult:
+    """Delete several questions (or all of them) from a segment in one transaction."""
+    # Host or assigned presenter may delete
+    result = await db.execute(
+        select(Segment)
+        .join(Event)
+        .where(
+            Segment.id == segment_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+
+    if not request.all and not request.question_ids:
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST,
+            detail="Provide question_ids or set all=true",
+        )
+
+    query = delete(Question).where(Question.segment_id == segment.id)
+    if not request.all:
+        requested_ids = set(request.question_ids)
+        result = await db.execute(
+            select(Question.id).where(
+                Question.segment_id == segment.id, Question.id.in_(requested_ids)
+            )
+        )
+        found_ids = set(result.scalars().all())
+        if found_ids != requested_ids:
+            raise HTTPException(
+                status_code=status.HTTP_400_BAD_REQUEST,
+                detail="Some questions do not belong to this segment",
+            )
+        query = query.where(Question.id.in_(requested_ids))
+
+    result = await db.execute(query)
+    await db.flush()
+    return BulkDeleteResult(deleted=result.rowcount or 0)
+
+
+@router.post("/segments/{segment_id}/questions/regenerate-all", response_model=list[QuestionResponse])
+async def regenerate_all_questions(
+    segment_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> list[QuestionResponse]:
+    """Replace the segment's AI-generated questions with a fresh set from its stored transcript.
+
+    Manually written questions are kept; the new questions are ordered after them.
+    """
+    from app.config import get_settings
+    from app.models import PresentationTranscript
+    from app.routes.segments import _generate_questions_for_transcript, _quality_scoring_enabled
+    from app.services.transcript_store import flush_pending_transcripts
+
+    result = await db.execute(
+        select(Segment)
+        .join(Event)
+        .where(
+            Segment.id == segment_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+
+    await flush_pending_transcripts(db, segment.id)
+    result = await db.execute(
+        select(PresentationTranscript.chunk_text)
+        .where(PresentationTranscript.segment_id == segment.id)
+        .order_by(PresentationTranscript.chunk_index, PresentationTranscript.created_at)
+    )
+    transcript_text = " ".join(text.strip() for text in result.scalars().all() if text)
+    if not transcript_text:
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST,
+            detail="No transcript available for this segment",
+        )
+
+    event = await db.get(Event, segment.event_id)
+    settings = get_settings()
+    # An explicit regenerate ignores the manual-question cooldown
+    generated = await _generate_questions_for_transcript(
+        db=db,
+        segment_id=segment.id,
+        transcript_text=transcript_text,
+        event=event,
+        settings=settings,
+        quality_scoring=_quality_scoring_enabled(event, settings),
+        notes=segment.notes,
+    )
+    if not generated:
+        # Keep the segment's failure count
+        await db.commit()
+        raise HTTPException(
+            status_code=status.HTTP_400_BAD_REQUEST,
+            detail="Could not generate questions from the transcript",
+        )
+
+    await db.execute(
+        delete(Question).where(Question.segment_id == segment.id, Question.is_ai_generated.is_(True))
+    )
+    result = await db.execute(
+        select(func.coalesce(func.max(Question.order_index), -1) + 1)
+        .where(Question.segment_id == segment.id)
+    )
+    next_index = result.scalar() or 0
+    for offset, question in enumerate(generated):
+        question.order_index = next_index + offset
+        db.add(question)
+
+    await db.flush()
+    return [QuestionResponse.model_validate(q) for q in generated]
+
+
+@router.get("/questions/{question_id}", response_model=QuestionResponse)
+async def get_question(
+    question_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> QuestionResponse:
+    """Get a single question, including its correct answer and explanation, for review."""
+    result = await db.execute(
+        select(Question)
+        .join(Segment)
+        .join(Event)
+        .where(
+            Question.id == question_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    question = result.scalar_one_or_none()
+    if not question:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Question not found")
+    return QuestionResponse.model_validate(question)
+
+
+@router.get("/questions/{question_id}/timing", response_model=QuestionTimingResponse)
+async def get_question_timing(
+    question_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    bucket_ms: int | None = Query(None, ge=100, le=60000),
+) -> QuestionTimingResponse:
+    """Histogram, median and p90 of response times to a question (host only)."""
+    result = await db.execute(
+        select(Question, Event.time_per_question)
+        .join(Segment, Question.segment_id == Segment.id)
+        .join(Event, Segment.event_id == Event.id)
+        .where(Question.id == question_id, Event.host_id == current_user.id)
+    )
+    row = result.one_or_none()
+    if not row:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Question not found")
+    question, time_per_question = row
+
+    times = await db.execute(
+        select(ParticipantAnswer.response_time_ms).where(
+            ParticipantAnswer.question_id == question.id,
+            ParticipantAnswer.response_time_ms.is_not(None),
+        )
+    )
+    response_times = sorted(times.scalars().all())
+    bucket_ms = bucket_ms or get_settings().timing_histogram_bucket_ms
+
+    return QuestionTimingResponse(
+        question_id=question.id,
+        time_limit_seconds=question.time_limit_seconds or time_per_question,
+        bucket_ms=bucket_ms,
+        response_count=len(response_times),
+        median_ms=percentile(response_times, 0.5),
+        p90_ms=percentile(response_times, 0.9),
+        buckets=timing_histogram(response_times, bucket_ms),
+    )
+
+
+@router.get("/events/{event_id}/tags/accuracy", response_model=list[TagAccuracyResponse])
+async def get_tag_accuracy(
+    event_id: UUID,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> list[TagAccuracyResponse]:
+    """Share of correct answers per question tag across the event (host only)."""
+    event = await db.scalar(
+        select(Event).where(Event.id == event_id, Event.host_id == current_user.id)
+    )
+    if not event:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Event not found")
+    return [TagAccuracyResponse(**stats) for stats in await tag_accuracy(db, event.id)]
+
+
+@router.post("/questions/{question_id}/answers/preview", response_model=FakeAnswerPreviewResponse)
+async def preview_fake_answers(
+    question_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+) -> FakeAnswerPreviewResponse:
+    """Show the distractors the AI would generate for a question without saving them."""
+    from app.services.ai.fake_answers import get_or_generate_fake_answers
+
+    result = await db.execute(
+        select(Question, Event)
+        .join(Segment, Question.segment_id == Segment.id)
+        .join(Event, Segment.event_id == Event.id)
+        .where(
+            Question.id == question_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    row = result.one_or_none()
+    if not row:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Question not found")
+    question, event = row
+
+    try:
+        fake_answers = await get_or_generate_fake_answers(
+            question.question_text,
+            question.correct_answer,
+            num_fakes=event.num_fake_answers,
+            rng_seed=event.rng_seed,
+            model=event.llm_model,
+        )
+    except Exception as e:
+        raise HTTPException(
+            status_code=status.HTTP_502_BAD_GATEWAY, detail=f"Distractor generation failed: {str(e)}"
+        )
+    return FakeAnswerPreviewResponse(question_id=question.id, fake_answers=fake_answers)
+
+
 @router.put("/questions/{question_id}", response_model=QuestionResponse)
 async def update_question(
     question_id: str,
@@ -144,8 +633,25 @@ async def update_question(
         question.correct_answer = request.correct_answer
     if request.fake_answers is not None:
         question.fake_answers = request.fake_answers
+    if request.explanation is not None:
+        question.explanation = request.explanation
     if request.order_index is not None:
         question.order_index = request.order_index
+    if request.tolerance is not None:
+        question.tolerance = request.tolerance
+    if request.tolerance_mode is not None:
+        question.tolerance_mode = request.tolerance_mode
+    if request.bonus_multiplier is not None:
+        question.bonus_multiplier = request.bonus_multiplier
+    if "time_limit_seconds" in request.model_fields_set:
+        # An explicit null goes back to the event's time_per_question
+        question.time_limit_seconds = request.time_limit_seconds
+    if request.tags is not None:
+        # An empty list clears the tags
+        question.tags = normalize_tags(request.tags)
+    question.question_text, question.correct_answer, question.fake_answers = _limit_question_lengths(
+        question.question_text, question.correct_answer, question.fake_answers
+    )
 
     await db.flush()
     return QuestionResponse.model_validate(question)
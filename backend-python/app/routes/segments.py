@@ -1,20 +1,34 @@
 """Segment routes."""
 
+import asyncio
 from datetime import datetime, timezone
 from typing import Annotated
 from uuid import uuid4
 
 from fastapi import APIRouter, Depends, HTTPException, UploadFile, status
-from sqlalchemy import func, select
+from sqlalchemy import func, or_, select, update
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from app.auth import CurrentUser
-from app.database import get_db
+from app.database import async_session_maker, get_db
 from uuid import UUID
 
-from app.models import Event, Question, Segment, SegmentStatus, AudioChunk, ProcessingLog
+from app.models import (
+    AudioChunk,
+    Event,
+    ProcessingLog,
+    Question,
+    Segment,
+    SegmentStatus,
+    can_transition_segment,
+)
 from app.schemas import CreateSegmentRequest, SegmentResponse, UpdateSegmentRequest
-from app.services.audio_storage import AudioStorageService
+from app.services.audio_storage import AudioStorageService, StorageUnavailableError
+from app.services.fallback_questions import track_generation_result
+from app.services.orphan_cleanup import delete_segment_dependents
+from app.services.question_limits import QuestionTooLongError, enforce_question_limits
+from app.services.redaction import redact_pii
+from app.services.transcript_store import flush_pending_transcripts, store_transcript
 from app.ws.hub import hub
 from app.ws.messages import NoQuestionsGeneratedMessage
 
@@ -22,6 +36,53 @@ router = APIRouter()
 SEGMENT_RESUME_DEBOUNCE: dict[str, datetime] = {}
 
 
+def _ensure_segment_transition(segment: Segment, target: str) -> None:
+    """Raise 409 if the segment cannot move from its current status to target."""
+    if not can_transition_segment(segment.status, target):
+        raise HTTPException(
+            status_code=status.HTTP_409_CONFLICT,
+            detail=f"Cannot change segment status from '{segment.status}' to '{target}'",
+        )
+
+
+async def _get_event_segment(
+    db: AsyncSession,
+    event_id: str,
+    segment_id: str,
+    user_id: UUID,
+    allow_presenter: bool = False,
+) -> Segment:
+    """Load a segment only if it belongs to the claimed event and the user may act on it.
+
+    Raises 404 when the segment is not part of that event (so guessed IDs from
+    other events are indistinguishable from missing ones) and 403 when the user
+    is neither the host nor, if allowed, the segment's presenter.
+    """
+    try:
+        event_uuid = UUID(event_id)
+        segment_uuid = UUID(segment_id)
+    except ValueError:
+        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Invalid segment or event id")
+
+    result = await db.execute(
+        select(Segment)
+        .join(Event, Segment.event_id == Event.id)
+        .where(Segment.id == segment_uuid, Segment.event_id == event_uuid)
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+
+    # Eager load event to avoid lazy access
+    await db.refresh(segment, attribute_names=["event"])
+
+    is_host = segment.event.host_id == user_id
+    is_presenter = allow_presenter and segment.presenter_user_id == user_id
+    if not (is_host or is_presenter):
+        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
+    return segment
+
+
 @router.post("/quizzes/{event_id}/questions", response_model=SegmentResponse, status_code=status.HTTP_201_CREATED)
 async def create_segment(
     event_id: str,
@@ -51,6 +112,12 @@ async def create_segment(
         presenter_user_id=request.presenter_user_id,
         title=request.title,
         order_index=next_index,
+        weight=request.weight,
+        can_advance=request.can_advance,
+        can_reveal=request.can_reveal,
+        can_end=request.can_end,
+        store_transcripts=request.store_transcripts,
+        notes=request.notes or None,
     )
     db.add(segment)
     await db.flush()
@@ -67,30 +134,9 @@ async def get_segment(
     db: Annotated[AsyncSession, Depends(get_db)],
 ) -> SegmentResponse:
     """Fetch a single segment by id. Host or assigned presenter may access."""
-    try:
-        event_uuid = UUID(event_id)
-        segment_uuid = UUID(segment_id)
-    except ValueError:
-        raise HTTPException(status_code=status.HTTP_400_BAD_REQUEST, detail="Invalid segment or event id")
-
-    result = await db.execute(
-        select(Segment)
-        .join(Event, Segment.event_id == Event.id)
-        .where(Segment.id == segment_uuid, Segment.event_id == event_uuid)
+    segment = await _get_event_segment(
+        db, event_id, segment_id, current_user.id, allow_presenter=True
     )
-    segment = result.scalar_one_or_none()
-    if not segment:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
-
-    # Eager load event to avoid lazy access
-    await db.refresh(segment, attribute_names=["event"])
-
-    is_host = str(segment.event.host_id) == str(current_user.id)  # type: ignore[attr-defined]
-    is_presenter = str(segment.presenter_user_id) == str(current_user.id)
-
-    if not (is_host or is_presenter):
-        raise HTTPException(status_code=status.HTTP_403_FORBIDDEN, detail="Not authorized")
-
     return SegmentResponse.model_validate(segment)
 
 
@@ -103,23 +149,32 @@ async def update_segment(
     db: Annotated[AsyncSession, Depends(get_db)],
 ) -> SegmentResponse:
     """Update a segment."""
-    result = await db.execute(
-        select(Segment).join(Event).where(Segment.id == segment_id, Event.host_id == current_user.id)
-    )
-    segment = result.scalar_one_or_none()
-    if not segment:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+    segment = await _get_event_segment(db, event_id, segment_id, current_user.id)
 
     if request.presenter_name is not None:
         segment.presenter_name = request.presenter_name
     if request.title is not None:
         segment.title = request.title
     if request.status is not None:
+        _ensure_segment_transition(segment, request.status)
         segment.status = request.status
     if request.previous_status is not None:
         segment.previous_status = request.previous_status
+    if request.weight is not None:
+        segment.weight = request.weight
+    if request.can_advance is not None:
+        segment.can_advance = request.can_advance
+    if request.can_reveal is not None:
+        segment.can_reveal = request.can_reveal
+    if request.can_end is not None:
+        segment.can_end = request.can_end
+    if request.store_transcripts is not None:
+        segment.store_transcripts = request.store_transcripts
+    if request.notes is not None:
+        segment.notes = request.notes or None
 
     await db.flush()
+    hub.invalidate_controller_cache(segment.event_id)
     return SegmentResponse.model_validate(segment)
 
 
@@ -143,11 +198,25 @@ async def patch_segment(
     if request.title is not None:
         segment.title = request.title
     if request.status is not None:
+        _ensure_segment_transition(segment, request.status)
         segment.status = request.status
     if request.previous_status is not None:
         segment.previous_status = request.previous_status
+    if request.weight is not None:
+        segment.weight = request.weight
+    if request.can_advance is not None:
+        segment.can_advance = request.can_advance
+    if request.can_reveal is not None:
+        segment.can_reveal = request.can_reveal
+    if request.can_end is not None:
+        segment.can_end = request.can_end
+    if request.store_transcripts is not None:
+        segment.store_transcripts = request.store_transcripts
+    if request.notes is not None:
+        segment.notes = request.notes or None
 
     await db.commit()
+    hub.invalidate_controller_cache(segment.event_id)
     await db.refresh(segment)
     return SegmentResponse.model_validate(segment)
 
@@ -160,12 +229,8 @@ async def delete_segment(
     db: Annotated[AsyncSession, Depends(get_db)],
 ) -> None:
     """Delete a segment."""
-    result = await db.execute(
-        select(Segment).join(Event).where(Segment.id == segment_id, Event.host_id == current_user.id)
-    )
-    segment = result.scalar_one_or_none()
-    if not segment:
-        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
+    segment = await _get_event_segment(db, event_id, segment_id, current_user.id)
+    await delete_segment_dependents(db, segment.id)
     await db.delete(segment)
 
 
@@ -184,6 +249,7 @@ async def start_recording(
     if not segment:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
 
+    _ensure_segment_transition(segment, SegmentStatus.RECORDING.value)
     segment.status = SegmentStatus.RECORDING.value
     segment.recording_started_at = datetime.now(timezone.utc)
     await db.flush()
@@ -204,6 +270,7 @@ async def stop_recording(
     if not segment:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
 
+    _ensure_segment_transition(segment, SegmentStatus.QUIZ_READY.value)
     segment.status = SegmentStatus.QUIZ_READY.value
     segment.recording_ended_at = datetime.now(timezone.utc)
     await db.flush()
@@ -241,6 +308,8 @@ async def complete_segment(
     if not segment:
         raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Segment not found")
 
+    _ensure_segment_transition(segment, SegmentStatus.COMPLETED.value)
+
     # Store previous status for resume capability
     segment.previous_status = segment.status
     segment.status = SegmentStatus.COMPLETED.value
@@ -340,7 +409,6 @@ async def transcribe_and_generate_questions(
     """Transcribe audio and generate quiz questions."""
     from app.services.transcription import WhisperTranscriptionService
     from app.services.ai import ClaudeProvider, OpenAIProvider
-    from app.models import PresentationTranscript
     from app.ws.messages import QuizGeneratingMessage, QuizReadyMessage
     from app.config import get_settings
     
@@ -356,6 +424,7 @@ async def transcribe_and_generate_questions(
     segment = result.scalar_one_or_none()
     if not segment:
         raise HTTPException(status_code=404, detail="Segment not found")
+    _ensure_segment_transition(segment, SegmentStatus.QUIZ_READY.value)
     
     # Broadcast: generation started (triggers Flappy Bird)
     await hub.broadcast(
@@ -386,21 +455,18 @@ async def transcribe_and_generate_questions(
             detail="Transcript too short. Please record more content."
         )
     
-    # Save transcript
-    transcript = PresentationTranscript(
-        id=uuid4(),
-        segment_id=UUID(segment_id),
-        chunk_text=transcript_text,
-        chunk_index=0,
-    )
-    db.add(transcript)
-    await db.flush()
-    
     # Get event for questions_to_generate setting
     event_result = await db.execute(
         select(Event).where(Event.id == segment.event_id)
     )
     event = event_result.scalar_one()
+    store_transcripts = _transcript_storage_enabled(event, segment)
+    if event.redact_pii:
+        transcript_text = redact_pii(transcript_text)
+    
+    # Save transcript
+    if store_transcripts:
+        await store_transcript(db, UUID(segment_id), transcript_text, chunk_index=0)
     
     # Generate questions using new helper function
     questions_generated = await _generate_questions_for_transcript(
@@ -408,14 +474,34 @@ async def transcribe_and_generate_questions(
         segment_id=UUID(segment_id),
         transcript_text=transcript_text,
         event=event,
-        settings=settings
+        settings=settings,
+        last_manual_question_at=segment.last_manual_question_at,
+        quality_scoring=_quality_scoring_enabled(event, settings),
+        notes=segment.notes,
     )
     
+    cooldown_remaining = _generation_cooldown_remaining(segment.last_manual_question_at, settings)
+    if not questions_generated and cooldown_remaining > 0:
+        # Generation resumes on this transcript once the manual-question cooldown ends
+        await db.commit()
+        _defer_generation(UUID(segment_id), transcript_text, cooldown_remaining)
+        return {
+            "success": True,
+            "transcript_length": len(transcript_text),
+            "questions_generated": 0,
+            "segment_id": segment_id,
+            "generation_cooldown_seconds": cooldown_remaining,
+        }
+
     # Add generated questions to database
     for question in questions_generated:
+        if not store_transcripts:
+            question.source_transcript = None
         db.add(question)
     
     if len(questions_generated) == 0:
+        # Keep the segment's failure count
+        await db.commit()
         raise HTTPException(
             status_code=400,
             detail="Could not generate questions. Try presenting more factual content."
@@ -436,6 +522,9 @@ async def transcribe_and_generate_questions(
             auto_start=True
         ).model_dump()
     )
+    from app.ws.game_handler import start_waiting_segment
+
+    await start_waiting_segment(db, segment.event_id, UUID(segment_id))
     
     return {
         "success": True,
@@ -456,14 +545,183 @@ def _split_transcript(text: str, chunk_size: int = 500) -> list[str]:
     return chunks if chunks else [text]
 
 
+def _generation_cooldown_remaining(
+    last_manual_question_at: datetime | None,
+    settings,
+    now: datetime | None = None,
+) -> float:
+    """Seconds left before automatic generation may resume after a manual question."""
+    if last_manual_question_at is None:
+        return 0.0
+    now = now or datetime.now(timezone.utc)
+    elapsed = (now - last_manual_question_at).total_seconds()
+    return max(settings.manual_question_generation_cooldown_seconds - elapsed, 0.0)
+
+
+# Generation held back by the manual-question cooldown, per segment
+_deferred_generations: dict[UUID, asyncio.Task] = {}
+
+
+def _defer_generation(segment_id: UUID, transcript_text: str, delay_seconds: float) -> None:
+    """Generate questions from the transcript once the manual-question cooldown has passed.
+
+    A later deferral for the same segment replaces the one still waiting.
+    """
+    waiting = _deferred_generations.get(segment_id)
+    if waiting and waiting is not asyncio.current_task():
+        waiting.cancel()
+    _deferred_generations[segment_id] = asyncio.create_task(
+        _generate_after_cooldown(segment_id, transcript_text, delay_seconds)
+    )
+
+
+async def _generate_after_cooldown(
+    segment_id: UUID, transcript_text: str, delay_seconds: float
+) -> None:
+    """Run a deferred generation and publish its questions like a finished recording.
+
+    Defers again when another manual question restarted the cooldown meanwhile, and
+    gives up when the segment has moved on (e.g. was completed) in the meantime.
+    """
+    from app.config import get_settings
+    from app.ws.game_handler import start_waiting_segment
+    from app.ws.messages import QuizReadyMessage
+
+    await asyncio.sleep(delay_seconds)
+    settings = get_settings()
+    try:
+        async with async_session_maker() as db:
+            segment = await db.get(Segment, segment_id)
+            if segment is None or not can_transition_segment(
+                segment.status, SegmentStatus.QUIZ_READY.value
+            ):
+                return
+            cooldown_remaining = _generation_cooldown_remaining(
+                segment.last_manual_question_at, settings
+            )
+            if cooldown_remaining > 0:
+                _defer_generation(segment_id, transcript_text, cooldown_remaining)
+                return
+
+            event = await db.get(Event, segment.event_id)
+            questions_generated = await _generate_questions_for_transcript(
+                db=db,
+                segment_id=segment_id,
+                transcript_text=transcript_text,
+                event=event,
+                settings=settings,
+                last_manual_question_at=segment.last_manual_question_at,
+                quality_scoring=_quality_scoring_enabled(event, settings),
+                notes=segment.notes,
+            )
+            if not questions_generated:
+                db.add(
+                    ProcessingLog(
+                        id=uuid4(),
+                        segment_id=segment_id,
+                        stage='generating',
+                        message="No questions generated after the manual-question cooldown",
+                        level='warning',
+                    )
+                )
+                await db.commit()
+                await hub.broadcast(
+                    segment.event_id,
+                    NoQuestionsGeneratedMessage(
+                        segment_id=segment_id,
+                        segment_title=segment.title,
+                        presenter_name=segment.presenter_name,
+                    ).model_dump(),
+                )
+                return
+
+            store_transcripts = _transcript_storage_enabled(event, segment)
+            for question in questions_generated:
+                if not store_transcripts:
+                    question.source_transcript = None
+                db.add(question)
+            db.add(
+                ProcessingLog(
+                    id=uuid4(),
+                    segment_id=segment_id,
+                    stage='complete',
+                    message=f"Generated {len(questions_generated)} questions after the cooldown",
+                )
+            )
+            segment.status = SegmentStatus.QUIZ_READY.value
+            await db.execute(
+                update(AudioChunk).where(AudioChunk.segment_id == segment_id).values(is_finalized=True)
+            )
+            await db.commit()
+
+            await hub.broadcast(
+                segment.event_id,
+                QuizReadyMessage(
+                    segment_id=segment_id,
+                    questions_count=len(questions_generated),
+                    auto_start=True,
+                ).model_dump(),
+            )
+            await start_waiting_segment(db, segment.event_id, segment_id)
+
+        try:
+            await AudioStorageService().delete_segment_chunks(segment_id)
+        except Exception:
+            pass  # Cleanup is non-critical
+    finally:
+        if _deferred_generations.get(segment_id) is asyncio.current_task():
+            del _deferred_generations[segment_id]
+
+
+def _quality_scoring_enabled(event: Event, settings) -> bool:
+    """Use the event's quality-scoring override, falling back to the global setting."""
+    if event.enable_ai_quality_scoring is not None:
+        return event.enable_ai_quality_scoring
+    return settings.enable_ai_quality_scoring
+
+
+def _quality_threshold(event: Event, settings) -> float:
+    """Use the event's quality threshold, falling back to settings.ai_quality_min_score."""
+    if event.question_quality_threshold is not None:
+        return event.question_quality_threshold
+    return settings.ai_quality_min_score
+
+
+def _transcript_storage_enabled(event: Event, segment: Segment) -> bool:
+    """Use the segment's transcript-storage override, falling back to the event's flag."""
+    if segment.store_transcripts is not None:
+        return segment.store_transcripts
+    return event.store_transcripts is not False
+
+
+async def _passes_quality_check(ai_provider, question: Question, min_score: float) -> bool:
+    """Score a generated question with the AI provider and record the score on it."""
+    assessment = await ai_provider.evaluate_question_quality(
+        question=question.question_text,
+        correct_answer=question.correct_answer,
+        source_transcript=question.source_transcript,
+    )
+    if assessment is None:
+        # Keep the question when the provider cannot score it
+        return True
+    question.quality_score = assessment.overall_score
+    return assessment.overall_score >= min_score
+
+
 async def _generate_questions_for_transcript(
     db: AsyncSession,
     segment_id: UUID,
     transcript_text: str,
     event: Event,
-    settings
+    settings,
+    last_manual_question_at: datetime | None = None,
+    quality_scoring: bool = False,
+    notes: str | None = None,
 ) -> list[Question]:
     """Generate questions from transcript using batch or chunking mode.
+
+    Token usage of the AI calls made along the way is recorded against the event, and
+    repeated empty results eventually yield a fallback question (see track_generation_result).
     
     Args:
         db: Database session
@@ -471,17 +729,49 @@ async def _generate_questions_for_transcript(
         transcript_text: Full transcript
         event: Event object (for questions_to_generate setting)
         settings: App settings
+        last_manual_question_at: When the host last added a question by hand;
+            nothing is generated while the cooldown after it is running
+        quality_scoring: Drop questions the AI provider scores below the
+            event's question_quality_threshold (or settings.ai_quality_min_score)
+        notes: Presenter's slide notes passed to the AI as extra context
         
     Returns:
         List of generated Question objects (not yet committed to DB)
     """
-    from app.services.ai import OpenAIProvider, ClaudeProvider
+    from app.services.ai.usage import collect_usage, store_usage
+
+    if event.redact_pii:
+        # Stored transcripts may predate redaction being switched on
+        transcript_text = redact_pii(transcript_text)
+        notes = redact_pii(notes) if notes else notes
+    with collect_usage() as usage:
+        questions = await _run_question_generation(
+            segment_id, transcript_text, event, settings, last_manual_question_at, quality_scoring, notes
+        )
+    store_usage(db, event.id, usage, segment_id=segment_id)
+    if _generation_cooldown_remaining(last_manual_question_at, settings) > 0:
+        # Skipped for the cooldown rather than failed
+        return questions
+    return await track_generation_result(db, segment_id, event, questions)
+
+
+async def _run_question_generation(
+    segment_id: UUID,
+    transcript_text: str,
+    event: Event,
+    settings,
+    last_manual_question_at: datetime | None,
+    quality_scoring: bool,
+    notes: str | None,
+) -> list[Question]:
+    """Call the configured AI provider; see _generate_questions_for_transcript."""
+    from app.services.ai import create_ai_provider
+
+    if _generation_cooldown_remaining(last_manual_question_at, settings) > 0:
+        return []
     
-    ai_provider = (
-        OpenAIProvider() 
-        if settings.default_ai_provider == "openai" 
-        else ClaudeProvider()
-    )
+    ai_provider = create_ai_provider(settings.default_ai_provider, event.llm_model)
+    min_score = _quality_threshold(event, settings) if quality_scoring else 0.0
     
     questions_generated = []
     num_questions = event.questions_to_generate
@@ -492,20 +782,33 @@ async def _generate_questions_for_transcript(
         generated_questions = await ai_provider.generate_questions_batch(
             transcript=transcript_text,
             num_questions=num_questions,
-            existing_questions=[]
+            existing_questions=[],
+            notes=notes,
         )
         
-        for idx, generated in enumerate(generated_questions):
+        for generated in generated_questions:
+            try:
+                question_text, correct_answer, fake_answers = enforce_question_limits(
+                    generated.question_text, generated.correct_answer, generated.fake_answers
+                )
+            except QuestionTooLongError:
+                continue
             question = Question(
                 id=uuid4(),
                 segment_id=segment_id,
-                question_text=generated.question_text,
-                correct_answer=generated.correct_answer,
-                fake_answers=generated.fake_answers,
-                order_index=idx,
+                question_text=question_text,
+                correct_answer=correct_answer,
+                fake_answers=fake_answers,
+                order_index=len(questions_generated),
                 is_ai_generated=True,
                 source_transcript=generated.source_transcript,
+                explanation=generated.explanation,
+                tags=generated.tags,
             )
+            if quality_scoring and not await _passes_quality_check(
+                ai_provider, question, min_score
+            ):
+                continue
             questions_generated.append(question)
     
     # Fallback: Use chunking approach for Claude/Ollama or if batch fails
@@ -516,20 +819,33 @@ async def _generate_questions_for_transcript(
         for chunk in chunks:
             generated = await ai_provider.analyze_and_generate_question(
                 transcript=chunk,
-                existing_questions=[q.question_text for q in existing_questions]
+                existing_questions=[q.question_text for q in existing_questions],
+                notes=notes,
             )
             
             if generated:
+                try:
+                    question_text, correct_answer, fake_answers = enforce_question_limits(
+                        generated.question_text, generated.correct_answer, generated.fake_answers
+                    )
+                except QuestionTooLongError:
+                    continue
                 question = Question(
                     id=uuid4(),
                     segment_id=segment_id,
-                    question_text=generated.question_text,
-                    correct_answer=generated.correct_answer,
-                    fake_answers=generated.fake_answers,
+                    question_text=question_text,
+                    correct_answer=correct_answer,
+                    fake_answers=fake_answers,
                     order_index=len(questions_generated),
                     is_ai_generated=True,
                     source_transcript=chunk[:500],
+                    explanation=generated.explanation,
+                    tags=generated.tags,
                 )
+                if quality_scoring and not await _passes_quality_check(
+                    ai_provider, question, min_score
+                ):
+                    continue
                 questions_generated.append(question)
                 existing_questions.append(question)
     
@@ -572,11 +888,14 @@ async def upload_audio_chunk(
     
     # Store in MinIO
     storage = AudioStorageService()
-    storage_path = await storage.store_chunk(
-        UUID(segment_id),
-        chunk_index,
-        chunk_data
-    )
+    try:
+        storage_path = await storage.store_chunk(
+            UUID(segment_id),
+            chunk_index,
+            chunk_data
+        )
+    except StorageUnavailableError as e:
+        raise HTTPException(status_code=status.HTTP_503_SERVICE_UNAVAILABLE, detail=str(e))
     
     # Save metadata to database
     audio_chunk_model = AudioChunk(
@@ -618,10 +937,8 @@ async def finalize_recording_and_generate(
     from app.services.audio_combiner import AudioCombiner
     from app.services.transcription import WhisperTranscriptionService
     from app.services.ai import OpenAIProvider, ClaudeProvider
-    from app.models import PresentationTranscript
     from app.ws.messages import QuizGeneratingMessage, QuizReadyMessage
     from app.config import get_settings
-    from sqlalchemy import update
     
     settings = get_settings()
     
@@ -635,6 +952,7 @@ async def finalize_recording_and_generate(
     segment = result.scalar_one_or_none()
     if not segment:
         raise HTTPException(status_code=404, detail="Segment not found")
+    _ensure_segment_transition(segment, SegmentStatus.QUIZ_READY.value)
     
     # Broadcast: generation started
     await hub.broadcast(
@@ -764,15 +1082,18 @@ async def finalize_recording_and_generate(
             detail="Transcript too short. Please record more content."
         )
     
-    # Save transcript
-    transcript = PresentationTranscript(
-        id=uuid4(),
-        segment_id=UUID(segment_id),
-        chunk_text=transcript_text,
-        chunk_index=0,
+    # Get event for questions_to_generate setting
+    event_result = await db.execute(
+        select(Event).where(Event.id == segment.event_id)
     )
-    db.add(transcript)
-    await db.flush()
+    event = event_result.scalar_one()
+    store_transcripts = _transcript_storage_enabled(event, segment)
+    if event.redact_pii:
+        transcript_text = redact_pii(transcript_text)
+    
+    # Save transcript
+    if store_transcripts:
+        await store_transcript(db, UUID(segment_id), transcript_text, chunk_index=0)
     
     # Log: Starting question generation
     log = ProcessingLog(
@@ -784,23 +1105,41 @@ async def finalize_recording_and_generate(
     db.add(log)
     await db.flush()
     
-    # Get event for questions_to_generate setting
-    event_result = await db.execute(
-        select(Event).where(Event.id == segment.event_id)
-    )
-    event = event_result.scalar_one()
-    
     # Generate questions using new helper function
     questions_generated = await _generate_questions_for_transcript(
         db=db,
         segment_id=UUID(segment_id),
         transcript_text=transcript_text,
         event=event,
-        settings=settings
+        settings=settings,
+        last_manual_question_at=segment.last_manual_question_at,
+        quality_scoring=_quality_scoring_enabled(event, settings),
+        notes=segment.notes,
     )
     
+    cooldown_remaining = _generation_cooldown_remaining(segment.last_manual_question_at, settings)
+    if not questions_generated and cooldown_remaining > 0:
+        log = ProcessingLog(
+            id=uuid4(),
+            segment_id=UUID(segment_id),
+            stage='generating',
+            message=f"Generation deferred for {int(cooldown_remaining)}s after a manual question",
+        )
+        db.add(log)
+        await db.commit()
+        _defer_generation(UUID(segment_id), transcript_text, cooldown_remaining)
+        return {
+            "success": True,
+            "chunks_processed": len(chunks),
+            "transcript_length": len(transcript_text),
+            "questions_generated": 0,
+            "generation_cooldown_seconds": cooldown_remaining,
+        }
+
     # Add generated questions to database
     for question in questions_generated:
+        if not store_transcripts:
+            question.source_transcript = None
         db.add(question)
     
     if len(questions_generated) == 0:
@@ -850,6 +1189,9 @@ async def finalize_recording_and_generate(
             auto_start=True
         ).model_dump()
     )
+    from app.ws.game_handler import start_waiting_segment
+
+    await start_waiting_segment(db, segment.event_id, UUID(segment_id))
     
     # Cleanup chunks from MinIO after successful processing
     try:
@@ -910,3 +1252,59 @@ async def get_processing_logs(
 
 
 
+
+
+@router.post("/segments/{segment_id}/summary")
+async def summarize_segment(
+    segment_id: str,
+    current_user: CurrentUser,
+    db: Annotated[AsyncSession, Depends(get_db)],
+    refresh: bool = False,
+) -> dict:
+    """Summarize the segment's stored transcript (host or presenter).
+
+    The summary is cached on the segment; pass ``refresh=true`` to regenerate it.
+    """
+    from app.services.ai import create_ai_provider
+    from app.services.ai.usage import collect_usage, store_usage
+    from app.models import PresentationTranscript
+    from app.config import get_settings
+
+    result = await db.execute(
+        select(Segment).join(Event).where(
+            Segment.id == segment_id,
+            or_(Event.host_id == current_user.id, Segment.presenter_user_id == current_user.id),
+        )
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        raise HTTPException(status_code=404, detail="Segment not found")
+
+    if segment.summary and not refresh:
+        return {"segment_id": str(segment.id), "summary": segment.summary, "cached": True}
+
+    await flush_pending_transcripts(db, segment.id)
+    transcripts_result = await db.execute(
+        select(PresentationTranscript.chunk_text)
+        .where(PresentationTranscript.segment_id == segment.id)
+        .order_by(PresentationTranscript.chunk_index, PresentationTranscript.created_at)
+    )
+    transcript_text = " ".join(text.strip() for text in transcripts_result.scalars().all() if text)
+    if not transcript_text:
+        raise HTTPException(status_code=400, detail="No transcript available for this segment")
+    event = await db.get(Event, segment.event_id)
+    if event and event.redact_pii:
+        transcript_text = redact_pii(transcript_text)
+
+    settings = get_settings()
+    ai_provider = create_ai_provider(settings.default_ai_provider, event.llm_model if event else None)
+    try:
+        with collect_usage() as usage:
+            summary = await ai_provider.summarize_transcript(transcript_text)
+    except Exception as e:
+        raise HTTPException(status_code=502, detail=f"Summary generation failed: {str(e)}")
+
+    store_usage(db, segment.event_id, usage, segment_id=segment.id)
+    segment.summary = summary
+    await db.flush()
+    return {"segment_id": str(segment.id), "summary": summary, "cached": False}
@@ -3,30 +3,58 @@
 from app.schemas.auth import (
     AuthResponse,
     LoginRequest,
+    MergeUsersRequest,
+    RefreshResponse,
+    RefreshTokenRequest,
     RegisterRequest,
     UpdateProfileRequest,
     UserResponse,
 )
 from app.schemas.event import (
+    AIModelUsage,
+    AIUsageResponse,
+    AssignPresentersRequest,
+    CanvasStrokePageResponse,
+    CanvasStrokeResponse,
     CreateEventRequest,
+    CreateInviteRequest,
     CreateSegmentRequest,
+    CreateTeamRequest,
+    EventConfigResponse,
+    EventDraftResponse,
+    EventInviteResponse,
     EventParticipantResponse,
     EventResponse,
+    EventStateResponse,
+    HostEventConfigResponse,
     JoinEventRequest,
     JoinEventResponse,
     JoinLockResponse,
+    MistakeResponse,
     QrCodeResponse,
+    SaveEventDraftRequest,
+    ParticipantSegmentResponse,
     SegmentResponse,
+    TeamResponse,
     UpdateEventRequest,
     UpdateSegmentRequest,
 )
 from app.schemas.question import (
+    BulkDeleteQuestionsRequest,
+    BulkDeleteResult,
     BulkImportQuestionsRequest,
     BulkImportResult,
     BulkQuestionItem,
     CreateQuestionRequest,
+    FakeAnswerPreviewResponse,
     LeaderboardEntry,
+    ParticipantQuestionPageResponse,
+    ParticipantQuestionResponse,
+    QuestionPageResponse,
     QuestionResponse,
+    QuestionTimingResponse,
+    TagAccuracyResponse,
+    TimingBucket,
     UpdateQuestionRequest,
 )
 
@@ -34,27 +62,55 @@ __all__ = [
     # Auth
     "RegisterRequest",
     "LoginRequest",
+    "MergeUsersRequest",
     "UpdateProfileRequest",
     "UserResponse",
     "AuthResponse",
+    "RefreshTokenRequest",
+    "RefreshResponse",
     # Event
     "CreateEventRequest",
+    "CreateInviteRequest",
     "UpdateEventRequest",
     "EventResponse",
+    "EventStateResponse",
+    "EventConfigResponse",
+    "HostEventConfigResponse",
+    "SaveEventDraftRequest",
+    "EventDraftResponse",
+    "EventInviteResponse",
+    "CreateTeamRequest",
+    "TeamResponse",
+    "AIModelUsage",
+    "AIUsageResponse",
     "CreateSegmentRequest",
     "UpdateSegmentRequest",
+    "AssignPresentersRequest",
+    "CanvasStrokePageResponse",
+    "CanvasStrokeResponse",
+    "ParticipantSegmentResponse",
     "SegmentResponse",
     "JoinEventRequest",
     "JoinEventResponse",
     "JoinLockResponse",
+    "MistakeResponse",
     "EventParticipantResponse",
     "QrCodeResponse",
     # Question
     "CreateQuestionRequest",
     "UpdateQuestionRequest",
+    "QuestionPageResponse",
+    "ParticipantQuestionPageResponse",
+    "ParticipantQuestionResponse",
     "QuestionResponse",
+    "QuestionTimingResponse",
+    "FakeAnswerPreviewResponse",
+    "TagAccuracyResponse",
+    "TimingBucket",
     "BulkQuestionItem",
     "BulkImportQuestionsRequest",
     "BulkImportResult",
+    "BulkDeleteQuestionsRequest",
+    "BulkDeleteResult",
     "LeaderboardEntry",
 ]
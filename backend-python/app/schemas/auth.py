@@ -46,7 +46,28 @@ class UserResponse(BaseModel):
 
 
 class AuthResponse(BaseModel):
-    """Authentication response with token and user."""
+    """Authentication response with tokens and user."""
 
     token: str
+    refresh_token: str
     user: UserResponse
+
+
+class RefreshTokenRequest(BaseModel):
+    """Refresh token presented to refresh or log out."""
+
+    refresh_token: str = Field(..., min_length=1)
+
+
+class MergeUsersRequest(BaseModel):
+    """Duplicate account to fold into the account that is kept."""
+
+    source_user_id: UUID
+    target_user_id: UUID
+
+
+class RefreshResponse(BaseModel):
+    """A new access token and the refresh token that replaces the one used."""
+
+    token: str
+    refresh_token: str
@@ -1,12 +1,28 @@
 """Event and Segment Pydantic schemas."""
 
 from datetime import datetime
+from typing import Any, Literal
 from uuid import UUID
 
 from pydantic import BaseModel, ConfigDict, Field
 
 
+HEX_COLOR_PATTERN = r"^#(?:[0-9a-fA-F]{3}|[0-9a-fA-F]{6})$"
+
+
 # Event schemas
+class EventBranding(BaseModel):
+    """Host-chosen theme delivered to clients when they connect."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    logo_url: str | None = Field(None, max_length=2048, pattern=r"^https?://")
+    primary_color: str | None = Field(None, pattern=HEX_COLOR_PATTERN)
+    secondary_color: str | None = Field(None, pattern=HEX_COLOR_PATTERN)
+    background_color: str | None = Field(None, pattern=HEX_COLOR_PATTERN)
+    text_color: str | None = Field(None, pattern=HEX_COLOR_PATTERN)
+
+
 class CreateEventRequest(BaseModel):
     """Create event request."""
 
@@ -17,6 +33,31 @@ class CreateEventRequest(BaseModel):
     time_per_question: int | None = Field(None, ge=5, le=300)
     questions_to_generate: int | None = Field(None, ge=1, le=20)
     question_gen_interval_seconds: int | None = Field(None, ge=10, le=300)
+    reveal_order: Literal["option", "count"] | None = None
+    segment_winner_order: Literal["segment", "score"] | None = None
+    numeric_scoring_mode: Literal["tolerance", "proximity"] | None = None
+    reveal_detail: Literal["full", "correct_only", "per_user"] | None = None
+    freeze_final_leaderboard: bool | None = None
+    hide_answer_progress: bool | None = None
+    instant_answer_feedback: bool | None = None
+    require_join_approval: bool | None = None
+    buzzer_mode: bool | None = None
+    audio_cues: bool | None = None
+    require_answer_lock: bool | None = None
+    host_can_answer: bool | None = None
+    team_mode: bool | None = None
+    timer_display_mode: Literal["count_down", "count_up"] | None = None
+    max_duration_seconds: int | None = Field(None, ge=0)  # 0 or unset means unlimited
+    rng_seed: int | None = Field(None, ge=0, le=2**63 - 1)
+    enable_ai_quality_scoring: bool | None = None
+    question_quality_threshold: float | None = Field(None, ge=0.0, le=1.0)
+    llm_model: str | None = Field(None, min_length=1, max_length=100)
+    allow_negative_scoring: bool | None = None
+    wrong_answer_penalty: int | None = Field(None, ge=0, le=1000)
+    store_transcripts: bool | None = None
+    redact_pii: bool | None = None
+    visibility: Literal["public", "unlisted", "private"] | None = None
+    branding: EventBranding | None = None
 
 
 class UpdateEventRequest(BaseModel):
@@ -29,6 +70,31 @@ class UpdateEventRequest(BaseModel):
     time_per_question: int | None = None
     questions_to_generate: int | None = None
     question_gen_interval_seconds: int | None = None
+    reveal_order: Literal["option", "count"] | None = None
+    segment_winner_order: Literal["segment", "score"] | None = None
+    numeric_scoring_mode: Literal["tolerance", "proximity"] | None = None
+    reveal_detail: Literal["full", "correct_only", "per_user"] | None = None
+    freeze_final_leaderboard: bool | None = None
+    hide_answer_progress: bool | None = None
+    instant_answer_feedback: bool | None = None
+    require_join_approval: bool | None = None
+    buzzer_mode: bool | None = None
+    audio_cues: bool | None = None
+    require_answer_lock: bool | None = None
+    host_can_answer: bool | None = None
+    team_mode: bool | None = None
+    timer_display_mode: Literal["count_down", "count_up"] | None = None
+    max_duration_seconds: int | None = Field(None, ge=0)  # 0 or unset means unlimited
+    rng_seed: int | None = Field(None, ge=0, le=2**63 - 1)
+    enable_ai_quality_scoring: bool | None = None
+    question_quality_threshold: float | None = Field(None, ge=0.0, le=1.0)
+    llm_model: str | None = Field(None, min_length=1, max_length=100)
+    allow_negative_scoring: bool | None = None
+    wrong_answer_penalty: int | None = Field(None, ge=0, le=1000)
+    store_transcripts: bool | None = None
+    redact_pii: bool | None = None
+    visibility: Literal["public", "unlisted", "private"] | None = None
+    branding: EventBranding | None = None
 
 
 class EventResponse(BaseModel):
@@ -47,11 +113,52 @@ class EventResponse(BaseModel):
     time_per_question: int
     questions_to_generate: int
     question_gen_interval_seconds: int | None = None
+    reveal_order: str = "option"
+    segment_winner_order: str = "segment"
+    numeric_scoring_mode: str = "tolerance"
+    reveal_detail: str = "full"
+    freeze_final_leaderboard: bool = False
+    hide_answer_progress: bool = False
+    instant_answer_feedback: bool = False
+    require_join_approval: bool = False
+    buzzer_mode: bool = False
+    audio_cues: bool = False
+    require_answer_lock: bool = False
+    host_can_answer: bool = False
+    team_mode: bool = False
+    timer_display_mode: str = "count_down"
+    max_duration_seconds: int | None = None
+    started_at: datetime | None = None
+    rng_seed: int | None = None
+    enable_ai_quality_scoring: bool | None = None
+    question_quality_threshold: float | None = None
+    llm_model: str | None = None
+    allow_negative_scoring: bool | None = None
+    wrong_answer_penalty: int | None = None
+    store_transcripts: bool = True
+    redact_pii: bool = False
+    visibility: str = "unlisted"
+    branding: dict[str, Any] | None = None
     join_locked: bool
     join_locked_at: datetime | None = None
+    expires_at: datetime | None = None
     created_at: datetime
 
 
+class SaveEventDraftRequest(BaseModel):
+    """Save (auto-save) an event draft."""
+
+    draft: dict[str, Any]
+
+
+class EventDraftResponse(BaseModel):
+    """Stored event draft."""
+
+    event_id: UUID
+    draft: dict[str, Any] | None = None
+    draft_updated_at: datetime | None = None
+
+
 # Segment schemas
 class CreateSegmentRequest(BaseModel):
     """Create segment request."""
@@ -59,6 +166,12 @@ class CreateSegmentRequest(BaseModel):
     presenter_name: str = Field(..., min_length=1, max_length=255)
     presenter_user_id: UUID | None = None
     title: str | None = None
+    weight: float = Field(default=1.0, gt=0)
+    can_advance: bool = True
+    can_reveal: bool = True
+    can_end: bool = True
+    store_transcripts: bool | None = None
+    notes: str | None = None  # Slides/notes used as extra context for question generation
 
 
 class UpdateSegmentRequest(BaseModel):
@@ -68,6 +181,18 @@ class UpdateSegmentRequest(BaseModel):
     title: str | None = None
     status: str | None = None
     previous_status: str | None = None
+    weight: float | None = Field(default=None, gt=0)
+    can_advance: bool | None = None
+    can_reveal: bool | None = None
+    can_end: bool | None = None
+    store_transcripts: bool | None = None
+    notes: str | None = None  # Empty string clears the notes
+
+
+class AssignPresentersRequest(BaseModel):
+    """Bulk presenter assignment: segment id to the participant's user id."""
+
+    assignments: dict[UUID, UUID] = Field(..., min_length=1)
 
 
 class SegmentResponse(BaseModel):
@@ -81,6 +206,12 @@ class SegmentResponse(BaseModel):
     presenter_user_id: UUID | None = None
     title: str | None = None
     order_index: int
+    weight: float = 1.0
+    can_advance: bool = True
+    can_reveal: bool = True
+    can_end: bool = True
+    store_transcripts: bool | None = None
+    notes: str | None = None
     status: str
     recording_started_at: datetime | None = None
     recording_ended_at: datetime | None = None
@@ -90,6 +221,18 @@ class SegmentResponse(BaseModel):
     created_at: datetime
 
 
+class ParticipantSegmentResponse(BaseModel):
+    """Segment as seen by participants; omits presenter accounts and host-only settings."""
+
+    model_config = ConfigDict(from_attributes=True)
+
+    id: UUID
+    presenter_name: str
+    title: str | None = None
+    order_index: int
+    status: str
+
+
 # Join event schemas
 class JoinEventRequest(BaseModel):
     """Join event request (anonymous)."""
@@ -111,10 +254,90 @@ class JoinEventResponse(BaseModel):
     session_token: str = Field(..., alias="sessionToken")
     display_name: str = Field(..., alias="displayName")
     is_rejoining: bool = Field(..., alias="isRejoining")
+    pending_approval: bool = Field(False, alias="pendingApproval")
 
     model_config = ConfigDict(populate_by_name=True, by_alias=True)
 
 
+class CreateInviteRequest(BaseModel):
+    """Create invite request."""
+
+    max_uses: int = Field(default=1, ge=1, le=1000)
+
+
+class EventInviteResponse(BaseModel):
+    """Invite token for a private event."""
+
+    id: UUID
+    event_id: UUID
+    token: str
+    max_uses: int
+    use_count: int
+    created_at: datetime
+
+    model_config = ConfigDict(from_attributes=True)
+
+
+class CreateTeamRequest(BaseModel):
+    """Create team request."""
+
+    name: str = Field(..., min_length=1, max_length=100)
+
+
+class TeamResponse(BaseModel):
+    """Team in a team-mode event."""
+
+    id: UUID
+    event_id: UUID
+    name: str
+    created_at: datetime
+
+    model_config = ConfigDict(from_attributes=True)
+
+
+class CanvasStrokeResponse(BaseModel):
+    """One stroke on an event's shared canvas."""
+
+    id: UUID
+    user_id: UUID
+    stroke_data: dict[str, Any]
+    stroke_order: int
+    color: str | None = None
+    created_at: datetime
+
+    model_config = ConfigDict(from_attributes=True)
+
+
+class CanvasStrokePageResponse(BaseModel):
+    """A page of canvas strokes, oldest first."""
+
+    strokes: list[CanvasStrokeResponse]
+    has_more: bool
+    # Pass as ``after`` to fetch the next page; None when nothing was returned
+    next_cursor: UUID | None = None
+
+
+class AIModelUsage(BaseModel):
+    """Token usage for one provider model within an event."""
+
+    provider: str
+    model: str
+    calls: int
+    input_tokens: int
+    output_tokens: int
+    estimated_cost_usd: float | None = None  # None when the model has no configured price
+
+
+class AIUsageResponse(BaseModel):
+    """AI token usage and estimated cost for an event."""
+
+    event_id: UUID
+    input_tokens: int
+    output_tokens: int
+    estimated_cost_usd: float  # Sum over priced models only
+    models: list[AIModelUsage]
+
+
 class JoinLockResponse(BaseModel):
     """Join lock status response."""
 
@@ -137,6 +360,82 @@ class EventParticipantResponse(BaseModel):
     total_score: int
     join_status: str
     is_late_joiner: bool
+    team_id: UUID | None = None
+
+
+class EventStateResponse(BaseModel):
+    """Snapshot of the live game state for clients that are not on the WebSocket."""
+
+    phase: str
+    question_index: int
+    total_questions: int
+    question_id: UUID | None = None
+    question_text: str | None = None
+    question_type: str | None = None
+    answers: list[str] = Field(default_factory=list)
+    answer_pending: bool = False
+    time_remaining_seconds: float | None = None
+
+
+class EventConfigResponse(BaseModel):
+    """Event settings a participant client needs to initialize."""
+
+    model_config = ConfigDict(from_attributes=True)
+
+    id: UUID
+    title: str
+    description: str | None = None
+    mode: str
+    status: str
+    visibility: str
+    time_per_question: int
+    num_fake_answers: int
+    numeric_scoring_mode: str
+    reveal_order: str
+    segment_winner_order: str
+    reveal_detail: str
+    timer_display_mode: str
+    buzzer_mode: bool
+    team_mode: bool
+    audio_cues: bool
+    require_answer_lock: bool
+    host_can_answer: bool
+    instant_answer_feedback: bool
+    hide_answer_progress: bool
+    branding: dict[str, Any] | None = None
+    join_locked: bool
+    max_duration_seconds: int | None = None
+    started_at: datetime | None = None
+    expires_at: datetime | None = None
+
+
+class HostEventConfigResponse(EventConfigResponse):
+    """Full event configuration, including host-only settings."""
+
+    join_code: str
+    questions_to_generate: int
+    question_gen_interval_seconds: int | None = None
+    enable_ai_quality_scoring: bool | None = None
+    question_quality_threshold: float | None = None
+    llm_model: str | None = None
+    allow_negative_scoring: bool | None = None
+    wrong_answer_penalty: int | None = None
+    store_transcripts: bool
+    redact_pii: bool
+    require_join_approval: bool
+    freeze_final_leaderboard: bool
+    rng_seed: int | None = None
+
+
+class MistakeResponse(BaseModel):
+    """A question the participant answered incorrectly."""
+
+    question_id: UUID
+    question_text: str
+    correct_answer: str
+    selected_answer: str
+    explanation: str | None = None
+    answered_at: datetime
 
 
 # QR code
@@ -1,9 +1,12 @@
 """Question Pydantic schemas."""
 
 from datetime import datetime
+from typing import Literal
 from uuid import UUID
 
-from pydantic import BaseModel, ConfigDict, Field
+from pydantic import BaseModel, ConfigDict, Field, model_validator
+
+from app.services.scoring import parse_numeric_answer
 
 
 class CreateQuestionRequest(BaseModel):
@@ -12,7 +15,25 @@ class CreateQuestionRequest(BaseModel):
     question_text: str = Field(..., min_length=1)
     correct_answer: str = Field(..., min_length=1)
     fake_answers: list[str] | None = None
+    explanation: str | None = None
     order_index: int | None = None
+    question_type: Literal["multiple_choice", "numeric"] = "multiple_choice"
+    tolerance: float | None = Field(None, ge=0)
+    tolerance_mode: Literal["absolute", "percent"] | None = None
+    bonus_multiplier: float = Field(default=1.0, ge=1.0, le=10.0)
+    time_limit_seconds: int | None = Field(None, ge=5, le=300)
+    tags: list[str] | None = None
+
+    @model_validator(mode="after")
+    def validate_numeric(self) -> "CreateQuestionRequest":
+        if self.question_type == "numeric":
+            if parse_numeric_answer(self.correct_answer) is None:
+                raise ValueError("Numeric questions need a numeric correct_answer")
+            if self.tolerance is None:
+                self.tolerance = 0.0
+            if self.tolerance_mode is None:
+                self.tolerance_mode = "absolute"
+        return self
 
 
 class UpdateQuestionRequest(BaseModel):
@@ -21,7 +42,13 @@ class UpdateQuestionRequest(BaseModel):
     question_text: str | None = None
     correct_answer: str | None = None
     fake_answers: list[str] | None = None
+    explanation: str | None = None
     order_index: int | None = None
+    tolerance: float | None = Field(None, ge=0)
+    tolerance_mode: Literal["absolute", "percent"] | None = None
+    bonus_multiplier: float | None = Field(None, ge=1.0, le=10.0)
+    time_limit_seconds: int | None = Field(None, ge=5, le=300)
+    tags: list[str] | None = None
 
 
 class QuestionResponse(BaseModel):
@@ -33,20 +60,60 @@ class QuestionResponse(BaseModel):
     segment_id: UUID
     question_text: str
     correct_answer: str
+    question_type: str = "multiple_choice"
+    tolerance: float | None = None
+    tolerance_mode: str | None = None
+    bonus_multiplier: float = 1.0
+    time_limit_seconds: int | None = None
     fake_answers: list[str] | None = None
+    tags: list[str] | None = None
     order_index: int
     is_ai_generated: bool | None = None
     source_transcript: str | None = None
+    explanation: str | None = None
     quality_score: float | None = None
+    is_discarded: bool = False
     generated_at: datetime | None = None
     created_at: datetime | None = None
 
 
+class QuestionPageResponse(BaseModel):
+    """One page of a segment's questions."""
+
+    items: list[QuestionResponse]
+    total: int  # Matching questions across all pages
+    limit: int
+    offset: int
+
+
+class ParticipantQuestionResponse(BaseModel):
+    """Question as seen by participants; never says which answer is correct."""
+
+    model_config = ConfigDict(from_attributes=True)
+
+    id: UUID
+    segment_id: UUID
+    question_text: str
+    question_type: str = "multiple_choice"
+    order_index: int
+
+
+class ParticipantQuestionPageResponse(BaseModel):
+    """One page of a segment's questions in the participant view."""
+
+    items: list[ParticipantQuestionResponse]
+    total: int
+    limit: int
+    offset: int
+
+
 class BulkQuestionItem(BaseModel):
     """Single question for bulk import."""
 
     question_text: str
     correct_answer: str
+    time_limit_seconds: int | None = Field(None, ge=5, le=300)
+    tags: list[str] | None = None
 
 
 class BulkImportQuestionsRequest(BaseModel):
@@ -55,6 +122,19 @@ class BulkImportQuestionsRequest(BaseModel):
     questions: list[BulkQuestionItem]
 
 
+class BulkDeleteQuestionsRequest(BaseModel):
+    """Bulk delete questions request."""
+
+    question_ids: list[UUID] = Field(default_factory=list)
+    all: bool = False
+
+
+class BulkDeleteResult(BaseModel):
+    """Bulk delete result."""
+
+    deleted: int
+
+
 class BulkImportResult(BaseModel):
     """Bulk import result."""
 
@@ -63,6 +143,43 @@ class BulkImportResult(BaseModel):
     questions: list[QuestionResponse]
 
 
+class TimingBucket(BaseModel):
+    """Responses whose time falls in [start_ms, end_ms)."""
+
+    start_ms: int
+    end_ms: int
+    count: int
+
+
+class QuestionTimingResponse(BaseModel):
+    """Response time distribution for one question."""
+
+    question_id: UUID
+    time_limit_seconds: int | None = None
+    bucket_ms: int
+    response_count: int
+    median_ms: float | None = None
+    p90_ms: float | None = None
+    buckets: list[TimingBucket]
+
+
+class TagAccuracyResponse(BaseModel):
+    """How participants did on an event's questions with one tag."""
+
+    tag: str
+    question_count: int
+    answer_count: int
+    correct_count: int
+    accuracy: float | None = None  # correct_count / answer_count; None until answered
+
+
+class FakeAnswerPreviewResponse(BaseModel):
+    """Distractors the AI would generate for a question; nothing is saved."""
+
+    question_id: UUID
+    fake_answers: list[str]
+
+
 # Leaderboard
 class LeaderboardEntry(BaseModel):
     """Leaderboard entry."""
@@ -0,0 +1,99 @@
+"""Merging a duplicate user account into the account that is kept."""
+
+from sqlalchemy import delete, select, update
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.models import (
+    CanvasStroke,
+    Event,
+    EventParticipant,
+    ParticipantAnswer,
+    RefreshToken,
+    Segment,
+    SegmentScore,
+    User,
+)
+
+
+async def merge_users(db: AsyncSession, source: User, target: User) -> dict[str, int]:
+    """Move everything owned by ``source`` to ``target``, then delete ``source``.
+
+    Where both users took part in the same event, the source's answers and segment
+    scores are folded into the target's participant row and the totals are summed.
+    Changes are not committed.
+
+    Returns:
+        Number of rows reassigned or merged per table
+    """
+    merged: dict[str, int] = {}
+    for name, model, column in (
+        ("events", Event, Event.host_id),
+        ("segments", Segment, Segment.presenter_user_id),
+        ("canvas_strokes", CanvasStroke, CanvasStroke.user_id),
+    ):
+        result = await db.execute(update(model).where(column == source.id).values({column: target.id}))
+        merged[name] = result.rowcount or 0
+
+    source_participants = (
+        await db.execute(select(EventParticipant).where(EventParticipant.user_id == source.id))
+    ).scalars().all()
+    merged["event_participants"] = len(source_participants)
+    for participant in source_participants:
+        existing = await db.scalar(
+            select(EventParticipant).where(
+                EventParticipant.event_id == participant.event_id,
+                EventParticipant.user_id == target.id,
+            )
+        )
+        if existing is None:
+            participant.user_id = target.id
+        else:
+            await _fold_participant(db, participant, existing)
+
+    await db.execute(delete(RefreshToken).where(RefreshToken.user_id == source.id))
+    await db.flush()
+    await db.delete(source)
+    return merged
+
+
+async def _fold_participant(
+    db: AsyncSession, participant: EventParticipant, into: EventParticipant
+) -> None:
+    """Add one participant's answers and scores to another in the same event and drop it."""
+    # One answer per question: the kept participant's own answer wins
+    already_answered = select(ParticipantAnswer.question_id).where(
+        ParticipantAnswer.participant_id == into.id
+    )
+    await db.execute(
+        update(ParticipantAnswer)
+        .where(
+            ParticipantAnswer.participant_id == participant.id,
+            ParticipantAnswer.question_id.not_in(already_answered),
+        )
+        .values(participant_id=into.id)
+    )
+
+    target_scores = {
+        score.segment_id: score
+        for score in (
+            await db.execute(select(SegmentScore).where(SegmentScore.participant_id == into.id))
+        ).scalars()
+    }
+    source_scores = (
+        await db.execute(select(SegmentScore).where(SegmentScore.participant_id == participant.id))
+    ).scalars().all()
+    for score in source_scores:
+        kept = target_scores.get(score.segment_id)
+        if kept is None:
+            score.participant_id = into.id
+            continue
+        kept.score += score.score
+        kept.questions_answered += score.questions_answered
+        kept.questions_correct += score.questions_correct
+        kept.total_response_time_ms += score.total_response_time_ms
+        await db.delete(score)
+
+    into.total_score += participant.total_score
+    into.total_response_time_ms += participant.total_response_time_ms
+    await db.flush()
+    await db.delete(participant)
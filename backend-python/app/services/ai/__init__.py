@@ -2,12 +2,33 @@
 
 from app.services.ai.base import AIProvider, GeneratedQuestion, QualityAssessment
 from app.services.ai.claude import ClaudeProvider
+from app.services.ai.gemini import GeminiProvider
 from app.services.ai.openai import OpenAIProvider
 
+
+def create_ai_provider(provider: str | None = None, model: str | None = None) -> AIProvider:
+    """Build the provider for a name, defaulting to settings.default_ai_provider.
+
+    Anything other than "openai" or "gemini" uses Claude. ``model`` overrides the
+    provider's configured model (e.g. an event's llm_model).
+    """
+    if provider is None:
+        from app.config import get_settings
+
+        provider = get_settings().default_ai_provider
+    if provider == "openai":
+        return OpenAIProvider(model=model)
+    if provider == "gemini":
+        return GeminiProvider(model=model)
+    return ClaudeProvider(model=model)
+
+
 __all__ = [
     "AIProvider",
     "GeneratedQuestion",
     "QualityAssessment",
     "ClaudeProvider",
+    "GeminiProvider",
     "OpenAIProvider",
+    "create_ai_provider",
 ]
@@ -12,6 +12,8 @@ class GeneratedQuestion:
     correct_answer: str
     fake_answers: list[str]
     source_transcript: str
+    explanation: str | None = None
+    tags: list[str] | None = None
 
 
 @dataclass
@@ -25,6 +27,22 @@ class QualityAssessment:
     issues: list[str]
 
 
+def format_notes_context(notes: str | None) -> str:
+    """Prompt section carrying the presenter's slides/notes, kept apart from the transcript.
+
+    Returns an empty string when there are no notes.
+    """
+    if not notes or not notes.strip():
+        return ""
+    return f"""
+Presenter's slide notes (additional context, separate from the spoken transcript;
+questions may draw on them but should fit what was presented):
+<notes>
+{notes.strip()}
+</notes>
+"""
+
+
 class AIProvider(Protocol):
     """Protocol for AI providers."""
 
@@ -42,8 +60,9 @@ class AIProvider(Protocol):
         transcript: str,
         previous_transcript: str | None = None,
         existing_questions: list[str] | None = None,
+        notes: str | None = None,
     ) -> GeneratedQuestion | None:
-        """Analyze transcript and generate a question if appropriate."""
+        """Analyze transcript (plus optional slide notes) and generate a question if appropriate."""
         ...
 
     async def evaluate_question_quality(
@@ -54,3 +73,7 @@ class AIProvider(Protocol):
     ) -> QualityAssessment | None:
         """Evaluate the quality of a generated question."""
         ...
+
+    async def summarize_transcript(self, transcript: str) -> str:
+        """Summarize what a presenter covered in a transcript."""
+        ...
@@ -5,7 +5,10 @@ import json
 from anthropic import AsyncAnthropic
 
 from app.config import get_settings
-from app.services.ai.base import GeneratedQuestion, QualityAssessment
+from app.services.ai.base import GeneratedQuestion, QualityAssessment, format_notes_context
+from app.services.ai.limiter import ai_call_slot
+from app.services.ai.usage import record_usage
+from app.services.question_tags import normalize_tags
 
 settings = get_settings()
 
@@ -13,8 +16,9 @@ settings = get_settings()
 class ClaudeProvider:
     """Claude AI provider."""
 
-    def __init__(self, api_key: str | None = None):
+    def __init__(self, api_key: str | None = None, model: str | None = None):
         self.client = AsyncAnthropic(api_key=api_key or settings.anthropic_api_key)
+        self.model = model or settings.claude_model
 
     async def generate_fake_answers(
         self,
@@ -31,11 +35,13 @@ Correct Answer: {correct_answer}
 
 Return ONLY a JSON array of fake answers, like: ["fake1", "fake2", "fake3"]"""
 
-        response = await self.client.messages.create(
-            model="claude-3-sonnet-20240229",
-            max_tokens=256,
-            messages=[{"role": "user", "content": prompt}],
-        )
+        async with ai_call_slot("claude"):
+            response = await self.client.messages.create(
+                model=self.model,
+                max_tokens=256,
+                messages=[{"role": "user", "content": prompt}],
+            )
+            record_usage("claude", self.model, getattr(response, "usage", None))
 
         try:
             content = response.content[0].text
@@ -48,6 +54,7 @@ Return ONLY a JSON array of fake answers, like: ["fake1", "fake2", "fake3"]"""
         transcript: str,
         previous_transcript: str | None = None,
         existing_questions: list[str] | None = None,
+        notes: str | None = None,
     ) -> GeneratedQuestion | None:
         """Analyze transcript and generate a question."""
         if len(transcript) < 50:
@@ -56,22 +63,25 @@ Return ONLY a JSON array of fake answers, like: ["fake1", "fake2", "fake3"]"""
         existing_str = "\n".join(existing_questions) if existing_questions else "None"
 
         prompt = f"""Analyze this transcript and generate a quiz question if there's a clear fact or concept.
+Keep the question under {settings.max_question_text_length} characters and each answer under {settings.max_answer_length} characters.
 
 Transcript: {transcript}
-
+{format_notes_context(notes)}
 Existing questions (avoid duplicates):
 {existing_str}
 
 If a good question can be generated, return JSON:
-{{"question": "...", "correct_answer": "...", "fake_answers": ["...", "...", "..."]}}
+{{"question": "...", "correct_answer": "...", "fake_answers": ["...", "...", "..."], "explanation": "One sentence on why the answer is correct", "tags": ["one or two short topics"]}}
 
 If no good question can be made, return: {{"skip": true}}"""
 
-        response = await self.client.messages.create(
-            model="claude-3-sonnet-20240229",
-            max_tokens=512,
-            messages=[{"role": "user", "content": prompt}],
-        )
+        async with ai_call_slot("claude"):
+            response = await self.client.messages.create(
+                model=self.model,
+                max_tokens=512,
+                messages=[{"role": "user", "content": prompt}],
+            )
+            record_usage("claude", self.model, getattr(response, "usage", None))
 
         try:
             content = response.content[0].text
@@ -83,6 +93,8 @@ If no good question can be made, return: {{"skip": true}}"""
                 correct_answer=data["correct_answer"],
                 fake_answers=data.get("fake_answers", []),
                 source_transcript=transcript,
+                explanation=data.get("explanation"),
+                tags=normalize_tags(data.get("tags")),
             )
         except (json.JSONDecodeError, KeyError):
             return None
@@ -103,11 +115,13 @@ Source: {source_transcript or 'N/A'}
 Return JSON with scores 0-1:
 {{"clarity": 0.9, "answerability": 0.8, "factual_accuracy": 0.95, "issues": []}}"""
 
-        response = await self.client.messages.create(
-            model="claude-3-5-haiku-20241022",
-            max_tokens=256,
-            messages=[{"role": "user", "content": prompt}],
-        )
+        async with ai_call_slot("claude"):
+            response = await self.client.messages.create(
+                model="claude-3-5-haiku-20241022",
+                max_tokens=256,
+                messages=[{"role": "user", "content": prompt}],
+            )
+            record_usage("claude", "claude-3-5-haiku-20241022", getattr(response, "usage", None))
 
         try:
             content = response.content[0].text
@@ -125,3 +139,23 @@ Return JSON with scores 0-1:
             )
         except (json.JSONDecodeError, KeyError):
             return None
+
+    async def summarize_transcript(self, transcript: str) -> str:
+        """Summarize a presentation transcript."""
+        prompt = f"""Summarize what the presenter covered in this talk.
+Write a concise summary of 3-5 sentences focusing on the key points.
+
+Transcript:
+{transcript}
+
+Return ONLY the summary text."""
+
+        async with ai_call_slot("claude"):
+            response = await self.client.messages.create(
+                model="claude-3-5-haiku-20241022",
+                max_tokens=512,
+                messages=[{"role": "user", "content": prompt}],
+            )
+            record_usage("claude", "claude-3-5-haiku-20241022", getattr(response, "usage", None))
+
+        return response.content[0].text.strip()
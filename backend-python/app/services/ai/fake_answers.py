@@ -0,0 +1,98 @@
+"""Content-addressed cache of generated fake answers.
+
+Reused quizzes bring the same question back across events; identical
+question/answer pairs share their distractors instead of calling the AI
+provider again.
+"""
+
+import hashlib
+import logging
+import re
+import time
+
+from app.config import get_settings
+from app.services.rng import seeded_random
+
+# key -> (expires_at monotonic seconds, fake answers)
+_cache: dict[str, tuple[float, list[str]]] = {}
+
+
+def _normalize(text: str) -> str:
+    return re.sub(r"\s+", " ", text).strip().lower()
+
+
+def _merge_distinct(existing: list[str], new: list[str], correct_answer: str) -> list[str]:
+    """Append new distractors that don't repeat the correct answer or each other."""
+    seen = {_normalize(correct_answer), *(_normalize(answer) for answer in existing)}
+    merged = list(existing)
+    for answer in new:
+        normalized = _normalize(answer)
+        if normalized and normalized not in seen:
+            seen.add(normalized)
+            merged.append(answer.strip())
+    return merged
+
+
+def fake_answer_cache_key(question: str, correct_answer: str, num_fakes: int) -> str:
+    """Hash of the normalized question, answer and distractor count."""
+    raw = f"{_normalize(question)}\x1f{_normalize(correct_answer)}\x1f{num_fakes}"
+    return hashlib.sha256(raw.encode("utf-8")).hexdigest()
+
+
+def _seeded_order(answers: list[str], rng_seed: int | None, question: str) -> list[str]:
+    """Put distractors in an order fixed by the seed, whatever order they were generated in."""
+    if rng_seed is None:
+        return answers
+    ordered = sorted(answers)
+    seeded_random(rng_seed, _normalize(question)).shuffle(ordered)
+    return ordered
+
+
+async def get_or_generate_fake_answers(
+    question: str,
+    correct_answer: str,
+    num_fakes: int = 3,
+    ai_provider=None,
+    rng_seed: int | None = None,
+    model: str | None = None,
+) -> list[str]:
+    """Return cached distractors for the question, generating and caching them on a miss.
+
+    When the provider returns fewer usable distractors than requested, generation is retried
+    up to ``fake_answer_shortfall_retries`` times; any remaining gap is filled with
+    "Option N" placeholders and logged. Padded results are not cached.
+    With an event rng_seed the distractors come back in a reproducible order.
+    ``model`` picks the provider model when no ``ai_provider`` is given.
+    """
+    settings = get_settings()
+    key = fake_answer_cache_key(question, correct_answer, num_fakes)
+    now = time.monotonic()
+
+    cached = _cache.get(key)
+    if cached and cached[0] > now:
+        return _seeded_order(list(cached[1]), rng_seed, question)
+
+    if ai_provider is None:
+        from app.services.ai import create_ai_provider
+
+        ai_provider = create_ai_provider(settings.default_ai_provider, model)
+
+    fake_answers: list[str] = []
+    for _ in range(1 + max(settings.fake_answer_shortfall_retries, 0)):
+        generated = await ai_provider.generate_fake_answers(
+            question=question,
+            correct_answer=correct_answer,
+            num_fakes=num_fakes,
+        )
+        fake_answers = _merge_distinct(fake_answers, generated or [], correct_answer)[:num_fakes]
+        if len(fake_answers) >= num_fakes:
+            _cache[key] = (now + settings.fake_answer_cache_ttl_seconds, list(fake_answers))
+            return _seeded_order(fake_answers, rng_seed, question)
+
+    logging.warning(
+        f"Only {len(fake_answers)} of {num_fakes} fake answers generated for "
+        f"question {question[:60]!r}; padding with placeholders"
+    )
+    while len(fake_answers) < num_fakes:
+        fake_answers.append(f"Option {len(fake_answers) + 1}")
+    return _seeded_order(fake_answers, rng_seed, question)
@@ -0,0 +1,163 @@
+"""Google Gemini AI provider using the Generative Language REST API."""
+
+import json
+from types import SimpleNamespace
+
+import httpx
+
+from app.config import get_settings
+from app.services.ai.base import GeneratedQuestion, QualityAssessment, format_notes_context
+from app.services.ai.limiter import ai_call_slot
+from app.services.ai.usage import record_usage
+from app.services.question_tags import normalize_tags
+
+settings = get_settings()
+
+GEMINI_API_URL = "https://generativelanguage.googleapis.com/v1beta"
+
+
+class GeminiProvider:
+    """Gemini AI provider."""
+
+    def __init__(
+        self,
+        api_key: str | None = None,
+        transport: httpx.AsyncBaseTransport | None = None,
+        model: str | None = None,
+    ):
+        self.api_key = api_key or settings.gemini_api_key
+        self.model = model or settings.gemini_model
+        self._transport = transport
+
+    async def _generate(self, prompt: str, json_output: bool = True) -> str:
+        """Send one prompt and return the text of the first candidate."""
+        body: dict = {"contents": [{"role": "user", "parts": [{"text": prompt}]}]}
+        if json_output:
+            body["generationConfig"] = {"responseMimeType": "application/json"}
+
+        async with ai_call_slot("gemini"):
+            async with httpx.AsyncClient(
+                base_url=GEMINI_API_URL, timeout=60.0, transport=self._transport
+            ) as client:
+                response = await client.post(
+                    f"/models/{self.model}:generateContent",
+                    headers={"x-goog-api-key": self.api_key or ""},
+                    json=body,
+                )
+                response.raise_for_status()
+                data = response.json()
+            usage = data.get("usageMetadata")
+            record_usage(
+                "gemini",
+                self.model,
+                SimpleNamespace(
+                    input_tokens=usage.get("promptTokenCount", 0),
+                    output_tokens=usage.get("candidatesTokenCount", 0),
+                )
+                if usage
+                else None,
+            )
+
+        candidates = data.get("candidates") or []
+        parts = candidates[0].get("content", {}).get("parts", []) if candidates else []
+        return "".join(part.get("text", "") for part in parts)
+
+    async def generate_fake_answers(
+        self,
+        question: str,
+        correct_answer: str,
+        num_fakes: int = 3,
+    ) -> list[str]:
+        """Generate plausible fake answers."""
+        content = await self._generate(
+            f"""Generate {num_fakes} plausible but incorrect answers for this quiz question.
+The answers should be similar in style and length to the correct answer.
+Question: {question}
+Correct Answer: {correct_answer}
+Return JSON: {{"answers": ["fake1", "fake2", "fake3"]}}"""
+        )
+
+        try:
+            return json.loads(content).get("answers", [])[:num_fakes]
+        except (json.JSONDecodeError, AttributeError):
+            return [f"Option {i+1}" for i in range(num_fakes)]
+
+    async def analyze_and_generate_question(
+        self,
+        transcript: str,
+        previous_transcript: str | None = None,
+        existing_questions: list[str] | None = None,
+        notes: str | None = None,
+    ) -> GeneratedQuestion | None:
+        """Analyze transcript and generate a question."""
+        if len(transcript) < 50:
+            return None
+
+        existing_str = "\n".join(existing_questions) if existing_questions else "None"
+
+        content = await self._generate(
+            f"""Analyze transcript and generate quiz question.
+Keep the question under {settings.max_question_text_length} characters and each answer under {settings.max_answer_length} characters.
+Transcript: {transcript}
+{format_notes_context(notes)}Existing questions: {existing_str}
+Return JSON: {{"question": "...", "correct_answer": "...", "fake_answers": ["...", "...", "..."], "explanation": "One sentence on why the answer is correct", "tags": ["one or two short topics"]}}
+Or if no good question: {{"skip": true}}"""
+        )
+
+        try:
+            data = json.loads(content)
+            if data.get("skip"):
+                return None
+            return GeneratedQuestion(
+                question_text=data["question"],
+                correct_answer=data["correct_answer"],
+                fake_answers=data.get("fake_answers", []),
+                source_transcript=transcript,
+                explanation=data.get("explanation"),
+                tags=normalize_tags(data.get("tags")),
+            )
+        except (json.JSONDecodeError, KeyError, AttributeError):
+            return None
+
+    async def evaluate_question_quality(
+        self,
+        question: str,
+        correct_answer: str,
+        source_transcript: str | None = None,
+    ) -> QualityAssessment | None:
+        """Evaluate question quality."""
+        content = await self._generate(
+            f"""Evaluate quiz question quality (scores 0-1).
+Question: {question}
+Answer: {correct_answer}
+Source: {source_transcript or 'N/A'}
+Return: {{"clarity": 0.9, "answerability": 0.8, "factual_accuracy": 0.95, "issues": []}}"""
+        )
+
+        try:
+            data = json.loads(content)
+            return QualityAssessment(
+                clarity_score=data.get("clarity", 0.5),
+                answerability_score=data.get("answerability", 0.5),
+                factual_accuracy_score=data.get("factual_accuracy", 0.5),
+                overall_score=(
+                    data.get("clarity", 0.5)
+                    + data.get("answerability", 0.5)
+                    + data.get("factual_accuracy", 0.5)
+                ) / 3,
+                issues=data.get("issues", []),
+            )
+        except (json.JSONDecodeError, AttributeError):
+            return None
+
+    async def summarize_transcript(self, transcript: str) -> str:
+        """Summarize a presentation transcript."""
+        content = await self._generate(
+            f"""Summarize what the presenter covered in this talk.
+Write a concise summary of 3-5 sentences focusing on the key points.
+Transcript:
+{transcript}
+Return only the summary text.""",
+            json_output=False,
+        )
+        return content.strip()
@@ -0,0 +1,29 @@
+"""Process-wide cap on concurrent calls to each AI provider.
+
+Calls beyond the limit wait for a free slot instead of failing, which keeps
+many simultaneous events from tripping provider rate limits.
+"""
+
+import asyncio
+from collections.abc import AsyncIterator
+from contextlib import asynccontextmanager
+
+from app.config import get_settings
+
+_semaphores: dict[str, asyncio.Semaphore] = {}
+
+
+def get_ai_semaphore(provider: str) -> asyncio.Semaphore:
+    """Return the shared semaphore for a provider, creating it on first use."""
+    semaphore = _semaphores.get(provider)
+    if semaphore is None:
+        semaphore = asyncio.Semaphore(max(get_settings().ai_max_concurrent_calls, 1))
+        _semaphores[provider] = semaphore
+    return semaphore
+
+
+@asynccontextmanager
+async def ai_call_slot(provider: str) -> AsyncIterator[None]:
+    """Hold one of the provider's concurrency slots for the duration of a call."""
+    async with get_ai_semaphore(provider):
+        yield
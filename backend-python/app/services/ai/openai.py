@@ -5,7 +5,10 @@ import json
 from openai import AsyncOpenAI
 
 from app.config import get_settings
-from app.services.ai.base import GeneratedQuestion, QualityAssessment
+from app.services.ai.base import GeneratedQuestion, QualityAssessment, format_notes_context
+from app.services.ai.limiter import ai_call_slot
+from app.services.ai.usage import record_usage
+from app.services.question_tags import normalize_tags
 
 settings = get_settings()
 
@@ -13,9 +16,9 @@ settings = get_settings()
 class OpenAIProvider:
     """OpenAI AI provider."""
 
-    def __init__(self, api_key: str | None = None):
+    def __init__(self, api_key: str | None = None, model: str | None = None):
         self.client = AsyncOpenAI(api_key=api_key or settings.openai_api_key)
-        self.model = settings.openai_model
+        self.model = model or settings.openai_model
 
     async def generate_fake_answers(
         self,
@@ -24,19 +27,21 @@ class OpenAIProvider:
         num_fakes: int = 3,
     ) -> list[str]:
         """Generate plausible fake answers."""
-        response = await self.client.chat.completions.create(
-            model=self.model,
-            response_format={"type": "json_object"},
-            messages=[
-                {
-                    "role": "user",
-                    "content": f"""Generate {num_fakes} plausible but incorrect answers.
+        async with ai_call_slot("openai"):
+            response = await self.client.chat.completions.create(
+                model=self.model,
+                response_format={"type": "json_object"},
+                messages=[
+                    {
+                        "role": "user",
+                        "content": f"""Generate {num_fakes} plausible but incorrect answers.
 Question: {question}
 Correct Answer: {correct_answer}
 Return JSON: {{"answers": ["fake1", "fake2", "fake3"]}}""",
-                }
-            ],
-        )
+                    }
+                ],
+            )
+            record_usage("openai", self.model, getattr(response, "usage", None))
 
         try:
             content = response.choices[0].message.content
@@ -50,6 +55,7 @@ Return JSON: {{"answers": ["fake1", "fake2", "fake3"]}}""",
         transcript: str,
         previous_transcript: str | None = None,
         existing_questions: list[str] | None = None,
+        notes: str | None = None,
     ) -> GeneratedQuestion | None:
         """Analyze transcript and generate a question."""
         if len(transcript) < 50:
@@ -57,20 +63,23 @@ Return JSON: {{"answers": ["fake1", "fake2", "fake3"]}}""",
 
         existing_str = "\n".join(existing_questions) if existing_questions else "None"
 
-        response = await self.client.chat.completions.create(
-            model=self.model,
-            response_format={"type": "json_object"},
-            messages=[
-                {
-                    "role": "user",
-                    "content": f"""Analyze transcript and generate quiz question.
+        async with ai_call_slot("openai"):
+            response = await self.client.chat.completions.create(
+                model=self.model,
+                response_format={"type": "json_object"},
+                messages=[
+                    {
+                        "role": "user",
+                        "content": f"""Analyze transcript and generate quiz question.
+Keep the question under {settings.max_question_text_length} characters and each answer under {settings.max_answer_length} characters.
 Transcript: {transcript}
-Existing questions: {existing_str}
-Return JSON: {{"question": "...", "correct_answer": "...", "fake_answers": ["...", "...", "..."]}}
+{format_notes_context(notes)}Existing questions: {existing_str}
+Return JSON: {{"question": "...", "correct_answer": "...", "fake_answers": ["...", "...", "..."], "explanation": "One sentence on why the answer is correct", "tags": ["one or two short topics"]}}
 Or if no good question: {{"skip": true}}""",
-                }
-            ],
-        )
+                    }
+                ],
+            )
+            record_usage("openai", self.model, getattr(response, "usage", None))
 
         try:
             content = response.choices[0].message.content
@@ -82,6 +91,8 @@ Or if no good question: {{"skip": true}}""",
                 correct_answer=data["correct_answer"],
                 fake_answers=data.get("fake_answers", []),
                 source_transcript=transcript,
+                explanation=data.get("explanation"),
+                tags=normalize_tags(data.get("tags")),
             )
         except (json.JSONDecodeError, KeyError):
             return None
@@ -91,6 +102,7 @@ Or if no good question: {{"skip": true}}""",
         transcript: str,
         num_questions: int = 5,
         existing_questions: list[str] | None = None,
+        notes: str | None = None,
     ) -> list[GeneratedQuestion]:
         """Generate multiple questions from transcript in a single API call.
         
@@ -101,6 +113,7 @@ Or if no good question: {{"skip": true}}""",
             transcript: Full transcript text
             num_questions: Number of questions to generate
             existing_questions: Previously generated questions to avoid duplicates
+            notes: Presenter's slide notes, sent as separate context
             
         Returns:
             List of GeneratedQuestion objects
@@ -110,17 +123,18 @@ Or if no good question: {{"skip": true}}""",
         
         existing_str = "\n".join(existing_questions) if existing_questions else "None"
         
-        response = await self.client.chat.completions.create(
-            model=self.model,
-            response_format={"type": "json_object"},
-            messages=[
-                {
-                    "role": "user",
-                    "content": f"""Analyze this presentation transcript and generate exactly {num_questions} quiz questions.
+        async with ai_call_slot("openai"):
+            response = await self.client.chat.completions.create(
+                model=self.model,
+                response_format={"type": "json_object"},
+                messages=[
+                    {
+                        "role": "user",
+                        "content": f"""Analyze this presentation transcript and generate exactly {num_questions} quiz questions.
 
 Transcript:
 {transcript}
-
+{format_notes_context(notes)}
 Previously generated questions (avoid duplicates):
 {existing_str}
 
@@ -131,6 +145,9 @@ Requirements:
 - Questions should be diverse and cover different parts of the content
 - Fake answers should be similar in style/length to correct answers
 - Questions should be clear, unambiguous, and answerable from the transcript
+- Each question needs a brief one-sentence explanation of why the correct answer is right
+- Tag each question with one or two short topics (e.g. "history", "networking")
+- Keep each question under {settings.max_question_text_length} characters and each answer under {settings.max_answer_length} characters
 
 Return JSON format:
 {{
@@ -138,17 +155,20 @@ Return JSON format:
     {{
       "question": "What is...?",
       "correct_answer": "The answer",
-      "fake_answers": ["Wrong 1", "Wrong 2", "Wrong 3"]
+      "fake_answers": ["Wrong 1", "Wrong 2", "Wrong 3"],
+      "explanation": "Why the answer is correct",
+      "tags": ["topic"]
     }},
     ...
   ]
 }}
 
 If the transcript doesn't contain enough content for {num_questions} questions, generate as many good questions as possible (minimum 1).""",
-                }
-            ],
-            max_tokens=4096,
-        )
+                    }
+                ],
+                max_tokens=4096,
+            )
+            record_usage("openai", self.model, getattr(response, "usage", None))
         
         try:
             content = response.choices[0].message.content
@@ -161,6 +181,8 @@ If the transcript doesn't contain enough content for {num_questions} questions,
                     correct_answer=q["correct_answer"],
                     fake_answers=q.get("fake_answers", []),
                     source_transcript=transcript[:500],
+                    explanation=q.get("explanation"),
+                    tags=normalize_tags(q.get("tags")),
                 )
                 for q in questions_data
             ]
@@ -177,20 +199,22 @@ If the transcript doesn't contain enough content for {num_questions} questions,
         source_transcript: str | None = None,
     ) -> QualityAssessment | None:
         """Evaluate question quality."""
-        response = await self.client.chat.completions.create(
-            model="gpt-4o-mini",
-            response_format={"type": "json_object"},
-            messages=[
-                {
-                    "role": "user",
-                    "content": f"""Evaluate quiz question quality (scores 0-1).
+        async with ai_call_slot("openai"):
+            response = await self.client.chat.completions.create(
+                model="gpt-4o-mini",
+                response_format={"type": "json_object"},
+                messages=[
+                    {
+                        "role": "user",
+                        "content": f"""Evaluate quiz question quality (scores 0-1).
 Question: {question}
 Answer: {correct_answer}
 Source: {source_transcript or 'N/A'}
 Return: {{"clarity": 0.9, "answerability": 0.8, "factual_accuracy": 0.95, "issues": []}}""",
-                }
-            ],
-        )
+                    }
+                ],
+            )
+            record_usage("openai", "gpt-4o-mini", getattr(response, "usage", None))
 
         try:
             content = response.choices[0].message.content
@@ -208,3 +232,23 @@ Return: {{"clarity": 0.9, "answerability": 0.8, "factual_accuracy": 0.95, "issue
             )
         except (json.JSONDecodeError, KeyError):
             return None
+
+    async def summarize_transcript(self, transcript: str) -> str:
+        """Summarize a presentation transcript."""
+        async with ai_call_slot("openai"):
+            response = await self.client.chat.completions.create(
+                model=self.model,
+                messages=[
+                    {
+                        "role": "user",
+                        "content": f"""Summarize what the presenter covered in this talk.
+Write a concise summary of 3-5 sentences focusing on the key points.
+Transcript:
+{transcript}
+Return only the summary text.""",
+                    }
+                ],
+            )
+            record_usage("openai", self.model, getattr(response, "usage", None))
+
+        return (response.choices[0].message.content or "").strip()
@@ -0,0 +1,106 @@
+"""Token usage tracking for AI provider calls.
+
+Providers report the usage returned with each response. Callers working on
+behalf of an event wrap their AI calls in ``collect_usage()`` and store what
+was collected with ``store_usage()`` so hosts can see what an event cost.
+"""
+
+import logging
+from collections.abc import Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass
+from typing import Any
+from uuid import UUID, uuid4
+
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.config import get_settings
+from app.models import AIUsage
+
+logger = logging.getLogger(__name__)
+
+_collector: ContextVar[list["TokenUsage"] | None] = ContextVar("ai_usage_collector", default=None)
+
+
+@dataclass
+class TokenUsage:
+    """Tokens consumed by a single provider call."""
+
+    provider: str
+    model: str
+    input_tokens: int
+    output_tokens: int
+
+
+def _token_count(usage: Any, *names: str) -> int:
+    for name in names:
+        value = getattr(usage, name, None)
+        if isinstance(value, int):
+            return value
+    return 0
+
+
+def record_usage(provider: str, model: str, usage: Any) -> TokenUsage | None:
+    """Log a response's token usage and add it to the active collector, if any.
+
+    Accepts both Anthropic (input/output_tokens) and OpenAI (prompt/completion_tokens)
+    usage objects; responses without usage are ignored.
+    """
+    if usage is None:
+        return None
+    entry = TokenUsage(
+        provider=provider,
+        model=model,
+        input_tokens=_token_count(usage, "input_tokens", "prompt_tokens"),
+        output_tokens=_token_count(usage, "output_tokens", "completion_tokens"),
+    )
+    logger.info(
+        "ai_usage provider=%s model=%s input_tokens=%d output_tokens=%d",
+        entry.provider,
+        entry.model,
+        entry.input_tokens,
+        entry.output_tokens,
+    )
+    collected = _collector.get()
+    if collected is not None:
+        collected.append(entry)
+    return entry
+
+
+@contextmanager
+def collect_usage() -> Iterator[list[TokenUsage]]:
+    """Collect usage from every provider call made inside the block."""
+    collected: list[TokenUsage] = []
+    token = _collector.set(collected)
+    try:
+        yield collected
+    finally:
+        _collector.reset(token)
+
+
+def store_usage(
+    db: AsyncSession, event_id: UUID, usage: list[TokenUsage], segment_id: UUID | None = None
+) -> None:
+    """Add collected usage rows for an event to the session (not committed)."""
+    for entry in usage:
+        db.add(
+            AIUsage(
+                id=uuid4(),
+                event_id=event_id,
+                segment_id=segment_id,
+                provider=entry.provider,
+                model=entry.model,
+                input_tokens=entry.input_tokens,
+                output_tokens=entry.output_tokens,
+            )
+        )
+
+
+def estimate_cost(model: str, input_tokens: int, output_tokens: int) -> float | None:
+    """Estimated USD cost from the configured price table, or None for unpriced models."""
+    prices = get_settings().ai_token_price_table.get(model)
+    if prices is None:
+        return None
+    input_price, output_price = prices
+    return (input_tokens * input_price + output_tokens * output_price) / 1_000_000
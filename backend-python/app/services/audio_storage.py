@@ -1,20 +1,59 @@
 """Audio chunk storage service using MinIO."""
+import asyncio
+import logging
+from typing import Any, Awaitable, Callable
 from uuid import UUID
 
 import aioboto3
-from botocore.exceptions import ClientError
+from botocore.exceptions import BotoCoreError, ClientError
 
 from app.config import get_settings
 
 settings = get_settings()
 
 
+class StorageUnavailableError(Exception):
+    """S3 kept failing after all retries."""
+
+
+def _is_transient(error: Exception) -> bool:
+    """Timeouts, connection problems and 5xx/throttling responses are worth retrying."""
+    if isinstance(error, (asyncio.TimeoutError, BotoCoreError, OSError)):
+        return True
+    if isinstance(error, ClientError):
+        response = error.response or {}
+        status = response.get("ResponseMetadata", {}).get("HTTPStatusCode", 0)
+        code = response.get("Error", {}).get("Code", "")
+        return status >= 500 or code in {"SlowDown", "RequestTimeout", "Throttling"}
+    return False
+
+
 class AudioStorageService:
     """Service for storing and retrieving audio chunks in MinIO."""
     
     def __init__(self):
         self.session = aioboto3.Session()
         self.bucket = "audio-chunks"
+
+    async def _with_retries(self, operation: str, call: Callable[[], Awaitable[Any]]) -> Any:
+        """Run an S3 call with a per-attempt timeout, retrying transient failures with backoff.
+
+        Raises:
+            StorageUnavailableError: If every attempt failed transiently
+        """
+        attempts = 1 + max(settings.s3_max_retries, 0)
+        for attempt in range(attempts):
+            try:
+                return await asyncio.wait_for(call(), timeout=settings.s3_timeout_seconds)
+            except Exception as e:
+                if not _is_transient(e):
+                    raise
+                if attempt == attempts - 1:
+                    raise StorageUnavailableError(
+                        f"Audio storage unavailable: {operation} failed after {attempts} attempts"
+                    ) from e
+                logging.warning(f"S3 {operation} attempt {attempt + 1} failed: {e}; retrying")
+                await asyncio.sleep(settings.s3_retry_backoff_seconds * (2 ** attempt))
     
     async def store_chunk(
         self,
@@ -33,24 +72,26 @@ class AudioStorageService:
             Storage path (key) in MinIO
             
         Raises:
-            Exception: If storage fails
+            StorageUnavailableError: If MinIO keeps failing after the configured retries
         """
         storage_path = f"{segment_id}/chunk_{chunk_index:04d}.webm"
         
-        async with self.session.client(
-            's3',
-            endpoint_url=f"http://{settings.minio_endpoint}",
-            aws_access_key_id=settings.minio_access_key,
-            aws_secret_access_key=settings.minio_secret_key,
-            use_ssl=settings.minio_use_ssl,
-        ) as s3:
-            await s3.put_object(
-                Bucket=self.bucket,
-                Key=storage_path,
-                Body=audio_data,
-                ContentType='audio/webm'
-            )
+        async def put() -> None:
+            async with self.session.client(
+                's3',
+                endpoint_url=f"http://{settings.minio_endpoint}",
+                aws_access_key_id=settings.minio_access_key,
+                aws_secret_access_key=settings.minio_secret_key,
+                use_ssl=settings.minio_use_ssl,
+            ) as s3:
+                await s3.put_object(
+                    Bucket=self.bucket,
+                    Key=storage_path,
+                    Body=audio_data,
+                    ContentType='audio/webm'
+                )
         
+        await self._with_retries("upload", put)
         return storage_path
     
     async def retrieve_chunk(self, storage_path: str) -> bytes:
@@ -64,17 +105,21 @@ class AudioStorageService:
             
         Raises:
             ClientError: If chunk doesn't exist
+            StorageUnavailableError: If MinIO keeps failing after the configured retries
         """
-        async with self.session.client(
-            's3',
-            endpoint_url=f"http://{settings.minio_endpoint}",
-            aws_access_key_id=settings.minio_access_key,
-            aws_secret_access_key=settings.minio_secret_key,
-            use_ssl=settings.minio_use_ssl,
-        ) as s3:
-            response = await s3.get_object(Bucket=self.bucket, Key=storage_path)
-            async with response['Body'] as stream:
-                return await stream.read()
+        async def get() -> bytes:
+            async with self.session.client(
+                's3',
+                endpoint_url=f"http://{settings.minio_endpoint}",
+                aws_access_key_id=settings.minio_access_key,
+                aws_secret_access_key=settings.minio_secret_key,
+                use_ssl=settings.minio_use_ssl,
+            ) as s3:
+                response = await s3.get_object(Bucket=self.bucket, Key=storage_path)
+                async with response['Body'] as stream:
+                    return await stream.read()
+
+        return await self._with_retries("download", get)
     
     async def delete_segment_chunks(self, segment_id: UUID) -> int:
         """Delete all chunks for a segment.
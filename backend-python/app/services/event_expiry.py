@@ -0,0 +1,68 @@
+"""Expiry of join codes for unused events and of live events that overrun their duration."""
+
+from datetime import datetime, timedelta, timezone
+
+from sqlalchemy import exists, select
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.config import get_settings
+from app.models import Event, EventParticipant, EventStatus, Segment
+
+
+def default_expires_at(now: datetime | None = None) -> datetime | None:
+    """Expiry for a newly created event, or None when join codes don't expire."""
+    ttl_hours = get_settings().join_code_ttl_hours
+    if ttl_hours <= 0:
+        return None
+    return (now or datetime.now(timezone.utc)) + timedelta(hours=ttl_hours)
+
+
+def is_join_code_expired(event: Event, now: datetime | None = None) -> bool:
+    """True once an event that never started is past its expiry.
+
+    Starting the event keeps its code valid for the rest of the session.
+    """
+    if event.expires_at is None or event.status != EventStatus.WAITING.value:
+        return False
+    return event.expires_at <= (now or datetime.now(timezone.utc))
+
+
+async def delete_expired_events(db: AsyncSession, now: datetime | None = None) -> int:
+    """Delete expired events that were never started and have no participants or segments.
+
+    Returns:
+        Number of events removed
+    """
+    has_participants = exists(select(EventParticipant.id).where(EventParticipant.event_id == Event.id))
+    has_segments = exists(select(Segment.id).where(Segment.event_id == Event.id))
+    result = await db.execute(
+        select(Event).where(
+            Event.expires_at <= (now or datetime.now(timezone.utc)),
+            Event.status == EventStatus.WAITING.value,
+            ~has_participants,
+            ~has_segments,
+        )
+    )
+    expired = result.scalars().all()
+    for event in expired:
+        await db.delete(event)
+
+    await db.commit()
+    return len(expired)
+
+
+async def find_overdue_events(db: AsyncSession, now: datetime | None = None) -> list[Event]:
+    """Started, unfinished events that have run past their max_duration_seconds."""
+    now = now or datetime.now(timezone.utc)
+    result = await db.execute(
+        select(Event).where(
+            Event.max_duration_seconds.is_not(None),
+            Event.started_at.is_not(None),
+            Event.status != EventStatus.FINISHED.value,
+        )
+    )
+    return [
+        event
+        for event in result.scalars().all()
+        if event.started_at + timedelta(seconds=event.max_duration_seconds) <= now
+    ]
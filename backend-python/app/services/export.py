@@ -1,4 +1,4 @@
-"""Export service for event results."""
+"""Export service for event results and segment questions."""
 
 import csv
 import io
@@ -56,6 +56,7 @@ async def export_event_data(db: AsyncSession, event_id: UUID) -> dict[str, Any]:
                     "question_text": q.question_text,
                     "correct_answer": q.correct_answer,
                     "order_index": q.order_index,
+                    "is_discarded": q.is_discarded,
                 }
                 for q in questions
             ],
@@ -146,3 +147,73 @@ def export_to_csv(data: dict[str, Any]) -> str:
         ])
 
     return output.getvalue()
+
+
+QUESTION_EXPORT_COLUMNS = [
+    "id",
+    "question_text",
+    "correct_answer",
+    "fake_answers",
+    "question_type",
+    "explanation",
+    "order_index",
+    "quality_score",
+    "time_limit_seconds",
+    "tags",
+    "is_ai_generated",
+    "is_discarded",
+]
+
+
+async def export_segment_questions(db: AsyncSession, segment_id: UUID) -> list[dict[str, Any]]:
+    """Export a segment's questions, including answers, in order.
+
+    Args:
+        db: Database session
+        segment_id: Segment UUID
+
+    Returns:
+        One dictionary per question, keyed by QUESTION_EXPORT_COLUMNS
+    """
+    result = await db.execute(
+        select(Question)
+        .where(Question.segment_id == segment_id)
+        .order_by(Question.order_index, Question.created_at)
+    )
+    return [
+        {
+            "id": str(q.id),
+            "question_text": q.question_text,
+            "correct_answer": q.correct_answer,
+            "fake_answers": list(q.fake_answers or []),
+            "question_type": q.question_type,
+            "explanation": q.explanation,
+            "order_index": q.order_index,
+            "quality_score": q.quality_score,
+            "time_limit_seconds": q.time_limit_seconds,
+            "tags": list(q.tags or []),
+            "is_ai_generated": q.is_ai_generated,
+            "is_discarded": q.is_discarded,
+        }
+        for q in result.scalars().all()
+    ]
+
+
+def questions_to_csv(questions: list[dict[str, Any]]) -> str:
+    """Convert exported questions to CSV string.
+
+    Args:
+        questions: Rows from export_segment_questions
+
+    Returns:
+        CSV string with one row per question; list cells hold JSON arrays
+    """
+    output = io.StringIO()
+    writer = csv.writer(output)
+    writer.writerow(QUESTION_EXPORT_COLUMNS)
+    for question in questions:
+        writer.writerow([
+            json.dumps(value) if isinstance(value, list) else ("" if value is None else value)
+            for value in (question[column] for column in QUESTION_EXPORT_COLUMNS)
+        ])
+    return output.getvalue()
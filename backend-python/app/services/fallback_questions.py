@@ -0,0 +1,93 @@
+"""Fallback questions for segments the AI repeatedly fails to generate anything for."""
+
+import json
+import logging
+from pathlib import Path
+from uuid import UUID, uuid4
+
+from sqlalchemy import func, select
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.config import get_settings
+from app.models import Event, Question, Segment
+from app.services.rng import seeded_random
+
+logger = logging.getLogger(__name__)
+
+# (question, correct answer, distractors) used when no bank file is configured
+DEFAULT_FALLBACK_QUESTIONS: list[tuple[str, str, list[str]]] = [
+    ("Which planet is known as the Red Planet?", "Mars", ["Venus", "Jupiter", "Saturn", "Mercury"]),
+    ("What is the largest ocean on Earth?", "Pacific Ocean", ["Atlantic Ocean", "Indian Ocean", "Arctic Ocean", "Southern Ocean"]),
+    ("How many continents are there?", "7", ["5", "6", "8", "9"]),
+    ("Which gas do plants absorb from the air?", "Carbon dioxide", ["Oxygen", "Nitrogen", "Helium", "Hydrogen"]),
+    ("At what temperature does water boil at sea level?", "100 °C", ["90 °C", "80 °C", "110 °C", "120 °C"]),
+]
+
+
+def load_fallback_bank() -> list[tuple[str, str, list[str]]]:
+    """The configured bank file, or the built-in questions when unset or unreadable.
+
+    The file is a JSON list of {"question", "correct_answer", "fake_answers"} objects.
+    """
+    path = get_settings().fallback_question_bank_path
+    if not path:
+        return DEFAULT_FALLBACK_QUESTIONS
+    try:
+        entries = json.loads(Path(path).read_text())
+        bank = [
+            (entry["question"], entry["correct_answer"], list(entry.get("fake_answers") or []))
+            for entry in entries
+        ]
+    except (OSError, ValueError, KeyError, TypeError):
+        logger.exception("Could not read fallback question bank %s", path)
+        return DEFAULT_FALLBACK_QUESTIONS
+    return bank or DEFAULT_FALLBACK_QUESTIONS
+
+
+def build_fallback_question(segment_id: UUID, event: Event) -> Question:
+    """Pick a bank question for the segment, trimmed to the event's distractor count."""
+    question_text, correct_answer, fake_answers = seeded_random(
+        event.rng_seed, segment_id, "fallback"
+    ).choice(load_fallback_bank())
+    return Question(
+        id=uuid4(),
+        segment_id=segment_id,
+        question_text=question_text,
+        correct_answer=correct_answer,
+        fake_answers=fake_answers[: event.num_fake_answers],
+        order_index=0,
+        is_ai_generated=False,
+    )
+
+
+async def track_generation_result(
+    db: AsyncSession, segment_id: UUID, event: Event, generated: list[Question]
+) -> list[Question]:
+    """Count consecutive empty generation attempts for a segment.
+
+    Once settings.fallback_question_after_failures attempts in a row produce nothing and
+    the segment still has no questions, returns a fallback question in place of the
+    empty result and starts counting again. Otherwise returns ``generated`` unchanged.
+    """
+    segment = await db.get(Segment, segment_id)
+    if segment is None:
+        return generated
+    if generated:
+        segment.generation_failures = 0
+        return generated
+
+    segment.generation_failures = (segment.generation_failures or 0) + 1
+    threshold = get_settings().fallback_question_after_failures
+    if not 0 < threshold <= segment.generation_failures:
+        return generated
+    existing = await db.scalar(
+        select(func.count())
+        .select_from(Question)
+        .where(Question.segment_id == segment_id, Question.is_discarded.is_(False))
+    )
+    if existing:
+        return generated
+
+    segment.generation_failures = 0
+    logger.warning("Using a fallback question for segment %s after %d failed generations", segment_id, threshold)
+    return [build_fallback_question(segment_id, event)]
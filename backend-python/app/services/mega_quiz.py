@@ -1,7 +1,6 @@
 """Mega quiz service for aggregating questions across segments."""
 
 from dataclasses import dataclass
-import random
 from typing import Literal
 from uuid import UUID
 
@@ -9,10 +8,11 @@ from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from app.models import Question, Segment
+from app.services.rng import seeded_random
 
 
 async def aggregate_event_questions(
-    db: AsyncSession, event_id: UUID, max_questions: int | None = None
+    db: AsyncSession, event_id: UUID, max_questions: int | None = None, rng_seed: int | None = None
 ) -> list[Question]:
     """Aggregate questions from all segments of an event.
 
@@ -20,6 +20,7 @@ async def aggregate_event_questions(
         db: Database session
         event_id: Event UUID
         max_questions: Optional limit on number of questions to return
+        rng_seed: Event seed; the same seed picks and orders the same questions
 
     Returns:
         List of questions shuffled and limited to max_questions if specified
@@ -38,14 +39,14 @@ async def aggregate_event_questions(
     for segment in segments:
         question_result = await db.execute(
             select(Question)
-            .where(Question.segment_id == segment.id)
+            .where(Question.segment_id == segment.id, Question.is_discarded.is_(False))
             .order_by(Question.order_index)
         )
         segment_questions = question_result.scalars().all()
         all_questions.extend(segment_questions)
 
     # Shuffle questions to mix content from different segments
-    random.shuffle(all_questions)
+    seeded_random(rng_seed, event_id, "mega_quiz").shuffle(all_questions)
 
     # Limit to max_questions if specified
     if max_questions is not None and len(all_questions) > max_questions:
@@ -72,7 +73,9 @@ async def count_event_questions(db: AsyncSession, event_id: UUID) -> int:
     total = 0
     for segment in segments:
         question_result = await db.execute(
-            select(Question).where(Question.segment_id == segment.id)
+            select(Question).where(
+                Question.segment_id == segment.id, Question.is_discarded.is_(False)
+            )
         )
         total += len(question_result.scalars().all())
 
@@ -0,0 +1,46 @@
+"""Cleanup of rows left behind by deleted segments."""
+
+from uuid import UUID
+
+from sqlalchemy import delete, exists, select
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.models import (
+    AudioChunk,
+    ParticipantAnswer,
+    PresentationTranscript,
+    ProcessingLog,
+    Segment,
+    SegmentScore,
+)
+
+# Tables keyed by segment_id that are not covered by the ORM cascade on Segment
+SEGMENT_CHILD_MODELS = {
+    "transcripts": PresentationTranscript,
+    "segment_scores": SegmentScore,
+    "participant_answers": ParticipantAnswer,
+    "audio_chunks": AudioChunk,
+    "processing_logs": ProcessingLog,
+}
+
+
+async def delete_segment_dependents(db: AsyncSession, segment_id: UUID) -> None:
+    """Delete rows that reference a segment so it can be removed in the same transaction."""
+    for model in SEGMENT_CHILD_MODELS.values():
+        await db.execute(delete(model).where(model.segment_id == segment_id))
+
+
+async def prune_orphaned_rows(db: AsyncSession) -> dict[str, int]:
+    """Delete rows whose segment no longer exists.
+
+    Returns:
+        Number of rows removed per table
+    """
+    removed: dict[str, int] = {}
+    for name, model in SEGMENT_CHILD_MODELS.items():
+        segment_exists = exists(select(Segment.id).where(Segment.id == model.segment_id))
+        result = await db.execute(delete(model).where(~segment_exists))
+        removed[name] = result.rowcount or 0
+
+    await db.commit()
+    return removed
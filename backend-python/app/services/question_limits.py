@@ -0,0 +1,34 @@
+"""Length limits for question and answer text."""
+
+from app.config import get_settings
+
+
+class QuestionTooLongError(ValueError):
+    """Raised when question text exceeds the limits under the reject policy."""
+
+
+def _limit(text: str, max_length: int, field: str, policy: str) -> str:
+    if len(text) <= max_length:
+        return text
+    if policy == "reject":
+        raise QuestionTooLongError(f"{field} exceeds {max_length} characters")
+    return text[: max_length - 1].rstrip() + "…"
+
+
+def enforce_question_limits(
+    question_text: str,
+    correct_answer: str,
+    fake_answers: list[str] | None = None,
+) -> tuple[str, str, list[str] | None]:
+    """Apply the configured max lengths, truncating or raising per question_length_policy."""
+    settings = get_settings()
+    policy = settings.question_length_policy
+    return (
+        _limit(question_text, settings.max_question_text_length, "question_text", policy),
+        _limit(correct_answer, settings.max_answer_length, "correct_answer", policy),
+        (
+            [_limit(a, settings.max_answer_length, "fake_answers", policy) for a in fake_answers]
+            if fake_answers is not None
+            else None
+        ),
+    )
@@ -0,0 +1,93 @@
+"""Question tags (topics) and how well an event's participants did on each."""
+
+from collections import defaultdict
+from typing import Iterable
+from uuid import UUID
+
+from sqlalchemy import select
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.models import ParticipantAnswer, Question, Segment
+
+MAX_TAGS_PER_QUESTION = 10
+MAX_TAG_LENGTH = 50
+
+
+def normalize_tags(tags: Iterable[str] | None) -> list[str] | None:
+    """Trim, lowercase and de-duplicate tags, keeping their order.
+
+    Returns None when no usable tag is left.
+    """
+    normalized: list[str] = []
+    for tag in tags or []:
+        if not isinstance(tag, str):
+            continue
+        tag = " ".join(tag.split()).lower()[:MAX_TAG_LENGTH]
+        if tag and tag not in normalized:
+            normalized.append(tag)
+    return normalized[:MAX_TAGS_PER_QUESTION] or None
+
+
+async def _tagged_answers(db: AsyncSession, event_id: UUID) -> list[tuple[list[str], ParticipantAnswer]]:
+    """Every answer to a tagged, non-discarded question in the event, with its tags."""
+    result = await db.execute(
+        select(Question.tags, ParticipantAnswer)
+        .join(ParticipantAnswer, ParticipantAnswer.question_id == Question.id)
+        .join(Segment, Question.segment_id == Segment.id)
+        .where(
+            Segment.event_id == event_id,
+            Question.tags.is_not(None),
+            Question.is_discarded.is_(False),
+        )
+    )
+    return [(tags, answer) for tags, answer in result.all() if tags]
+
+
+async def tag_accuracy(db: AsyncSession, event_id: UUID) -> list[dict]:
+    """Per-tag question, answer and correct-answer counts for an event, by tag name.
+
+    Accuracy is the share of answers that were correct, or None when a tagged
+    question has no answers yet.
+    """
+    questions: dict[str, set[UUID]] = defaultdict(set)
+    answers: dict[str, int] = defaultdict(int)
+    correct: dict[str, int] = defaultdict(int)
+
+    tagged_questions = await db.execute(
+        select(Question.id, Question.tags)
+        .join(Segment, Question.segment_id == Segment.id)
+        .where(
+            Segment.event_id == event_id,
+            Question.tags.is_not(None),
+            Question.is_discarded.is_(False),
+        )
+    )
+    for question_id, tags in tagged_questions.all():
+        for tag in tags or []:
+            questions[tag].add(question_id)
+
+    for tags, answer in await _tagged_answers(db, event_id):
+        for tag in tags:
+            answers[tag] += 1
+            correct[tag] += int(bool(answer.is_correct))
+
+    return [
+        {
+            "tag": tag,
+            "question_count": len(questions[tag]),
+            "answer_count": answers[tag],
+            "correct_count": correct[tag],
+            "accuracy": correct[tag] / answers[tag] if answers[tag] else None,
+        }
+        for tag in sorted(questions)
+    ]
+
+
+async def tag_scores(db: AsyncSession, event_id: UUID, tag: str) -> dict[UUID, int]:
+    """Points each participant earned on the event's questions with a tag."""
+    tag = (normalize_tags([tag]) or [""])[0]
+    scores: dict[UUID, int] = defaultdict(int)
+    for tags, answer in await _tagged_answers(db, event_id):
+        if tag in tags:
+            scores[answer.participant_id] += answer.points
+    return dict(scores)
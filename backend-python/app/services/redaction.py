@@ -0,0 +1,18 @@
+"""Masking of personal data spoken in presentation transcripts."""
+
+import re
+
+EMAIL_PATTERN = re.compile(r"\b[\w.+-]+@[\w-]+(?:\.[\w-]+)+\b")
+# Ten-digit numbers with an optional country code, e.g. +1 (555) 123-4567 or 555.123.4567
+PHONE_PATTERN = re.compile(
+    r"(?<![\w+])(?:\+\d{1,3}[\s.-]?)?(?:\(\d{3}\)|\d{3})[\s.-]?\d{3}[\s.-]?\d{4}(?!\w)"
+)
+
+EMAIL_PLACEHOLDER = "[email]"
+PHONE_PLACEHOLDER = "[phone]"
+
+
+def redact_pii(text: str) -> str:
+    """Replace email addresses and phone numbers in the text with placeholders."""
+    text = EMAIL_PATTERN.sub(EMAIL_PLACEHOLDER, text)
+    return PHONE_PATTERN.sub(PHONE_PLACEHOLDER, text)
@@ -0,0 +1,32 @@
+"""Response time statistics for calibrating question time limits."""
+
+import math
+
+
+def percentile(sorted_values: list[int], fraction: float) -> float | None:
+    """Linearly interpolated percentile of already sorted values (fraction in [0, 1])."""
+    if not sorted_values:
+        return None
+    position = (len(sorted_values) - 1) * fraction
+    lower = math.floor(position)
+    upper = math.ceil(position)
+    if lower == upper:
+        return float(sorted_values[lower])
+    weight = position - lower
+    return sorted_values[lower] * (1 - weight) + sorted_values[upper] * weight
+
+
+def timing_histogram(response_times_ms: list[int], bucket_ms: int) -> list[dict[str, int]]:
+    """Count response times into fixed-width buckets from 0 up to the slowest response.
+
+    Empty buckets in between are kept so the histogram plots without gaps.
+    """
+    if not response_times_ms:
+        return []
+    counts = [0] * (max(response_times_ms) // bucket_ms + 1)
+    for response_time in response_times_ms:
+        counts[max(response_time, 0) // bucket_ms] += 1
+    return [
+        {"start_ms": idx * bucket_ms, "end_ms": (idx + 1) * bucket_ms, "count": count}
+        for idx, count in enumerate(counts)
+    ]
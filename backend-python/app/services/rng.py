@@ -0,0 +1,14 @@
+"""Randomness that can be pinned per event so reruns make the same choices."""
+
+import random
+
+
+def seeded_random(seed: int | None, *scope: object) -> random.Random:
+    """Random source for one decision, e.g. shuffling a particular question's options.
+
+    With a seed the sequence depends only on the seed and scope, so a rerun of the
+    event reproduces it; without one it is seeded from the OS as usual.
+    """
+    if seed is None:
+        return random.Random()
+    return random.Random(":".join(str(part) for part in (seed, *scope)))
@@ -1,11 +1,13 @@
 """Scoring helpers for quiz questions."""
 
+import re
 from uuid import UUID
 
-from sqlalchemy import select
+from sqlalchemy import literal_column, select, update
+from sqlalchemy.dialects.postgresql import insert as pg_insert
 from sqlalchemy.ext.asyncio import AsyncSession
 
-from app.models import EventParticipant, SegmentScore
+from app.models import EventParticipant, ParticipantAnswer, Segment, SegmentScore, Team
 
 
 def calculate_speed_based_score(time_limit_ms: int, response_time_ms: int) -> int:
@@ -24,6 +26,111 @@ def calculate_speed_based_score(time_limit_ms: int, response_time_ms: int) -> in
     return max(1, min(1000, score))
 
 
+STREAK_BONUS_STEP = 0.1  # Extra share of the score per consecutive correct answer before this one
+STREAK_BONUS_CAP = 0.5
+
+
+def streak_bonus_percent(current_streak: int) -> int:
+    """Bonus percentage for an answer following ``current_streak`` correct answers in a row."""
+    return round(min(max(current_streak, 0) * STREAK_BONUS_STEP, STREAK_BONUS_CAP) * 100)
+
+
+def calculate_speed_based_score_with_streak(
+    time_limit_ms: int, response_time_ms: int, current_streak: int
+) -> int:
+    """
+    Calculate a speed-based score boosted by the answerer's streak.
+
+    Each consecutive correct answer before this one adds 10%, up to +50%.
+    """
+    base_score = calculate_speed_based_score(time_limit_ms, response_time_ms)
+    return round(base_score * (100 + streak_bonus_percent(current_streak)) / 100)
+
+
+def wrong_answer_points(allow_negative_scoring: bool, wrong_answer_penalty: int) -> int:
+    """
+    Points for a wrong answer.
+
+    Minus the penalty when negative scoring is allowed, otherwise 0.
+    """
+    if not allow_negative_scoring:
+        return 0
+    return -max(wrong_answer_penalty, 0)
+
+
+def clamp_delta(delta: int, *totals: int) -> int:
+    """Shrink a negative score delta so that none of the given totals drops below zero."""
+    if delta >= 0:
+        return delta
+    return max(delta, *(-max(total, 0) for total in totals))
+
+
+_NUMERIC_SUFFIXES = {"k": 1_000, "m": 1_000_000, "b": 1_000_000_000}
+
+
+def parse_numeric_answer(raw: str) -> float | None:
+    """
+    Parse a participant's numeric answer.
+
+    Accepts thousands separators, surrounding whitespace, and k/m/b suffixes
+    (e.g. "37,400,000", " 37.4m ", "1_000"). Returns None for non-numeric input.
+    """
+    text = raw.strip().lower().replace(",", "").replace("_", "").replace(" ", "")
+    if not text:
+        return None
+
+    multiplier = 1
+    if text[-1] in _NUMERIC_SUFFIXES:
+        multiplier = _NUMERIC_SUFFIXES[text[-1]]
+        text = text[:-1]
+
+    if not re.fullmatch(r"[+-]?(\d+(\.\d*)?|\.\d+)(e[+-]?\d+)?", text):
+        return None
+    return float(text) * multiplier
+
+
+def numeric_error(guess: float, target: float, tolerance: float, mode: str) -> tuple[float, float]:
+    """Return (distance from target, allowed tolerance in absolute units)."""
+    allowed = abs(target) * tolerance / 100 if mode == "percent" else tolerance
+    return abs(guess - target), max(allowed, 0.0)
+
+
+def calculate_numeric_score(
+    guess: float,
+    target: float,
+    tolerance: float,
+    mode: str,
+    base_score: int,
+    partial_credit: bool,
+) -> tuple[int, bool]:
+    """
+    Score a numeric answer by proximity.
+
+    - Within tolerance: full base_score, counted as correct.
+    - Outside tolerance with partial_credit: linear decay to zero at twice the tolerance.
+    - Otherwise: zero.
+
+    Returns (score, is_correct).
+    """
+    distance, allowed = numeric_error(guess, target, tolerance, mode)
+    if distance <= allowed:
+        return base_score, True
+    if not partial_credit or allowed == 0 or distance >= 2 * allowed:
+        return 0, False
+    return int(base_score * (2 * allowed - distance) / allowed), False
+
+
+def calculate_proximity_score(guess: float, target: float, base_score: int) -> int:
+    """
+    Score an estimation by closeness: base_score / (1 + normalized error).
+
+    The error is normalized by the magnitude of the target (at least 1) so the
+    same relative miss scores the same regardless of scale.
+    """
+    normalized_error = abs(guess - target) / max(abs(target), 1.0)
+    return int(base_score / (1 + normalized_error))
+
+
 async def upsert_segment_score(
     db: AsyncSession, segment_id: UUID, participant_id: UUID
 ) -> SegmentScore:
@@ -44,6 +151,17 @@ async def upsert_segment_score(
     return segment_score
 
 
+async def current_streak(db: AsyncSession, segment_id: UUID, participant_id: UUID) -> int:
+    """Consecutive correct answers the participant has given in the segment so far."""
+    streak = await db.scalar(
+        select(SegmentScore.current_streak).where(
+            SegmentScore.segment_id == segment_id,
+            SegmentScore.participant_id == participant_id,
+        )
+    )
+    return streak or 0
+
+
 async def apply_score(
     db: AsyncSession,
     segment_id: UUID,
@@ -52,24 +170,33 @@ async def apply_score(
     is_correct: bool,
     response_time_ms: int | None,
     commit: bool = True,
-) -> None:
+) -> int:
     """
     Apply scoring deltas to segment and event totals.
 
     - Increment questions_answered for every call.
     - Increment questions_correct only when is_correct is True.
-    - Add delta_score to both segment score and event total_score.
+    - Extend current_streak on a correct answer and reset it otherwise.
+    - Add delta_score to both segment score and event total_score, clamped so neither goes below 0.
     - Accumulate response_time_ms when provided for tie-breaking.
+
+    Returns the delta actually applied, which differs from delta_score only when clamped.
     """
     segment_score = await upsert_segment_score(db, segment_id, participant_id)
+    participant_row = await db.get(EventParticipant, participant_id)
+    totals = [segment_score.score] + ([participant_row.total_score] if participant_row else [])
+    delta_score = clamp_delta(delta_score, *totals)
+
     segment_score.score += delta_score
     segment_score.questions_answered += 1
     if is_correct:
         segment_score.questions_correct += 1
+        segment_score.current_streak += 1
+    else:
+        segment_score.current_streak = 0
     if response_time_ms is not None:
         segment_score.total_response_time_ms += response_time_ms
 
-    participant_row = await db.get(EventParticipant, participant_id)
     if participant_row:
         participant_row.total_score += delta_score
         if response_time_ms is not None:
@@ -77,3 +204,206 @@ async def apply_score(
 
     if commit:
         await db.commit()
+    return delta_score
+
+
+async def record_answer(db: AsyncSession, answer: ParticipantAnswer) -> ParticipantAnswer:
+    """
+    Store a scored answer and apply it to the segment and event totals.
+
+    The answer row and both score updates go into the caller's transaction, so they
+    land together on commit. The row is written with an upsert on
+    (question_id, participant_id): when the participant already has an answer to the
+    question, that row is locked and updated and the totals move only by the
+    difference, so a retried or concurrent submission never counts twice. The stored
+    points are the delta actually applied after clamping at zero, so revoking or
+    changing the answer later undoes exactly what was added. An answer left from
+    before a score reset no longer counts, so it is replaced and scored afresh.
+    Changes are not committed. Returns the stored answer row.
+    """
+    insert = pg_insert(ParticipantAnswer).values(
+        event_id=answer.event_id,
+        segment_id=answer.segment_id,
+        question_id=answer.question_id,
+        participant_id=answer.participant_id,
+        selected_answer=answer.selected_answer,
+        is_correct=answer.is_correct,
+        points=answer.points,
+        response_time_ms=answer.response_time_ms,
+        answered_at=answer.answered_at,
+    )
+    # On conflict, touch no scoring column: the existing row is locked and RETURNING
+    # yields it unchanged. xmax is 0 only for a freshly inserted row.
+    upsert = (
+        insert.on_conflict_do_update(
+            constraint="uq_participant_answer_question",
+            set_={"question_id": insert.excluded.question_id},
+        )
+        .returning(ParticipantAnswer, literal_column("xmax = 0").label("inserted"))
+        .execution_options(populate_existing=True)
+    )
+    stored, inserted = (await db.execute(upsert)).one()
+    replaces_reset_answer = not inserted and not stored.counts_toward_score
+    if replaces_reset_answer:
+        stored.selected_answer = answer.selected_answer
+        stored.is_correct = answer.is_correct
+        stored.points = answer.points
+        stored.response_time_ms = answer.response_time_ms
+        stored.answered_at = answer.answered_at
+        stored.counts_toward_score = True
+    if inserted or replaces_reset_answer:
+        stored.points = await apply_score(
+            db,
+            segment_id=stored.segment_id,
+            participant_id=stored.participant_id,
+            delta_score=stored.points,
+            is_correct=stored.is_correct,
+            response_time_ms=stored.response_time_ms,
+            commit=False,
+        )
+        return stored
+
+    previous = stored
+
+    delta_time_ms = (answer.response_time_ms or 0) - (previous.response_time_ms or 0)
+
+    segment_score = await upsert_segment_score(db, previous.segment_id, previous.participant_id)
+    participant_row = await db.get(EventParticipant, previous.participant_id)
+    totals = [segment_score.score] + ([participant_row.total_score] if participant_row else [])
+    delta_score = clamp_delta(answer.points - previous.points, *totals)
+
+    segment_score.score += delta_score
+    segment_score.questions_correct += int(answer.is_correct) - int(previous.is_correct)
+    if answer.is_correct != previous.is_correct:
+        segment_score.current_streak = segment_score.current_streak + 1 if answer.is_correct else 0
+    segment_score.total_response_time_ms += delta_time_ms
+
+    if participant_row:
+        participant_row.total_score += delta_score
+        participant_row.total_response_time_ms += delta_time_ms
+
+    previous.selected_answer = answer.selected_answer
+    previous.is_correct = answer.is_correct
+    previous.points += delta_score
+    previous.response_time_ms = answer.response_time_ms
+    previous.answered_at = answer.answered_at
+    return previous
+
+
+async def revoke_question_scores(db: AsyncSession, question_id: UUID) -> int:
+    """
+    Undo apply_score for every recorded answer to a question and delete the answers.
+
+    Answers from before a participant's score reset are deleted without touching
+    totals, since the reset already took them out.
+
+    The streaks of affected participants restart, as the run they were on no longer
+    holds. Changes are not committed. Returns the number of answers revoked.
+    """
+    result = await db.execute(
+        select(ParticipantAnswer).where(ParticipantAnswer.question_id == question_id)
+    )
+    answers = result.scalars().all()
+    for answer in answers:
+        if not answer.counts_toward_score:
+            await db.delete(answer)
+            continue
+        segment_score = await upsert_segment_score(db, answer.segment_id, answer.participant_id)
+        segment_score.score -= answer.points
+        segment_score.current_streak = 0
+        segment_score.questions_answered = max(segment_score.questions_answered - 1, 0)
+        if answer.is_correct:
+            segment_score.questions_correct = max(segment_score.questions_correct - 1, 0)
+        if answer.response_time_ms is not None:
+            segment_score.total_response_time_ms -= answer.response_time_ms
+
+        participant_row = await db.get(EventParticipant, answer.participant_id)
+        if participant_row:
+            participant_row.total_score -= answer.points
+            if answer.response_time_ms is not None:
+                participant_row.total_response_time_ms -= answer.response_time_ms
+
+        await db.delete(answer)
+    return len(answers)
+
+
+async def get_weighted_event_scores(db: AsyncSession, event_id: UUID) -> dict[UUID, int]:
+    """
+    Compute master leaderboard scores as the weighted sum of segment scores.
+
+    Participants without any segment score are omitted (treated as 0).
+    """
+    result = await db.execute(
+        select(SegmentScore.participant_id, SegmentScore.score, Segment.weight)
+        .join(Segment, SegmentScore.segment_id == Segment.id)
+        .where(Segment.event_id == event_id)
+    )
+    totals: dict[UUID, float] = {}
+    for participant_id, score, weight in result.all():
+        totals[participant_id] = totals.get(participant_id, 0.0) + score * (weight or 1.0)
+    return {participant_id: round(total) for participant_id, total in totals.items()}
+
+
+async def get_team_scores(
+    db: AsyncSession, event_id: UUID, segment_id: UUID | None = None
+) -> dict[UUID, int]:
+    """
+    Sum member scores per team.
+
+    With segment_id the raw segment scores are summed; otherwise each member's
+    weighted event score is, matching the individual leaderboards. Every team of
+    the event is included, so empty teams score 0.
+    """
+    teams = await db.execute(select(Team.id).where(Team.event_id == event_id))
+    totals: dict[UUID, int] = {team_id: 0 for team_id in teams.scalars().all()}
+
+    members = await db.execute(
+        select(EventParticipant.id, EventParticipant.team_id).where(
+            EventParticipant.event_id == event_id,
+            EventParticipant.team_id.is_not(None),
+        )
+    )
+    team_of = dict(members.all())
+
+    if segment_id is not None:
+        result = await db.execute(
+            select(SegmentScore.participant_id, SegmentScore.score).where(
+                SegmentScore.segment_id == segment_id
+            )
+        )
+        member_scores = dict(result.all())
+    else:
+        member_scores = await get_weighted_event_scores(db, event_id)
+
+    for participant_id, team_id in team_of.items():
+        if team_id in totals:
+            totals[team_id] += member_scores.get(participant_id, 0)
+    return totals
+
+
+async def reset_participant_score(db: AsyncSession, participant: EventParticipant) -> None:
+    """
+    Zero a participant's event total and every segment score they hold, in one transaction.
+
+    Their streaks restart. Recorded answers are kept for review, timing and tag
+    stats but stop counting toward the score, so a later resubmission scores from
+    scratch instead of against a wiped answer.
+    """
+    await db.execute(
+        update(ParticipantAnswer)
+        .where(ParticipantAnswer.participant_id == participant.id)
+        .values(counts_toward_score=False)
+    )
+    segment_scores = await db.execute(
+        select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+    )
+    for segment_score in segment_scores.scalars().all():
+        segment_score.score = 0
+        segment_score.questions_answered = 0
+        segment_score.questions_correct = 0
+        segment_score.total_response_time_ms = 0
+        segment_score.current_streak = 0
+
+    participant.total_score = 0
+    participant.total_response_time_ms = 0
+    await db.commit()
@@ -0,0 +1,80 @@
+"""Saving transcript chunks without losing them to transient database errors."""
+
+import asyncio
+import logging
+from uuid import UUID, uuid4
+
+from sqlalchemy.exc import SQLAlchemyError
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from app.config import get_settings
+from app.models import PresentationTranscript
+
+logger = logging.getLogger(__name__)
+
+# segment -> (chunk_index, text) chunks whose insert kept failing, retried on the next store.
+# Held in process memory, so a restart drops whatever is still waiting.
+_pending: dict[UUID, list[tuple[int, str]]] = {}
+
+
+async def _insert_with_retry(db: AsyncSession, segment_id: UUID, chunk_index: int, chunk_text: str) -> bool:
+    settings = get_settings()
+    attempts = 1 + max(settings.transcript_store_retries, 0)
+    for attempt in range(1, attempts + 1):
+        try:
+            # A savepoint keeps a failed insert from poisoning the caller's transaction
+            async with db.begin_nested():
+                db.add(
+                    PresentationTranscript(
+                        id=uuid4(),
+                        segment_id=segment_id,
+                        chunk_text=chunk_text,
+                        chunk_index=chunk_index,
+                    )
+                )
+            return True
+        except SQLAlchemyError:
+            logger.warning(
+                "Storing transcript chunk %d for segment %s failed (attempt %d/%d)",
+                chunk_index,
+                segment_id,
+                attempt,
+                attempts,
+                exc_info=True,
+            )
+            if attempt < attempts:
+                await asyncio.sleep(max(settings.transcript_store_retry_delay_ms, 0) / 1000)
+    return False
+
+
+async def store_transcript(
+    db: AsyncSession, segment_id: UUID, chunk_text: str | None, chunk_index: int = 0
+) -> bool:
+    """Save a transcript chunk, first retrying any chunks buffered for the segment.
+
+    Each insert is retried up to settings.transcript_store_retries times; chunks that
+    still fail are buffered in memory for the next call. Returns False if anything
+    is left buffered. Pass ``chunk_text=None`` to only retry the buffer.
+    """
+    chunks = _pending.pop(segment_id, [])
+    if chunk_text is not None:
+        chunks.append((chunk_index, chunk_text))
+
+    failed = [
+        (index, text)
+        for index, text in chunks
+        if not await _insert_with_retry(db, segment_id, index, text)
+    ]
+    if failed:
+        logger.error(
+            "Buffered %d transcript chunk(s) for segment %s until the next store", len(failed), segment_id
+        )
+        _pending[segment_id] = failed
+    return not failed
+
+
+async def flush_pending_transcripts(db: AsyncSession, segment_id: UUID) -> bool:
+    """Retry chunks buffered for the segment, e.g. before reading its transcript."""
+    if segment_id not in _pending:
+        return True
+    return await store_transcript(db, segment_id, None)
@@ -1,37 +1,93 @@
 """WebSocket game handler."""
 
+import asyncio
 import logging
-from datetime import datetime, timezone
+import math
+from datetime import datetime, timedelta, timezone
 from typing import Any, Iterable
 from uuid import UUID
 
 from fastapi import APIRouter, WebSocket, WebSocketDisconnect
+from fastapi.responses import JSONResponse
+from pydantic import ValidationError
 from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
 
+from app.auth import get_user_id_from_token
 from app.config import get_settings
 from app.database import async_session_maker, get_db
-from app.models import Event, EventParticipant, JoinStatus, Question, Segment, SegmentScore, SegmentStatus
+from app.models import (
+    DONE_SEGMENT_STATUSES,
+    CanvasStroke,
+    Event,
+    EventParticipant,
+    EventStatus,
+    JoinStatus,
+    NumericScoringMode,
+    ParticipantAnswer,
+    Question,
+    RevealDetail,
+    QuestionType,
+    RevealOrder,
+    Segment,
+    SegmentScore,
+    SegmentStatus,
+    SegmentWinnerOrder,
+    Team,
+    TimerDisplayMode,
+    User,
+    can_transition_segment,
+)
 from app.services.mega_quiz import (
     aggregate_event_questions,
     get_mega_quiz_metadata,
     should_emit_mega_quiz_ready,
 )
-from app.services.scoring import apply_score, calculate_speed_based_score
-from app.ws.hub import hub
+from app.services.rng import seeded_random
+from app.services.scoring import (
+    apply_score,
+    calculate_numeric_score,
+    calculate_proximity_score,
+    calculate_speed_based_score,
+    calculate_speed_based_score_with_streak,
+    current_streak,
+    get_team_scores,
+    revoke_question_scores,
+    get_weighted_event_scores,
+    streak_bonus_percent,
+    numeric_error,
+    parse_numeric_answer,
+    record_answer,
+    wrong_answer_points,
+)
+from app.ws.hub import QUESTION_OPEN_PHASES, hub
 from app.ws.messages import (
     AdminSelectPresenterMessage,
+    AllAnsweredMessage,
+    AnnouncementMessage,
     AnswerDistribution,
     AnswerReceivedMessage,
+    AnswerResultMessage,
+    AnswerSelectedMessage,
+    BuzzResetMessage,
+    BuzzWinnerMessage,
+    ParticipantSelectingMessage,
     ConnectedMessage,
+    CountdownTickMessage,
     ErrorMessage,
+    FinalLeaderboardMessage,
     EventCompleteMessage,
     GameEndedMessage,
     GameStartedMessage,
+    JoinPendingMessage,
+    JoinRejectedMessage,
+    JoinRequestedMessage,
     LeaderboardMessage,
+    LeaderboardSnapshotMessage,
     MegaQuizReadyMessage,
     MegaQuizStartedMessage,
     NoQuestionsGeneratedMessage,
+    NumericGuess,
     ParticipantInfo,
     ParticipantJoinedMessage,
     ParticipantLeftMessage,
@@ -42,14 +98,24 @@ from app.ws.messages import (
     PresenterOverrideNeededMessage,
     PresenterPausedMessage,
     PresenterSelectedMessage,
+    QuestionDiscardedMessage,
+    StrokeRemovedMessage,
     QuestionMessage,
     QuizPhase,
+    ResumedMessage,
+    RevealCueMessage,
     RevealMessage,
     SegmentCompleteMessage,
+    SegmentSwitchedMessage,
+    UpcomingPresenterMessage,
     SegmentWinner,
     SelectPresenterMessage,
     StartPresentationMessage,
+    StatusMessage,
+    TimeRemainingMessage,
+    TimeUpMessage,
     WaitingForPresenterMessage,
+    YourAnswerResultMessage,
     parse_client_message,
 )
 
@@ -57,29 +123,78 @@ router = APIRouter()
 settings = get_settings()
 
 
-def _can_control_segment(event: Event, segment: Segment, user_id: UUID) -> bool:
-    """Return True if the user is host or presenter for the segment."""
-    return event.host_id == user_id or segment.presenter_user_id == user_id
+def _can_control_segment(
+    event: Event, segment: Segment, user_id: UUID, permission: str | None = None
+) -> bool:
+    """Return True if the user is host or presenter for the segment.
+
+    When ``permission`` is given ("advance", "reveal" or "end"), the presenter
+    additionally needs the matching ``can_*`` flag on the segment.
+    """
+    if event.host_id == user_id:
+        return True
+    if segment.presenter_user_id != user_id:
+        return False
+    if permission is None:
+        return True
+    return getattr(segment, f"can_{permission}", True) is not False
 
 
 async def _get_active_segment_with_event(
-    db: AsyncSession, event_uuid: UUID
+    db: AsyncSession, event_uuid: UUID, preferred_segment_id: UUID | None = None
 ) -> Segment | None:
-    """Fetch the first segment for an event that has at least one question."""
-    result = await db.execute(
+    """Fetch the segment to quiz on: the preferred one if it has questions, else the first with questions."""
+    query = (
         select(Segment)
         .join(Event, Segment.event_id == Event.id)
         .join(Question, Question.segment_id == Segment.id)
         .where(Segment.event_id == event_uuid)
-        .order_by(Segment.order_index)
     )
-    segment = result.scalars().first()
+    segment = None
+    if preferred_segment_id is not None:
+        result = await db.execute(query.where(Segment.id == preferred_segment_id))
+        segment = result.scalars().first()
+    if segment is None:
+        result = await db.execute(query.order_by(Segment.order_index))
+        segment = result.scalars().first()
     if segment:
         # Ensure event relationship is available
         await db.refresh(segment, attribute_names=["event"])
     return segment
 
 
+async def _is_segment_controller(
+    db: AsyncSession,
+    event_uuid: UUID,
+    segment_id: UUID,
+    user_id: UUID,
+    permission: str | None = None,
+) -> bool:
+    """_can_control_segment backed by the hub's short-lived cache of granted checks.
+
+    Only grants are cached, so a refused user is re-checked against the database next time.
+    """
+    if hub.is_controller_cached(event_uuid, user_id, segment_id, permission):
+        return True
+    event_row = await db.get(Event, event_uuid)
+    segment_row = await db.get(Segment, segment_id)
+    if not event_row or not segment_row or segment_row.event_id != event_uuid:
+        return False
+    if not _can_control_segment(event_row, segment_row, user_id, permission):
+        return False
+    hub.cache_controller(event_uuid, user_id, segment_id, permission)
+    return True
+
+
+def _answer_options(
+    question_id: UUID, correct_answer: str, fake_answers: list[str] | None, rng_seed: int | None
+) -> list[str]:
+    """The correct and fake answers in the order participants see them."""
+    options = [correct_answer] + (fake_answers or [])
+    seeded_random(rng_seed, question_id).shuffle(options)
+    return options
+
+
 def _build_question_payload(
     question_id: UUID,
     question_text: str,
@@ -88,13 +203,29 @@ def _build_question_payload(
     total_questions: int,
     time_limit: int,
     index: int,
+    question_type: str = QuestionType.MULTIPLE_CHOICE.value,
+    bonus_multiplier: float | None = None,
+    question_started_at: datetime | None = None,
+    timer_display_mode: str = TimerDisplayMode.COUNT_DOWN.value,
+    rng_seed: int | None = None,
+    shown_options: dict[UUID, list[str]] | None = None,
 ) -> QuestionMessage:
-    """Build a QuestionMessage for broadcasting."""
-    import random
-    
-    # Combine correct and fake answers, then shuffle
-    all_answers = [correct_answer] + (fake_answers or [])
-    random.shuffle(all_answers)
+    """Build a QuestionMessage for broadcasting.
+
+    The start time defaults to now for questions that start with this broadcast.
+    With an event rng_seed the option order is the same every time the question is shown.
+    With shown_options (the game state's record of sent orders) a question already sent
+    keeps its order, and a new one's order is recorded there for resends and the reveal.
+    """
+    # Numeric questions are free entry, so no options are sent
+    if question_type == QuestionType.NUMERIC.value:
+        all_answers = []
+    elif shown_options is not None and question_id in shown_options:
+        all_answers = list(shown_options[question_id])
+    else:
+        all_answers = _answer_options(question_id, correct_answer, fake_answers, rng_seed)
+        if shown_options is not None:
+            shown_options[question_id] = list(all_answers)
     
     return QuestionMessage(
         question_id=question_id,
@@ -103,28 +234,200 @@ def _build_question_payload(
         text=question_text,
         answers=all_answers,
         time_limit=time_limit,
+        question_started_at=question_started_at or datetime.now(timezone.utc),
+        timer_display_mode=timer_display_mode,
+        question_type=question_type,
+        is_bonus=(bonus_multiplier or 1.0) > 1.0,
+        bonus_multiplier=bonus_multiplier or 1.0,
     )
 
 
+def _schedule_question_cues(event_id: UUID, session) -> None:
+    """Start the current question's timed broadcasts, replacing any earlier ones.
+
+    Events with audio cues enabled get countdown and time-up cues; time_remaining ticks
+    go out when settings.countdown_sync_interval_seconds is set, and the answer is
+    revealed at time-up when settings.auto_reveal_on_time_up is.
+    """
+    _cancel_question_cues(session)
+    state = session.game_state
+    if state.question_started_at is None or state.current_question_id is None:
+        return
+    question = (state.current_question_id, state.question_started_at, state.time_limit_seconds)
+    if state.audio_cues:
+        session.cue_task = asyncio.create_task(_run_question_cues(event_id, session, *question))
+    if settings.countdown_sync_interval_seconds > 0:
+        session.countdown_task = asyncio.create_task(
+            _run_countdown_sync(event_id, session, *question)
+        )
+    if settings.auto_reveal_on_time_up:
+        session.reveal_task = asyncio.create_task(_reveal_on_time_up(event_id, session, *question))
+
+
+def _cancel_question_cues(session) -> None:
+    if session.cue_task:
+        session.cue_task.cancel()
+        session.cue_task = None
+    if session.countdown_task:
+        session.countdown_task.cancel()
+        session.countdown_task = None
+    # A time-up reveal cancels the cues itself; it must not cancel its own task midway
+    if session.reveal_task and session.reveal_task is not asyncio.current_task():
+        session.reveal_task.cancel()
+    session.reveal_task = None
+
+
+async def _run_countdown_sync(
+    event_id: UUID, session, question_id: UUID, started_at: datetime, time_limit: int
+) -> None:
+    """Broadcast the time left on the server timer every sync interval until it runs out.
+
+    Stops as soon as the question is no longer the open one.
+    """
+    deadline = started_at + timedelta(seconds=time_limit)
+    while True:
+        state = session.game_state
+        if state.current_question_id != question_id or state.quiz_phase not in QUESTION_OPEN_PHASES:
+            return
+        remaining = (deadline - datetime.now(timezone.utc)).total_seconds()
+        if remaining <= 0:
+            return
+        await hub.broadcast(
+            event_id,
+            TimeRemainingMessage(
+                question_id=question_id, remaining_seconds=round(remaining, 1)
+            ).model_dump(mode="json"),
+            replayable=False,
+        )
+        await asyncio.sleep(min(settings.countdown_sync_interval_seconds, remaining))
+
+
+async def _run_question_cues(
+    event_id: UUID, session, question_id: UUID, started_at: datetime, time_limit: int
+) -> None:
+    """Broadcast a tick for each of the final seconds, then TimeUp when the server timer expires.
+
+    Stops as soon as the question is no longer the open one.
+    """
+    deadline = started_at + timedelta(seconds=time_limit)
+    tick_from = max(min(settings.countdown_tick_seconds, time_limit - 1), 0)
+    for seconds_remaining in [*range(tick_from, 0, -1), 0]:
+        delay = (deadline - timedelta(seconds=seconds_remaining) - datetime.now(timezone.utc)).total_seconds()
+        if delay > 0:
+            await asyncio.sleep(delay)
+        elif seconds_remaining:
+            continue  # Tick already passed; only time-up is sent late
+        state = session.game_state
+        if state.current_question_id != question_id or state.quiz_phase not in QUESTION_OPEN_PHASES:
+            return
+        cue = (
+            CountdownTickMessage(question_id=question_id, seconds_remaining=seconds_remaining)
+            if seconds_remaining
+            else TimeUpMessage(question_id=question_id)
+        )
+        await hub.broadcast(event_id, cue.model_dump(mode="json"), replayable=False)
+
+
+async def _reveal_on_time_up(
+    event_id: UUID, session, question_id: UUID, started_at: datetime, time_limit: int
+) -> None:
+    """Reveal the question once its timer (plus the answer grace period) has run out.
+
+    Does nothing if the question was already revealed or moved on from in the meantime.
+    """
+    grace = timedelta(milliseconds=max(settings.answer_timeout_grace_ms, 0))
+    deadline = started_at + timedelta(seconds=time_limit) + grace
+    delay = (deadline - datetime.now(timezone.utc)).total_seconds()
+    if delay > 0:
+        await asyncio.sleep(delay)
+    state = session.game_state
+    if (
+        state.current_question_id != question_id
+        or state.question_started_at != started_at
+        or state.quiz_phase not in QUESTION_OPEN_PHASES
+    ):
+        return
+    try:
+        await reveal_current_question(event_id, session)
+    except Exception:
+        logging.exception("Automatic reveal failed for event %s", event_id)
+
+
+async def _announce_answer_received(
+    event_id: UUID,
+    user_id: UUID,
+    hide_answer_progress: bool,
+    streak: int | None = None,
+    streak_bonus: int | None = None,
+) -> None:
+    """Tell the room someone answered, or only confirm to the answerer when progress is hidden."""
+    message = AnswerReceivedMessage(
+        user_id=user_id, streak=streak, streak_bonus=streak_bonus
+    ).model_dump()
+    if hide_answer_progress:
+        await hub.send_to_user(event_id, user_id, message)
+    else:
+        await hub.broadcast(event_id, message)
+
+
+def _normalize_answer_text(answer: str) -> str:
+    """Grouping key for answers that only differ in case or whitespace."""
+    return " ".join(answer.split()).casefold()
+
+
 def _build_reveal_payload(
     question: Question,
     question_index: int,
     answers: Iterable[str],
+    reveal_order: str = RevealOrder.OPTION.value,
+    options: list[str] | None = None,
+    numeric_target: float | None = None,
+    normalize_text: bool = False,
 ) -> RevealMessage:
-    """Build a RevealMessage using the collected answers."""
+    """Build a RevealMessage using the collected answers.
+
+    With reveal_order "count" the distribution is sorted most-picked first; otherwise it
+    follows the answer option order (answers not among the options come last).
+    For numeric questions (numeric_target set) guesses are grouped by value and
+    ordered by distance from the target. With normalize_text, answers differing only in
+    case or whitespace share one entry, shown as the matching option or the first one seen.
+    """
     # Compute distribution counts
     counts: dict[str, int] = {}
+    values: dict[str, float] = {}
+    display_forms = {_normalize_answer_text(option): option for option in options or []}
     for answer in answers:
+        if numeric_target is not None:
+            value = parse_numeric_answer(answer)
+            if value is None:
+                continue
+            answer = f"{value:g}"
+            values[answer] = value
+        elif normalize_text:
+            answer = display_forms.setdefault(_normalize_answer_text(answer), " ".join(answer.split()))
         counts[answer] = counts.get(answer, 0) + 1
 
+    if numeric_target is not None:
+        ordered = sorted(counts.items(), key=lambda item: abs(values[item[0]] - numeric_target))
+    elif reveal_order == RevealOrder.COUNT.value:
+        ordered = sorted(counts.items(), key=lambda item: item[1], reverse=True)
+    elif options:
+        position = {option: idx for idx, option in enumerate(options)}
+        ordered = sorted(counts.items(), key=lambda item: position.get(item[0], len(position)))
+    else:
+        ordered = list(counts.items())
+
     total = sum(counts.values()) or 1
     distribution = [
         AnswerDistribution(
             answer=answer,
             count=count,
             percentage=(count / total) * 100,
+            distance=(
+                abs(values[answer] - numeric_target) if numeric_target is not None else None
+            ),
         )
-        for answer, count in counts.items()
+        for answer, count in ordered
     ]
 
     return RevealMessage(
@@ -132,12 +435,558 @@ def _build_reveal_payload(
         question_number=question_index + 1,
         question_text=question.question_text,
         correct_answer=question.correct_answer,
+        explanation=question.explanation,
         distribution=distribution,
         segment_leaderboard=[],
         event_leaderboard=[],
     )
 
 
+def _is_answer_correct(question_data: dict[str, Any], answer: str) -> bool:
+    """Check an answer against a cached question, honoring numeric tolerance."""
+    if question_data.get("question_type") == QuestionType.NUMERIC.value:
+        guess = parse_numeric_answer(answer)
+        target = parse_numeric_answer(question_data["correct_answer"])
+        if guess is None or target is None:
+            return False
+        distance, allowed = numeric_error(
+            guess,
+            target,
+            question_data.get("tolerance") or 0.0,
+            question_data.get("tolerance_mode") or "absolute",
+        )
+        return distance <= allowed
+    return answer == question_data["correct_answer"]
+
+
+async def _send_reveal(
+    event_id: UUID,
+    session,
+    reveal_message: RevealMessage,
+    question_data: dict[str, Any],
+    reveal_detail: str,
+    controller_ids: Iterable[UUID] = (),
+) -> None:
+    """Deliver the reveal according to the event's reveal_detail setting.
+
+    "full" broadcasts everything and "correct_only" strips the distribution. In
+    "per_user" mode participants privately get only their own correctness while
+    the host/presenter still receive the full reveal.
+    """
+    if reveal_detail == RevealDetail.CORRECT_ONLY.value:
+        reveal_message.distribution = []
+        reveal_message.guesses = []
+        await hub.broadcast(event_id, reveal_message.model_dump())
+        return
+
+    if reveal_detail != RevealDetail.PER_USER.value:
+        await hub.broadcast(event_id, reveal_message.model_dump())
+        return
+
+    controllers = set(controller_ids)
+    answers = session.game_state.answers_received
+    for participant_id in session.game_state.participants:
+        if participant_id in controllers:
+            continue
+        answer = answers.get(participant_id)
+        await hub.send_to_user(
+            event_id,
+            participant_id,
+            AnswerResultMessage(
+                question_id=reveal_message.question_id,
+                is_correct=answer is not None and _is_answer_correct(question_data, answer),
+                selected_answer=answer,
+            ).model_dump(),
+        )
+    for controller_id in controllers:
+        await hub.send_to_user(event_id, controller_id, reveal_message.model_dump())
+
+
+async def _send_own_answer_result(event_id: UUID, answer: ParticipantAnswer) -> None:
+    """Tell only the answerer whether they were right and what they earned."""
+    await hub.send_to_user(
+        event_id,
+        answer.participant_id,
+        YourAnswerResultMessage(
+            question_id=answer.question_id,
+            is_correct=answer.is_correct,
+            points=answer.points,
+        ).model_dump(),
+    )
+
+
+async def _broadcast_selecting(
+    event_id: UUID,
+    session,
+    user_id: UUID,
+    question_id: UUID,
+    now: datetime | None = None,
+) -> bool:
+    """Broadcast that a participant is choosing an answer, rate limited per user.
+
+    Nothing is recorded; returns whether a broadcast was sent.
+    """
+    state = session.game_state
+    if (
+        state.quiz_phase != QuizPhase.SHOWING_QUESTION
+        or question_id != state.current_question_id
+        or user_id in state.answers_received
+    ):
+        return False
+
+    now = now or datetime.now(timezone.utc)
+    last_sent = state.selecting_broadcast_at.get(user_id)
+    interval_ms = settings.selecting_broadcast_interval_ms
+    if last_sent and (now - last_sent).total_seconds() * 1000 < interval_ms:
+        return False
+
+    state.selecting_broadcast_at[user_id] = now
+    await hub.broadcast(
+        event_id, ParticipantSelectingMessage(user_id=user_id).model_dump(), replayable=False
+    )
+    return True
+
+
+ANSWER_ERROR_MESSAGES = {
+    'duplicate': 'You have already submitted an answer for this question',
+    'too_late': 'Time expired. Your answer was not recorded',
+    'no_question': 'No active question to answer',
+    'no_session': 'Event session not found',
+    'late_join': 'You can start answering with the next question',
+    'paused': 'Quiz is paused while the presenter reconnects',
+    'revealed': 'The answer has already been revealed for this question',
+    'not_buzz_winner': 'Only the participant who buzzed in can answer right now',
+    'presenter': 'The presenter does not answer questions in this event',
+    'no_selection': 'Select an answer before locking it in',
+    'lock_required': 'Select an answer and lock it in to submit',
+    'lock_disabled': 'Answers are submitted directly in this quiz',
+}
+
+
+def _invalid_numeric_answer(session, selected_answer: str) -> bool:
+    """Whether the current question is numeric and the answer isn't a number."""
+    questions = session.game_state.questions
+    current_index = session.game_state.current_question_index
+    return (
+        current_index < len(questions)
+        and questions[current_index].get("question_type") == QuestionType.NUMERIC.value
+        and parse_numeric_answer(selected_answer) is None
+    )
+
+
+async def _score_recorded_answer(
+    event_uuid: UUID,
+    session,
+    user_id: UUID,
+    selected_answer: str,
+    submitted_at: datetime,
+    changed: bool = False,
+) -> None:
+    """Score an answer the hub accepted, then announce it (and its result when enabled).
+
+    A ``changed`` answer replaces the participant's earlier one: it is rescored, but only
+    the answerer hears about it and it doesn't count towards everyone having answered.
+    """
+    hide_answer_progress = False
+    scored_answer = None
+    streak = streak_bonus = None
+    async with async_session_maker() as db:
+        try:
+            event_row = await db.get(Event, event_uuid)
+            hide_answer_progress = bool(event_row and event_row.hide_answer_progress)
+            scored_answer = await _score_answer_submission(
+                db,
+                session=session,
+                participant_id=user_id,
+                selected_answer=selected_answer,
+                submitted_at=submitted_at,
+                numeric_scoring_mode=(
+                    event_row.numeric_scoring_mode
+                    if event_row
+                    else NumericScoringMode.TOLERANCE.value
+                ),
+                wrong_answer_penalty=_wrong_answer_penalty(event_row),
+            )
+            if scored_answer and event_row and event_row.instant_answer_feedback:
+                streak = await current_streak(
+                    db, scored_answer.segment_id, scored_answer.participant_id
+                )
+                streak_bonus = streak_bonus_percent(streak - 1) if scored_answer.is_correct else 0
+            await db.commit()
+        except Exception:
+            await db.rollback()
+            raise
+
+    await _announce_answer_received(
+        event_uuid,
+        user_id,
+        hide_answer_progress or changed,
+        streak=streak,
+        streak_bonus=streak_bonus,
+    )
+    if scored_answer and event_row and event_row.instant_answer_feedback:
+        await _send_own_answer_result(event_uuid, scored_answer)
+    if changed:
+        return
+    if await _notify_all_answered(event_uuid, session) and settings.auto_reveal_when_all_answered:
+        await reveal_current_question(event_uuid, session)
+
+
+async def _notify_all_answered(event_id: UUID, session) -> bool:
+    """Tell the presenter once every participant expected to answer has answered.
+
+    Only the presenter hears it; the room gets it only when the presenter isn't connected.
+    Returns whether it was sent.
+    """
+    state = session.game_state
+    if state.buzzer_mode or state.question_started_at is None:
+        return False
+    expected = {
+        p.user_id
+        for p in state.participants.values()
+        if state.counts_as_answerer(p.user_id)
+        and p.online is not False
+        and not (p.joined_at and p.joined_at > state.question_started_at)
+    }
+    if not expected or not expected <= state.answers_received.keys():
+        return False
+
+    message = AllAnsweredMessage(
+        answer_count=len(state.answers_received),
+        total_participants=len(expected),
+    ).model_dump()
+    if state.current_presenter_id in session.connections:
+        await hub.send_to_user(event_id, state.current_presenter_id, message)
+    else:
+        await hub.broadcast(event_id, message, replayable=False)
+    return True
+
+
+async def _auto_lock_selections(
+    db: AsyncSession,
+    event_uuid: UUID,
+    session,
+    numeric_scoring_mode: str,
+    wrong_answer_penalty: int = 0,
+) -> list[ParticipantAnswer]:
+    """Lock and score the selections nobody locked in before the question closed.
+
+    Each counts as answered when it was selected, so time that ran out while the
+    participant hesitated doesn't void it.
+    """
+    scored = []
+    for user_id, answer, selected_at in await hub.auto_lock_selections(event_uuid):
+        scored_answer = await _score_answer_submission(
+            db,
+            session=session,
+            participant_id=user_id,
+            selected_answer=answer,
+            submitted_at=selected_at,
+            numeric_scoring_mode=numeric_scoring_mode,
+            wrong_answer_penalty=wrong_answer_penalty,
+        )
+        if scored_answer:
+            scored.append(scored_answer)
+    if scored:
+        await db.commit()
+    return scored
+
+
+def _freeze_final_leaderboard(
+    reveal_message: RevealMessage, session, freeze_enabled: bool
+) -> bool:
+    """Strip leaderboards from the reveal of a segment's last question when the event freezes them."""
+    state = session.game_state
+    if not freeze_enabled or state.current_question_index != len(state.questions) - 1:
+        return False
+    reveal_message.segment_leaderboard = []
+    reveal_message.event_leaderboard = []
+    reveal_message.team_leaderboard = []
+    state.final_leaderboard_frozen = True
+    return True
+
+
+def _compact_reveal_leaderboards(
+    reveal_message: RevealMessage, session, segment_id: UUID, top_n: int
+) -> None:
+    """Trim reveal leaderboards to the top N plus entries whose rank or score changed.
+
+    The first reveal of each leaderboard (per segment, and for the event) goes out
+    in full; top_n <= 0 always sends full leaderboards.
+    """
+    if top_n <= 0:
+        return
+    baselines = session.game_state.reveal_leaderboard_baselines
+    for key, board in ((str(segment_id), "segment_leaderboard"), ("event", "event_leaderboard")):
+        entries = getattr(reveal_message, board)
+        previous = baselines.get(key)
+        baselines[key] = {entry["user_id"]: (entry["rank"], entry["score"]) for entry in entries}
+        if previous is None:
+            continue
+        setattr(
+            reveal_message,
+            board,
+            [
+                entry
+                for entry in entries
+                if entry["rank"] <= top_n
+                or previous.get(entry["user_id"]) != (entry["rank"], entry["score"])
+            ],
+        )
+        setattr(reveal_message, f"{board}_is_delta", True)
+
+
+async def _send_status(event_uuid: UUID, websocket) -> None:
+    """Answer a get_status poll with the hub's phase and counts, to the requester only."""
+    state = hub.get_game_state(event_uuid)
+    status = (
+        StatusMessage(
+            phase=state.quiz_phase,
+            participant_count=hub.get_participant_count(event_uuid),
+            question_index=state.current_question_index,
+            total_questions=state.total_questions,
+        )
+        if state
+        else StatusMessage(
+            phase=QuizPhase.NOT_STARTED, participant_count=0, question_index=0, total_questions=0
+        )
+    )
+    await websocket.send_json(status.model_dump(mode="json"))
+
+
+async def _send_resume(
+    event_uuid: UUID, websocket, user_id: UUID, last_question_id: UUID | None = None
+) -> None:
+    """Bring a reconnected client back into the quiz, to that client only.
+
+    Sends the phase and whether the user already answered, then re-sends the open
+    question with only the time left on the server timer.
+    """
+    state = hub.get_game_state(event_uuid)
+    if state is None:
+        await websocket.send_json(
+            ResumedMessage(phase=QuizPhase.NOT_STARTED).model_dump(mode="json")
+        )
+        return
+
+    your_answer = state.answers_received.get(user_id)
+    await websocket.send_json(
+        ResumedMessage(
+            phase=state.quiz_phase,
+            question_id=state.current_question_id,
+            question_index=state.current_question_index,
+            total_questions=state.total_questions,
+            has_answered=your_answer is not None,
+            your_answer=your_answer,
+            question_changed=(
+                last_question_id is not None and last_question_id != state.current_question_id
+            ),
+        ).model_dump(mode="json")
+    )
+
+    index = state.current_question_index
+    if (
+        state.quiz_phase not in QUESTION_OPEN_PHASES
+        or state.question_started_at is None
+        or index >= len(state.questions)
+    ):
+        return
+    now = datetime.now(timezone.utc)
+    elapsed = (now - state.question_started_at).total_seconds()
+    remaining = max(math.ceil(state.time_limit_seconds - elapsed), 0)
+    question = state.questions[index]
+    await websocket.send_json(
+        _build_question_payload(
+            question_id=question["id"],
+            question_text=question["text"],
+            correct_answer=question["correct_answer"],
+            fake_answers=question.get("fake_answers", []),
+            total_questions=state.total_questions,
+            time_limit=remaining,
+            index=index,
+            question_type=question.get("question_type", QuestionType.MULTIPLE_CHOICE.value),
+            bonus_multiplier=question.get("bonus_multiplier"),
+            question_started_at=now,
+            timer_display_mode=state.timer_display_mode,
+            rng_seed=state.rng_seed,
+            shown_options=state.answer_options,
+        ).model_dump(mode="json")
+    )
+
+
+async def _send_leaderboard_snapshot(db: AsyncSession, session, event_id: UUID, websocket) -> str | None:
+    """Send full leaderboards to one client. Returns an error message on failure."""
+    state = session.game_state
+    if state.final_leaderboard_frozen:
+        return "The leaderboard is hidden until the host reveals it"
+
+    event_row = await db.get(Event, event_id)
+    if not event_row:
+        return "Event not found"
+    await websocket.send_json(
+        LeaderboardSnapshotMessage(
+            segment_leaderboard=(
+                await _get_segment_leaderboard(db, state.current_segment_id)
+                if state.current_segment_id
+                else []
+            ),
+            event_leaderboard=await _get_event_leaderboard(db, event_id, session),
+            team_leaderboard=(
+                await _get_team_leaderboard(db, event_id) if event_row.team_mode else []
+            ),
+        ).model_dump(mode="json")
+    )
+    return None
+
+
+async def reveal_current_question(event_uuid: UUID, session, user_id: UUID | None = None) -> str | None:
+    """Close the current question and broadcast its reveal.
+
+    ``user_id`` is the host or presenter asking for the reveal; automatic reveals pass
+    None and skip the permission check. Each question is revealed at most once, so when
+    a manual reveal, time running out and everyone having answered coincide, the first
+    one wins and the rest are ignored. Returns an error message for the requester, if any.
+    """
+    if not session.game_state.questions:
+        return "No active question to reveal"
+
+    current_index = session.game_state.current_question_index
+    question_data = session.game_state.questions[current_index]
+    question = Question(
+        id=question_data["id"],
+        segment_id=session.game_state.current_segment_id,
+        question_text=question_data["text"],
+        correct_answer=question_data["correct_answer"],
+        explanation=question_data.get("explanation"),
+        order_index=current_index,
+    )
+
+    async with async_session_maker() as db:
+        try:
+            event_row = await db.get(Event, event_uuid)
+            segment_row = await db.get(Segment, session.game_state.current_segment_id)
+            if not event_row or not segment_row or (
+                user_id is not None
+                and not _can_control_segment(event_row, segment_row, user_id, "reveal")
+            ):
+                return "Only the host or presenter can reveal answers"
+            if not session.game_state.claim_reveal(question.id):
+                # Already revealed, or a reveal from another trigger is under way
+                return None
+
+            # Time is up: pending selections count as locked
+            await _auto_lock_selections(
+                db,
+                event_uuid,
+                session,
+                event_row.numeric_scoring_mode,
+                _wrong_answer_penalty(event_row),
+            )
+            session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+            await _apply_zero_scores_for_unanswered(db, session)
+            segment_lb = await _get_segment_leaderboard(db, segment_row.id) if segment_row else []
+            event_lb = await _get_event_leaderboard(db, event_uuid, session)
+
+            numeric_target = (
+                parse_numeric_answer(question_data["correct_answer"])
+                if question_data.get("question_type") == QuestionType.NUMERIC.value
+                else None
+            )
+            reveal_message = _build_reveal_payload(
+                question=question,
+                question_index=current_index,
+                answers=session.game_state.answers_received.values(),
+                reveal_order=event_row.reveal_order,
+                options=session.game_state.answer_options.get(question.id, []),
+                numeric_target=numeric_target,
+                normalize_text=settings.normalize_answer_distribution,
+            )
+            if numeric_target is not None:
+                reveal_message.guesses = _rank_numeric_guesses(
+                    session.game_state.answers_received, numeric_target
+                )
+            reveal_message.segment_leaderboard = segment_lb
+            reveal_message.event_leaderboard = event_lb
+            if event_row.team_mode:
+                reveal_message.team_leaderboard = await _get_team_leaderboard(db, event_uuid)
+            _compact_reveal_leaderboards(
+                reveal_message, session, segment_row.id, settings.reveal_leaderboard_top_n
+            )
+            _freeze_final_leaderboard(
+                reveal_message, session, event_row.freeze_final_leaderboard
+            )
+
+            _cancel_question_cues(session)
+            if session.game_state.audio_cues:
+                await hub.broadcast(
+                    event_uuid,
+                    RevealCueMessage(question_id=question.id).model_dump(mode="json"),
+                    replayable=False,
+                )
+            await _send_reveal(
+                event_uuid,
+                session,
+                reveal_message,
+                question_data,
+                event_row.reveal_detail,
+                controller_ids={
+                    cid
+                    for cid in (event_row.host_id, segment_row.presenter_user_id)
+                    if cid
+                },
+            )
+            await hub.broadcast(
+                event_uuid,
+                PhaseChangedMessage(
+                    phase=QuizPhase.REVEALING_ANSWER,
+                    question_index=current_index,
+                    total_questions=session.game_state.total_questions,
+                ).model_dump(),
+            )
+        except Exception:
+            await db.rollback()
+            raise
+        return None
+
+
+async def _reveal_final_leaderboard(
+    db: AsyncSession, session, event_id: UUID, user_id: UUID
+) -> str | None:
+    """Broadcast the leaderboards withheld from the final reveal. Returns an error message on failure."""
+    state = session.game_state
+    event_row = await db.get(Event, event_id)
+    segment_row = await db.get(Segment, state.current_segment_id) if state.current_segment_id else None
+    if not event_row or not segment_row or not _can_control_segment(event_row, segment_row, user_id, "reveal"):
+        return "Only the host or presenter can reveal the final leaderboard"
+    if not state.final_leaderboard_frozen:
+        return "No leaderboard is waiting to be revealed"
+
+    state.final_leaderboard_frozen = False
+    await hub.broadcast(
+        event_id,
+        FinalLeaderboardMessage(
+            segment_leaderboard=await _get_segment_leaderboard(db, segment_row.id),
+            event_leaderboard=await _get_event_leaderboard(db, event_id, session),
+        ).model_dump(),
+    )
+    return None
+
+
+def _rank_numeric_guesses(
+    answers_received: dict[UUID, str], target: float
+) -> list[NumericGuess]:
+    """Return every participant's numeric guess, closest to the target first."""
+    guesses = []
+    for participant_id, answer in answers_received.items():
+        value = parse_numeric_answer(answer)
+        if value is None:
+            continue
+        guesses.append(
+            NumericGuess(user_id=participant_id, guess=value, distance=abs(value - target))
+        )
+    return sorted(guesses, key=lambda g: g.distance)
+
+
 def _calculate_response_time_ms(
     question_started_at: datetime | None, submitted_at: datetime
 ) -> int | None:
@@ -153,8 +1002,15 @@ async def _score_answer_submission(
     participant_id: UUID,
     selected_answer: str,
     submitted_at: datetime,
-) -> bool:
-    """Score a participant's answer for the current question."""
+    numeric_scoring_mode: str = NumericScoringMode.TOLERANCE.value,
+    wrong_answer_penalty: int = 0,
+) -> ParticipantAnswer | None:
+    """Score a participant's answer for the current question.
+
+    A wrong answer that earned nothing loses ``wrong_answer_penalty`` points.
+    Returns the recorded answer, or None when there was nothing to score. Changes are
+    not committed, so the answer and score updates share the caller's transaction.
+    """
     question_index = session.game_state.current_question_index
     questions = session.game_state.questions
     question_started_at = session.game_state.question_started_at
@@ -166,30 +1022,109 @@ async def _score_answer_submission(
         or question_started_at is None
         or segment_id is None
     ):
-        return False
+        return None
 
     question_data = questions[question_index]
     time_limit_ms = session.game_state.time_limit_seconds * 1000
     response_time_ms = _calculate_response_time_ms(question_started_at, submitted_at)
     if response_time_ms is None:
-        return False
+        return None
+    participant_id = await _scoring_participant_id(db, session, participant_id)
+
+    if question_data.get("question_type") == QuestionType.NUMERIC.value:
+        guess = parse_numeric_answer(selected_answer)
+        target = parse_numeric_answer(question_data["correct_answer"])
+        if guess is None or target is None:
+            return None
+        base_score = calculate_speed_based_score(time_limit_ms, response_time_ms)
+        if numeric_scoring_mode == NumericScoringMode.PROXIMITY.value:
+            delta_score = calculate_proximity_score(guess, target, base_score)
+            is_correct = _is_answer_correct(question_data, selected_answer)
+        else:
+            delta_score, is_correct = calculate_numeric_score(
+                guess,
+                target,
+                tolerance=question_data.get("tolerance") or 0.0,
+                mode=question_data.get("tolerance_mode") or "absolute",
+                base_score=base_score,
+                partial_credit=settings.numeric_partial_credit,
+            )
+    else:
+        is_correct = selected_answer == question_data["correct_answer"]
+        delta_score = (
+            calculate_speed_based_score_with_streak(
+                time_limit_ms,
+                response_time_ms,
+                await current_streak(db, segment_id, participant_id),
+            )
+            if is_correct
+            else 0
+        )
 
-    is_correct = selected_answer == question_data["correct_answer"]
-    delta_score = (
-        calculate_speed_based_score(time_limit_ms, response_time_ms)
-        if is_correct
-        else 0
-    )
+    bonus_multiplier = question_data.get("bonus_multiplier") or 1.0
+    if bonus_multiplier != 1.0:
+        delta_score = round(delta_score * bonus_multiplier)
+    if not is_correct and delta_score == 0:
+        delta_score = -wrong_answer_penalty
 
-    await apply_score(
-        db,
+    answer = ParticipantAnswer(
+        event_id=session.event_id,
         segment_id=segment_id,
+        question_id=question_data["id"],
         participant_id=participant_id,
-        delta_score=delta_score,
+        selected_answer=selected_answer,
         is_correct=is_correct,
+        points=delta_score,
         response_time_ms=response_time_ms,
+        answered_at=submitted_at,
     )
-    return True
+    return await record_answer(db, answer)
+
+
+def _wrong_answer_penalty(event: Event | None) -> int:
+    """Points a wrong answer loses in this event; 0 unless negative scoring is allowed."""
+    allow_negative_scoring = settings.allow_negative_scoring
+    wrong_answer_penalty = settings.wrong_answer_penalty
+    if event is not None:
+        if event.allow_negative_scoring is not None:
+            allow_negative_scoring = event.allow_negative_scoring
+        if event.wrong_answer_penalty is not None:
+            wrong_answer_penalty = event.wrong_answer_penalty
+    return -wrong_answer_points(allow_negative_scoring, wrong_answer_penalty)
+
+
+async def _scoring_participant_id(db: AsyncSession, session, user_id: UUID) -> UUID:
+    """Participant row a user's answers are scored against.
+
+    A playing presenter connects with their user id, so they get an event participant
+    row (created on first use) to hold their scores; everyone else scores as themselves.
+    """
+    state = session.game_state
+    if not state.host_can_answer or user_id != state.current_presenter_id:
+        return user_id
+    if await db.get(EventParticipant, user_id):
+        return user_id  # Presenter joined as a participant
+
+    participant = await db.scalar(
+        select(EventParticipant).where(
+            EventParticipant.event_id == session.event_id, EventParticipant.user_id == user_id
+        )
+    )
+    if participant is None:
+        user = await db.get(User, user_id)
+        participant = EventParticipant(
+            event_id=session.event_id,
+            user_id=user_id,
+            display_name=(user.display_name or user.username) if user else "Presenter",
+            avatar_url=user.avatar_url if user else None,
+            avatar_type=user.avatar_type if user else None,
+            device_id=user_id,
+            join_status=JoinStatus.ACTIVE_IN_QUIZ.value,
+            join_timestamp=datetime.now(timezone.utc),
+        )
+        db.add(participant)
+        await db.flush()
+    return participant.id
 
 
 async def _apply_zero_scores_for_unanswered(
@@ -216,10 +1151,13 @@ async def _apply_zero_scores_for_unanswered(
         if participant.user_id in answered_ids:
             continue
 
+        if not session.game_state.counts_as_answerer(participant.user_id):
+            continue
+
         await apply_score(
             db,
             segment_id=segment_id,
-            participant_id=participant.user_id,
+            participant_id=await _scoring_participant_id(db, session, participant.user_id),
             delta_score=0,
             is_correct=False,
             response_time_ms=None,
@@ -240,18 +1178,17 @@ async def _apply_zero_scores_for_unanswered(
 
 
 async def _get_event_leaderboard(db: AsyncSession, event_id: UUID, session=None) -> list[dict[str, Any]]:
-    """Fetch event leaderboard ordered by score and response time."""
+    """Fetch event leaderboard ordered by weighted segment score and response time."""
     from app.models import EventParticipant
 
     result = await db.execute(
-        select(EventParticipant)
-        .where(EventParticipant.event_id == event_id)
-        .order_by(
-            EventParticipant.total_score.desc(),
-            EventParticipant.total_response_time_ms.asc(),
-        )
+        select(EventParticipant).where(EventParticipant.event_id == event_id)
+    )
+    weighted_scores = await get_weighted_event_scores(db, event_id)
+    participants = sorted(
+        result.scalars().all(),
+        key=lambda p: (-weighted_scores.get(p.id, 0), p.total_response_time_ms),
     )
-    participants = result.scalars().all()
     leaderboard = []
     
     # Get current connection status from hub session if available
@@ -274,7 +1211,7 @@ async def _get_event_leaderboard(db: AsyncSession, event_id: UUID, session=None)
                 "user_id": participant.id,
                 "username": participant.display_name,
                 "avatar_url": participant.avatar_url,
-                "score": participant.total_score,
+                "score": weighted_scores.get(participant.id, 0),
                 "is_late_joiner": participant.is_late_joiner,
                 "response_time_ms": participant.total_response_time_ms,
                 "is_present": is_present,
@@ -313,8 +1250,42 @@ async def _get_segment_leaderboard(db: AsyncSession, segment_id: UUID) -> list[d
     return leaderboard
 
 
+async def _get_team_leaderboard(
+    db: AsyncSession, event_id: UUID, segment_id: UUID | None = None
+) -> list[dict[str, Any]]:
+    """Fetch team standings for the event, or for one segment when segment_id is given."""
+    scores = await get_team_scores(db, event_id, segment_id)
+    result = await db.execute(select(Team).where(Team.event_id == event_id))
+    teams = sorted(result.scalars().all(), key=lambda team: (-scores.get(team.id, 0), team.name))
+    return [
+        {
+            "rank": idx + 1,
+            "team_id": team.id,
+            "name": team.name,
+            "score": scores.get(team.id, 0),
+        }
+        for idx, team in enumerate(teams)
+    ]
+
+
+async def _assign_team(
+    db: AsyncSession, event_uuid: UUID, participant: EventParticipant, team_id: UUID
+) -> bool:
+    """Put a participant on one of the event's teams; False if the team isn't in this event."""
+    team = await db.get(Team, team_id)
+    if not team or team.event_id != event_uuid:
+        return False
+    participant.team_id = team.id
+    await db.commit()
+    return True
+
+
 async def _get_segment_winners(db: AsyncSession, event_id: UUID) -> list[SegmentWinner]:
-    """Return winners for each completed segment."""
+    """Return winners for each completed segment.
+
+    Winners follow segment order, or highest winning score first when the event's
+    segment_winner_order is "score". Segments nobody scored in are left out.
+    """
     result = await db.execute(
         select(SegmentScore, Segment, EventParticipant)
         .join(Segment, SegmentScore.segment_id == Segment.id)
@@ -334,15 +1305,85 @@ async def _get_segment_winners(db: AsyncSession, event_id: UUID) -> list[Segment
             winner_name=participant.display_name,
             winner_score=score_row.score,
         )
+    event = await db.get(Event, event_id)
+    if event and event.segment_winner_order == SegmentWinnerOrder.SCORE.value:
+        # Stable sort, so equal scores keep segment order
+        return sorted(winners.values(), key=lambda winner: winner.winner_score, reverse=True)
     return list(winners.values())
 
 
+async def _switch_to_segment(
+    db: AsyncSession, session, event_id: UUID, segment_id: UUID, user_id: UUID
+) -> tuple[SegmentSwitchedMessage | None, str | None]:
+    """Make segment_id the current segment for the event and reset question state.
+
+    A question left open is abandoned: its timers stop and its selections, buzz and
+    reveal state are dropped, and a segment left mid-quiz goes back to quiz_ready so
+    it can be started again later. Returns the broadcast payload, or an error message when the switch is not allowed.
+    """
+    event = await db.get(Event, event_id)
+    if not event:
+        return None, "Event not found"
+    if event.host_id != user_id:
+        return None, "Only the host can switch segments"
+
+    result = await db.execute(
+        select(Segment).where(Segment.id == segment_id, Segment.event_id == event_id)
+    )
+    segment = result.scalar_one_or_none()
+    if not segment:
+        return None, "Segment not found"
+    if segment.status in _DONE_STATUS_VALUES:
+        return None, f"Cannot switch to a {segment.status} segment"
+
+    _cancel_question_cues(session)
+    await hub.clear_answers(event_id)
+
+    state = session.game_state
+    left_segment = (
+        await db.get(Segment, state.current_segment_id) if state.current_segment_id else None
+    )
+    for abandoned in (left_segment, segment):
+        if abandoned is not None and abandoned.status == SegmentStatus.QUIZZING.value:
+            abandoned.status = SegmentStatus.QUIZ_READY.value
+    await db.commit()
+
+    state.current_segment_id = segment.id
+    state.current_presenter_id = segment.presenter_user_id
+    state.questions = []
+    state.total_questions = 0
+    state.current_question_index = 0
+    state.current_question_id = None
+    state.question_started_at = None
+    state.revealed_question = None
+    state.scored_question_ids.clear()
+    state.answer_options.clear()
+    state.final_leaderboard_frozen = False
+    state.presenter_paused = False
+    state.presenter_pause_reason = None
+    state.quiz_phase = QuizPhase.NOT_STARTED
+
+    return (
+        SegmentSwitchedMessage(
+            segment_id=segment.id,
+            segment_title=segment.title,
+            presenter_id=segment.presenter_user_id,
+            presenter_name=segment.presenter_name,
+            segment_status=segment.status,
+        ),
+        None,
+    )
+
+
+_DONE_STATUS_VALUES = frozenset(status.value for status in DONE_SEGMENT_STATUSES)
+
+
 async def _maybe_emit_completion_payload(
     db: AsyncSession, event_id: UUID
 ) -> MegaQuizReadyMessage | EventCompleteMessage | None:
-    """If all segments completed, return mega quiz ready or final event payload."""
+    """If every segment is done (completed or skipped), return mega quiz ready or final event payload."""
     incomplete = await db.execute(
-        select(Segment).where(Segment.event_id == event_id, Segment.status != SegmentStatus.COMPLETED.value)
+        select(Segment).where(Segment.event_id == event_id, Segment.status.notin_(_DONE_STATUS_VALUES))
     )
     if incomplete.first():
         return None
@@ -370,18 +1411,1069 @@ async def _maybe_emit_completion_payload(
     )
 
 
+async def _emit_event_completion(db: AsyncSession, session, event_uuid: UUID) -> bool:
+    """Broadcast the mega quiz or final results once every segment is done.
+
+    Returns True if the completion payload was sent.
+    """
+    completion = await _maybe_emit_completion_payload(db, event_uuid)
+    if not completion:
+        return False
+    session.game_state.quiz_phase = (
+        QuizPhase.MEGA_QUIZ_READY
+        if isinstance(completion, MegaQuizReadyMessage)
+        else QuizPhase.EVENT_COMPLETE
+    )
+    await hub.broadcast(event_uuid, completion.model_dump())
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=session.game_state.quiz_phase,
+            question_index=session.game_state.current_question_index,
+            total_questions=session.game_state.total_questions,
+        ).model_dump(),
+    )
+    return True
+
+
+async def _get_upcoming_presenter(
+    db: AsyncSession, event_id: UUID, current_segment: Segment
+) -> UpcomingPresenterMessage | None:
+    """Find the next unfinished segment after the current one and describe its presenter."""
+    result = await db.execute(
+        select(Segment)
+        .where(
+            Segment.event_id == event_id,
+            Segment.id != current_segment.id,
+            Segment.order_index > current_segment.order_index,
+            Segment.status.notin_(_DONE_STATUS_VALUES),
+        )
+        .order_by(Segment.order_index)
+    )
+    next_segment = result.scalars().first()
+    if not next_segment:
+        return None
+    return UpcomingPresenterMessage(
+        segment_id=next_segment.id,
+        segment_title=next_segment.title,
+        presenter_id=next_segment.presenter_user_id,
+        presenter_name=next_segment.presenter_name,
+    )
+
+
+async def _announce_upcoming_presenter(
+    db: AsyncSession,
+    event_id: UUID,
+    current_segment: Segment | UUID,
+    question_index: int,
+    total_questions: int,
+) -> None:
+    """Broadcast the next presenter once the segment reaches its final question."""
+    if not settings.announce_upcoming_presenter or question_index != total_questions - 1:
+        return
+    if isinstance(current_segment, UUID):
+        current_segment = await db.get(Segment, current_segment)
+        if not current_segment:
+            return
+    upcoming = await _get_upcoming_presenter(db, event_id, current_segment)
+    if upcoming:
+        await hub.broadcast(event_id, upcoming.model_dump())
+
+
+async def _has_playable_questions(db: AsyncSession, segment_id: UUID) -> bool:
+    """Whether the segment has any question that hasn't been discarded."""
+    question_id = await db.scalar(
+        select(Question.id)
+        .where(Question.segment_id == segment_id, Question.is_discarded.is_(False))
+        .limit(1)
+    )
+    return question_id is not None
+
+
+async def _next_segment_with_questions(db: AsyncSession, segment: Segment) -> Segment | None:
+    """The first later segment in the event that has questions and can still be quizzed."""
+    result = await db.execute(
+        select(Segment)
+        .where(Segment.event_id == segment.event_id, Segment.order_index > segment.order_index)
+        .order_by(Segment.order_index)
+    )
+    for candidate in result.scalars():
+        if can_transition_segment(
+            candidate.status, SegmentStatus.QUIZZING.value
+        ) and await _has_playable_questions(db, candidate.id):
+            return candidate
+    return None
+
+
+_GENERATING_STATUS_VALUES = frozenset(
+    {SegmentStatus.RECORDING.value, SegmentStatus.RECORDING_PAUSED.value}
+)
+
+
+async def _wait_for_questions(event_uuid: UUID, session, user_id: UUID) -> None:
+    """Hold the room in WAITING_FOR_QUESTIONS until generation gives the segment questions."""
+    state = session.game_state
+    state.waiting_starter_id = user_id
+    state.quiz_phase = QuizPhase.WAITING_FOR_QUESTIONS
+    state.question_started_at = None
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=QuizPhase.WAITING_FOR_QUESTIONS, question_index=0, total_questions=0
+        ).model_dump(),
+    )
+
+
+async def _stop_waiting_for_questions(event_uuid: UUID, session) -> None:
+    """Call off a wait for questions and return the room to NOT_STARTED."""
+    state = session.game_state
+    state.waiting_starter_id = None
+    state.quiz_phase = QuizPhase.NOT_STARTED
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=QuizPhase.NOT_STARTED, question_index=0, total_questions=0
+        ).model_dump(),
+    )
+
+
+async def start_waiting_segment(db: AsyncSession, event_uuid: UUID, segment_id: UUID) -> bool:
+    """Start a segment that was waiting for questions, now that it has some.
+
+    Returns whether the quiz started.
+    """
+    session = hub.event_sessions.get(event_uuid)
+    if (
+        session is None
+        or session.game_state.quiz_phase != QuizPhase.WAITING_FOR_QUESTIONS
+        or session.game_state.current_segment_id != segment_id
+        or session.game_state.waiting_starter_id is None
+    ):
+        return False
+    return await start_event_game(db, event_uuid, session.game_state.waiting_starter_id) is None
+
+
+def _question_time_limit(question: dict[str, Any], default_seconds: int) -> int:
+    """A question's own time limit when it has a usable one, else the event's."""
+    time_limit = question.get("time_limit_seconds")
+    return time_limit if time_limit and time_limit > 0 else default_seconds
+
+
+async def start_event_game(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """Start the quiz for the active segment and broadcast it to the room.
+
+    Shared by the WebSocket start_game command and the REST start endpoint.
+    When the current segment has no questions, settings.empty_segment_start decides
+    whether to fail, skip to the next segment with questions, or wait for some. The
+    room only waits while the segment is still recording, so questions are on the way;
+    the host can call the wait off with end_game. Returns an error message when the quiz cannot be started.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    preferred_segment_id = session.game_state.current_segment_id
+    skipped_segment = None
+    if preferred_segment_id and settings.empty_segment_start != "error":
+        empty_segment = await db.get(Segment, preferred_segment_id)
+        if empty_segment and not await _has_playable_questions(db, empty_segment.id):
+            event_row = await db.get(Event, event_uuid)
+            if not event_row or not _can_control_segment(event_row, empty_segment, user_id):
+                return "Only the host or presenter can start the quiz"
+            if settings.empty_segment_start == "wait":
+                if empty_segment.status not in _GENERATING_STATUS_VALUES:
+                    return "This segment has no questions and none are being generated"
+                await _wait_for_questions(event_uuid, session, user_id)
+                return None
+            next_segment = await _next_segment_with_questions(db, empty_segment)
+            if next_segment is None:
+                return "No later segment has questions"
+            skipped_segment, preferred_segment_id = empty_segment, next_segment.id
+
+    segment = await _get_active_segment_with_event(db, event_uuid, preferred_segment_id)
+    if not segment:
+        return "No segment with questions available"
+
+    if not _can_control_segment(segment.event, segment, user_id):
+        return "Only the host or presenter can start the quiz"
+
+    if not can_transition_segment(segment.status, SegmentStatus.QUIZZING.value):
+        return f"Cannot start quiz for a segment in '{segment.status}' status"
+
+    # Load questions for the segment
+    q_result = await db.execute(
+        select(Question)
+        .where(Question.segment_id == segment.id, Question.is_discarded.is_(False))
+        .order_by(Question.order_index)
+    )
+    questions = q_result.scalars().all()
+    if not questions:
+        return "No questions found for this segment"
+
+    session = await hub.get_or_create_session(event_uuid)
+    session.game_state.waiting_starter_id = None
+    session.game_state.scored_question_ids.clear()
+    session.game_state.answer_options.clear()
+    session.game_state.final_leaderboard_frozen = False
+    session.game_state.current_segment_id = segment.id
+    session.game_state.current_presenter_id = segment.presenter_user_id or user_id
+    session.game_state.questions = [
+        {
+            "id": q.id, 
+            "text": q.question_text, 
+            "correct_answer": q.correct_answer,
+            "fake_answers": q.fake_answers or [],
+            "explanation": q.explanation,
+            "question_type": q.question_type or QuestionType.MULTIPLE_CHOICE.value,
+            "tolerance": q.tolerance,
+            "tolerance_mode": q.tolerance_mode,
+            "bonus_multiplier": q.bonus_multiplier or 1.0,
+            "time_limit_seconds": q.time_limit_seconds,
+        } for q in questions
+    ]
+    session.game_state.total_questions = len(questions)
+    session.game_state.current_question_index = 0
+    session.game_state.current_question_id = questions[0].id
+    session.game_state.presenter_paused = False
+    session.game_state.presenter_pause_reason = None
+    session.game_state.buzzer_mode = bool(segment.event.buzzer_mode)
+    session.game_state.timer_display_mode = (
+        segment.event.timer_display_mode or TimerDisplayMode.COUNT_DOWN.value
+    )
+    session.game_state.rng_seed = segment.event.rng_seed
+    session.game_state.audio_cues = bool(segment.event.audio_cues)
+    session.game_state.answer_lock = bool(segment.event.require_answer_lock)
+    session.game_state.host_can_answer = bool(segment.event.host_can_answer)
+    session.game_state.quiz_phase = session.game_state.question_phase
+    session.game_state.default_time_limit_seconds = (
+        segment.event.time_per_question or session.game_state.default_time_limit_seconds
+    )
+    session.game_state.time_limit_seconds = _question_time_limit(
+        session.game_state.questions[0], session.game_state.default_time_limit_seconds
+    )
+    await hub.clear_answers(event_uuid)
+
+    # Pause if no connected participants (excluding current presenter unless they play along)
+    connected_answerers = [
+        p for p in session.game_state.participants.values()
+        if session.game_state.counts_as_answerer(p.user_id) and p.online is not False
+    ]
+    no_connected_participants = len(connected_answerers) == 0
+
+    if no_connected_participants:
+        session.game_state.presenter_paused = True
+        session.game_state.presenter_pause_reason = "no_participants"
+        session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
+        session.game_state.question_started_at = None
+    else:
+        session.game_state.question_started_at = datetime.now(timezone.utc)
+
+    if skipped_segment and can_transition_segment(skipped_segment.status, SegmentStatus.SKIPPED.value):
+        skipped_segment.status = SegmentStatus.SKIPPED.value
+    # Update segment status to reflect quiz in progress
+    segment.status = SegmentStatus.QUIZZING.value
+    segment.quiz_started_at = datetime.now(timezone.utc)
+    if segment.event.started_at is None:
+        segment.event.started_at = segment.quiz_started_at
+    await db.commit()
+
+    time_limit = session.game_state.time_limit_seconds
+    await hub.broadcast(event_uuid, GameStartedMessage().model_dump())
+    if session.game_state.presenter_paused:
+        await hub.broadcast(
+            event_uuid,
+            PresenterPausedMessage(
+                presenter_id=session.game_state.current_presenter_id or user_id,
+                presenter_name=segment.presenter_name or "Presenter",
+                segment_id=segment.id,
+                question_index=session.game_state.current_question_index,
+                total_questions=session.game_state.total_questions,
+                reason="no_participants",
+            ).model_dump(),
+        )
+        await hub.broadcast(
+            event_uuid,
+            PhaseChangedMessage(
+                phase=QuizPhase.PRESENTER_PAUSED,
+                question_index=0,
+                total_questions=len(questions),
+            ).model_dump(),
+        )
+    else:
+        await hub.broadcast(
+            event_uuid,
+            PhaseChangedMessage(
+                phase=session.game_state.quiz_phase,
+                question_index=0,
+                total_questions=len(questions),
+            ).model_dump(),
+        )
+        await hub.broadcast(
+            event_uuid,
+            _build_question_payload(
+                question_id=questions[0].id,
+                question_text=questions[0].question_text,
+                correct_answer=questions[0].correct_answer,
+                fake_answers=questions[0].fake_answers or [],
+                total_questions=len(questions),
+                time_limit=time_limit,
+                index=0,
+                question_type=questions[0].question_type
+                or QuestionType.MULTIPLE_CHOICE.value,
+                bonus_multiplier=questions[0].bonus_multiplier,
+                question_started_at=session.game_state.question_started_at,
+                timer_display_mode=session.game_state.timer_display_mode,
+                rng_seed=session.game_state.rng_seed,
+                shown_options=session.game_state.answer_options,
+            ).model_dump(),
+        )
+        _schedule_question_cues(event_uuid, session)
+        await _announce_upcoming_presenter(db, event_uuid, segment, 0, len(questions))
+    return None
+
+
+async def _handle_presenter_disconnect(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> bool:
+    """Pause the quiz when the current presenter drops and let the host know it can take over.
+
+    Returns True if the disconnected user was presenting.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    current_segment_id = session.game_state.current_segment_id
+    if session.game_state.current_presenter_id != user_id or not current_segment_id:
+        return False
+
+    participant = await db.get(EventParticipant, user_id)
+    if participant:
+        presenter_name = participant.display_name
+    else:
+        user_row = await db.get(User, user_id)
+        presenter_name = (user_row.display_name or user_row.username) if user_row else "Presenter"
+
+    session.game_state.presenter_paused = True
+    session.game_state.presenter_pause_reason = "presenter_disconnected"
+    session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
+    session.game_state.question_started_at = None
+
+    await hub.broadcast(
+        event_uuid,
+        PresenterPausedMessage(
+            presenter_id=user_id,
+            presenter_name=presenter_name,
+            segment_id=current_segment_id,
+            question_index=session.game_state.current_question_index,
+            total_questions=session.game_state.total_questions,
+            reason="presenter_disconnected",
+        ).model_dump(),
+    )
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=QuizPhase.PRESENTER_PAUSED,
+            question_index=session.game_state.current_question_index,
+            total_questions=session.game_state.total_questions,
+        ).model_dump(),
+    )
+    await hub.broadcast(
+        event_uuid,
+        PresenterDisconnectedMessage(
+            presenter_id=user_id,
+            presenter_name=presenter_name,
+            segment_id=current_segment_id,
+        ).model_dump(),
+    )
+
+    event = await db.get(Event, event_uuid)
+    if event and event.host_id:
+        await hub.send_to_user(
+            event_uuid,
+            event.host_id,
+            PresenterOverrideNeededMessage(
+                presenter_id=user_id,
+                presenter_name=presenter_name,
+                segment_id=current_segment_id,
+            ).model_dump(),
+        )
+    return True
+
+
+async def _take_over_disconnected_presenter(
+    db: AsyncSession, session, event: Event, segment: Segment, user_id: UUID
+) -> bool:
+    """Make the host the segment presenter when it steps in for a disconnected presenter."""
+    state = session.game_state
+    if event.host_id != user_id or state.presenter_pause_reason != "presenter_disconnected":
+        return False
+
+    previous_presenter_id = state.current_presenter_id
+    segment.presenter_user_id = user_id
+    await db.commit()
+    hub.invalidate_controller_cache(event.id)
+
+    state.current_presenter_id = user_id
+    state.presenter_paused = False
+    state.presenter_pause_reason = None
+
+    host = await db.get(User, user_id)
+    await hub.broadcast(
+        event.id,
+        PresenterChangedMessage(
+            previous_presenter_id=previous_presenter_id or user_id,
+            new_presenter_id=user_id,
+            new_presenter_name=(host.display_name or host.username) if host else "Host",
+            segment_id=segment.id,
+        ).model_dump(),
+    )
+    return True
+
+
+async def _get_event_branding(db: AsyncSession, event_uuid: UUID) -> dict[str, Any] | None:
+    """Theme the host configured for the event, sent to clients on connect."""
+    event_row = await db.get(Event, event_uuid)
+    return event_row.branding if event_row else None
+
+
+async def _hold_for_host_approval(
+    db: AsyncSession, event_uuid: UUID, participant: EventParticipant, websocket: WebSocket
+) -> None:
+    """Park a pending joiner's socket and ask the host to let them in."""
+    await hub.hold_pending(event_uuid, participant.id, websocket)
+    await websocket.send_json(JoinPendingMessage(event_id=event_uuid).model_dump())
+    event_row = await db.get(Event, event_uuid)
+    if event_row:
+        await hub.send_to_user(
+            event_uuid,
+            event_row.host_id,
+            JoinRequestedMessage(
+                user_id=participant.id,
+                username=participant.display_name,
+                avatar_url=participant.avatar_url,
+            ).model_dump(),
+        )
+
+
+async def _get_pending_joiner(
+    db: AsyncSession, event_uuid: UUID, host_id: UUID, participant_id: UUID
+) -> tuple[EventParticipant | None, str | None]:
+    """Load a participant awaiting approval, checking the caller is the event host."""
+    event_row = await db.get(Event, event_uuid)
+    if not event_row or event_row.host_id != host_id:
+        return None, "Only the host can approve or reject joiners"
+    participant = await db.get(EventParticipant, participant_id)
+    if (
+        not participant
+        or participant.event_id != event_uuid
+        or participant.join_status != JoinStatus.PENDING_APPROVAL.value
+    ):
+        return None, "No pending join request from that participant"
+    return participant, None
+
+
+async def approve_join(
+    db: AsyncSession, event_uuid: UUID, host_id: UUID, participant_id: UUID
+) -> str | None:
+    """Let a pending joiner in; if they are still connected they join the room right away.
+
+    Returns an error message when the caller is not the host or nothing is pending.
+    """
+    participant, error = await _get_pending_joiner(db, event_uuid, host_id, participant_id)
+    if error:
+        return error
+
+    session = await hub.get_or_create_session(event_uuid)
+    state = session.game_state
+    question_active = state.quiz_phase in QUESTION_OPEN_PHASES and state.question_started_at is not None
+    participant.join_status = (
+        JoinStatus.WAITING_FOR_SEGMENT.value if question_active else JoinStatus.JOINED.value
+    )
+    participant.is_late_joiner = participant.is_late_joiner or question_active
+    await db.commit()
+
+    websocket = await hub.release_pending(event_uuid, participant_id)
+    if websocket is None:
+        # They left while waiting and will join normally when they reconnect
+        return None
+
+    await hub.connect(event_uuid, participant_id, websocket)
+    await websocket.send_json(
+        ConnectedMessage(
+            participants=list(state.participants.values()),
+            reconnect_token=hub.issue_reconnect_token(event_uuid, participant_id),
+            branding=await _get_event_branding(db, event_uuid),
+        ).model_dump()
+    )
+    info = ParticipantInfo(
+        user_id=participant.id,
+        username=participant.display_name,
+        avatar_url=participant.avatar_url,
+        join_status=participant.join_status,
+        is_late_joiner=participant.is_late_joiner,
+        joined_at=participant.join_timestamp or datetime.now(timezone.utc),
+    )
+    await hub.add_participant(event_uuid, info)
+    await hub.broadcast(event_uuid, ParticipantJoinedMessage(user=info).model_dump())
+    await hub.send_to_user(
+        event_uuid,
+        participant_id,
+        PhaseChangedMessage(
+            phase=state.quiz_phase,
+            question_index=state.current_question_index,
+            total_questions=state.total_questions,
+        ).model_dump(),
+    )
+    return None
+
+
+async def reject_join(
+    db: AsyncSession, event_uuid: UUID, host_id: UUID, participant_id: UUID
+) -> str | None:
+    """Turn a pending joiner away and close their connection.
+
+    Returns an error message when the caller is not the host or nothing is pending.
+    """
+    participant, error = await _get_pending_joiner(db, event_uuid, host_id, participant_id)
+    if error:
+        return error
+
+    participant.join_status = JoinStatus.REJECTED.value
+    await db.commit()
+
+    websocket = await hub.release_pending(event_uuid, participant_id)
+    if websocket is not None:
+        try:
+            await websocket.send_json(JoinRejectedMessage(event_id=event_uuid).model_dump())
+            await websocket.close()
+        except Exception:
+            pass  # The joiner may already be gone
+    return None
+
+
+async def buzz_in(event_uuid: UUID, user_id: UUID) -> str | None:
+    """Claim answer rights in a buzzer round and tell the room who won the buzz.
+
+    Returns an error message when the buzzer is not open to this participant.
+    """
+    success, reason = await hub.record_buzz(event_uuid, user_id)
+    if not success:
+        return {
+            'no_session': 'Event session not found',
+            'not_buzzing': 'The buzzer is not open',
+            'claimed': 'Someone else buzzed in first',
+            'already_answered': 'You have already answered this question',
+        }.get(reason, 'Failed to buzz in')
+
+    await hub.broadcast(
+        event_uuid,
+        BuzzWinnerMessage(
+            user_id=user_id, answer_window_seconds=settings.buzz_answer_window_seconds
+        ).model_dump(),
+    )
+    return None
+
+
+async def reset_buzzer(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """Reopen the buzzer for the current question so the others can buzz.
+
+    Returns an error message when the caller is not the host or presenter.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    state = session.game_state
+    if state.quiz_phase != QuizPhase.BUZZING or not state.current_segment_id:
+        return "The buzzer is not open"
+    if not await _is_segment_controller(db, event_uuid, state.current_segment_id, user_id):
+        return "Only the host or presenter can reset the buzzer"
+
+    await hub.reset_buzz(event_uuid)
+    await hub.broadcast(event_uuid, BuzzResetMessage().model_dump())
+    return None
+
+
+MAX_ANNOUNCEMENT_LENGTH = 280
+
+
+def _sanitize_announcement(text: str) -> str:
+    """Drop control characters and collapse whitespace so announcements render as one line."""
+    printable = "".join(ch if ch.isprintable() else " " for ch in text)
+    return " ".join(printable.split())
+
+
+async def send_announcement(
+    db: AsyncSession, event_uuid: UUID, user_id: UUID, text: str
+) -> str | None:
+    """Broadcast a host or presenter announcement to everyone in the event.
+
+    Returns an error message when the sender may not announce or the text is invalid.
+    """
+    event_row = await db.get(Event, event_uuid)
+    if not event_row:
+        return "Event not found"
+    session = await hub.get_or_create_session(event_uuid)
+    segment_id = session.game_state.current_segment_id
+    segment_row = await db.get(Segment, segment_id) if segment_id else None
+    is_controller = (
+        _can_control_segment(event_row, segment_row, user_id)
+        if segment_row
+        else event_row.host_id == user_id
+    )
+    if not is_controller:
+        return "Only the host or presenter can make announcements"
+
+    cleaned = _sanitize_announcement(text)
+    if not cleaned:
+        return "Announcement cannot be empty"
+    if len(cleaned) > MAX_ANNOUNCEMENT_LENGTH:
+        return f"Announcement must be at most {MAX_ANNOUNCEMENT_LENGTH} characters"
+
+    participant = await db.get(EventParticipant, user_id)
+    if participant:
+        sender_name = participant.display_name
+    else:
+        user_row = await db.get(User, user_id)
+        sender_name = (user_row.display_name or user_row.username) if user_row else "Host"
+
+    await hub.broadcast(
+        event_uuid,
+        AnnouncementMessage(text=cleaned, from_user_id=user_id, from_name=sender_name).model_dump(),
+    )
+    return None
+
+
+async def advance_question(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """Move the quiz to the next question, or complete the segment after the last one.
+
+    When the presenter has dropped and the host advances, the host takes over presenting.
+    Returns an error message when the quiz cannot be advanced.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    if not session.game_state.questions:
+        return "No active quiz to advance"
+
+    segment_id = session.game_state.current_segment_id
+    if not segment_id or not await _is_segment_controller(db, event_uuid, segment_id, user_id, "advance"):
+        return "Only the host or presenter can change questions"
+
+    if session.game_state.presenter_pause_reason == "presenter_disconnected":
+        event_row = await db.get(Event, event_uuid)
+        segment_row = await db.get(Segment, segment_id)
+        await _take_over_disconnected_presenter(db, session, event_row, segment_row, user_id)
+
+    await _apply_zero_scores_for_unanswered(db, session)
+
+    next_index = session.game_state.current_question_index + 1
+    questions = session.game_state.questions
+    if next_index >= len(questions):
+        session.game_state.quiz_phase = QuizPhase.SEGMENT_COMPLETE
+        await hub.broadcast(event_uuid, GameEndedMessage().model_dump())
+        await hub.broadcast(
+            event_uuid,
+            PhaseChangedMessage(
+                phase=QuizPhase.SEGMENT_COMPLETE,
+                question_index=session.game_state.current_question_index,
+                total_questions=len(questions),
+            ).model_dump(),
+        )
+
+        # Mark segment complete and broadcast results
+        if segment_id:
+            segment_row = await db.get(Segment, segment_id)
+            if segment_row and can_transition_segment(
+                segment_row.status, SegmentStatus.COMPLETED.value
+            ):
+                segment_row.status = SegmentStatus.COMPLETED.value
+                segment_row.ended_at = datetime.now(timezone.utc)
+                await db.commit()
+
+                segment_lb = await _get_segment_leaderboard(db, segment_id)
+                event_lb = await _get_event_leaderboard(db, event_uuid, session)
+                await hub.broadcast(
+                    event_uuid,
+                    SegmentCompleteMessage(
+                        segment_id=segment_id,
+                        segment_title=segment_row.title or "Segment",
+                        presenter_name=segment_row.presenter_name,
+                        segment_leaderboard=segment_lb,
+                        event_leaderboard=event_lb,
+                        segment_winner=segment_lb[0] if segment_lb else None,
+                        event_leader=event_lb[0] if event_lb else None,
+                    ).model_dump(),
+                )
+
+                # If all segments complete, broadcast final event leaderboard
+                await _emit_event_completion(db, session, event_uuid)
+        return None
+
+    session.game_state.current_question_index = next_index
+    session.game_state.current_question_id = questions[next_index]["id"]
+    session.game_state.time_limit_seconds = _question_time_limit(
+        questions[next_index], session.game_state.default_time_limit_seconds
+    )
+    session.game_state.presenter_paused = False
+    session.game_state.quiz_phase = session.game_state.question_phase
+    session.game_state.question_started_at = datetime.now(timezone.utc)
+    await hub.clear_answers(event_uuid)
+
+    time_limit = session.game_state.time_limit_seconds
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=session.game_state.quiz_phase,
+            question_index=next_index,
+            total_questions=len(questions),
+        ).model_dump(),
+    )
+
+    await hub.broadcast(
+        event_uuid,
+        _build_question_payload(
+            question_id=questions[next_index]["id"],
+            question_text=questions[next_index]["text"],
+            correct_answer=questions[next_index]["correct_answer"],
+            fake_answers=questions[next_index].get("fake_answers", []),
+            total_questions=len(questions),
+            time_limit=time_limit,
+            index=next_index,
+            question_type=questions[next_index].get(
+                "question_type", QuestionType.MULTIPLE_CHOICE.value
+            ),
+            bonus_multiplier=questions[next_index].get("bonus_multiplier"),
+            question_started_at=session.game_state.question_started_at,
+            timer_display_mode=session.game_state.timer_display_mode,
+            rng_seed=session.game_state.rng_seed,
+            shown_options=session.game_state.answer_options,
+        ).model_dump(),
+    )
+    _schedule_question_cues(event_uuid, session)
+    await _announce_upcoming_presenter(
+        db, event_uuid, segment_id, next_index, len(questions)
+    )
+    return None
+
+
+async def _announce_participant_left(event_uuid: UUID, user_id: UUID, presenter_name: str) -> None:
+    """Tell the room a participant left once their reconnect window has run out.
+
+    Pauses an active quiz when that leaves nobody connected. Otherwise, if the leaver
+    was the last one the open question was waiting on, it now counts as all answered.
+    """
+    await hub.broadcast(
+        event_uuid,
+        ParticipantLeftMessage(user_id=user_id, online=False).model_dump(),
+    )
+
+    # If everyone disconnected during an active quiz, pause with reason
+    session = await hub.get_or_create_session(event_uuid)
+    if (
+        session
+        and session.game_state.quiz_phase not in {QuizPhase.NOT_STARTED, QuizPhase.EVENT_COMPLETE, QuizPhase.MEGA_QUIZ_READY}
+        and hub.get_participant_count(event_uuid) == 0
+    ):
+        session.game_state.presenter_paused = True
+        session.game_state.presenter_pause_reason = "all_disconnected"
+        session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
+        session.game_state.question_started_at = None
+        await hub.broadcast(
+            event_uuid,
+            PresenterPausedMessage(
+                presenter_id=session.game_state.current_presenter_id or user_id,
+                presenter_name=presenter_name,
+                segment_id=session.game_state.current_segment_id or UUID(int=0),
+                question_index=session.game_state.current_question_index,
+                total_questions=session.game_state.total_questions,
+                reason="all_disconnected",
+            ).model_dump(),
+        )
+        await hub.broadcast(
+            event_uuid,
+            PhaseChangedMessage(
+                phase=QuizPhase.PRESENTER_PAUSED,
+                question_index=session.game_state.current_question_index,
+                total_questions=session.game_state.total_questions,
+            ).model_dump(),
+        )
+        return
+
+    if (
+        session.game_state.quiz_phase in QUESTION_OPEN_PHASES
+        and await _notify_all_answered(event_uuid, session)
+        and settings.auto_reveal_when_all_answered
+    ):
+        await reveal_current_question(event_uuid, session)
+
+
+async def remove_canvas_stroke(
+    db: AsyncSession, event_uuid: UUID, user_id: UUID, stroke_id: UUID | None = None
+) -> str | None:
+    """Delete a canvas stroke and tell the room to drop it.
+
+    Without ``stroke_id`` the sender's most recent stroke is undone. Authors may remove
+    their own strokes and the host may remove anyone's. Returns an error message when
+    nothing was removed.
+    """
+    if stroke_id is None:
+        stroke = await db.scalar(
+            select(CanvasStroke)
+            .where(CanvasStroke.event_id == event_uuid, CanvasStroke.user_id == user_id)
+            .order_by(CanvasStroke.created_at.desc(), CanvasStroke.id.desc())
+            .limit(1)
+        )
+        if stroke is None:
+            return "No stroke to undo"
+    else:
+        stroke = await db.get(CanvasStroke, stroke_id)
+        if stroke is None or stroke.event_id != event_uuid:
+            return "Stroke not found"
+        if stroke.user_id != user_id:
+            event_row = await db.get(Event, event_uuid)
+            if not event_row or event_row.host_id != user_id:
+                return "Only the host or the stroke's author can delete it"
+
+    removed_id = stroke.id
+    await db.delete(stroke)
+    await db.commit()
+    await hub.broadcast(event_uuid, StrokeRemovedMessage(stroke_id=removed_id).model_dump())
+    return None
+
+
+async def discard_question(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """Throw out the open question: revoke its scores and answers, then advance.
+
+    Returns an error message when the question cannot be discarded.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    state = session.game_state
+    segment_id = state.current_segment_id
+    if not state.questions or not segment_id:
+        return "No active quiz"
+    if not await _is_segment_controller(db, event_uuid, segment_id, user_id, "advance"):
+        return "Only the host or presenter can discard questions"
+    question_id = state.current_question_id
+    if question_id is None or state.quiz_phase not in QUESTION_OPEN_PHASES:
+        return "Only an open question can be discarded"
+
+    # Stop taking answers and keep advance_question from giving out zero scores for it
+    state.question_started_at = None
+    state.scored_question_ids.add(question_id)
+    _cancel_question_cues(session)
+    await hub.clear_answers(event_uuid)
+
+    await revoke_question_scores(db, question_id)
+    question_row = await db.get(Question, question_id)
+    if question_row:
+        question_row.is_discarded = True
+    await db.commit()
+
+    await hub.broadcast(event_uuid, QuestionDiscardedMessage(question_id=question_id).model_dump())
+    return await advance_question(db, event_uuid, user_id)
+
+
+async def end_event_game(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """End the quiz for the current segment and broadcast completion to the room.
+
+    Shared by the WebSocket end_game command and the REST end endpoint. While the room
+    is waiting for questions, this calls the wait off instead of completing the segment.
+    Returns an error message when the quiz cannot be ended.
+    """
+    session = await hub.get_or_create_session(event_uuid)
+    event_row = await db.get(Event, event_uuid)
+    segment_row = await db.get(Segment, session.game_state.current_segment_id) if session.game_state.current_segment_id else None
+    if not event_row or not segment_row or not _can_control_segment(event_row, segment_row, user_id, "end"):
+        return "Only the host or presenter can end the quiz"
+
+    if session.game_state.quiz_phase == QuizPhase.WAITING_FOR_QUESTIONS:
+        await _stop_waiting_for_questions(event_uuid, session)
+        return None
+
+    if not can_transition_segment(segment_row.status, SegmentStatus.COMPLETED.value):
+        return f"Cannot end quiz for a segment in '{segment_row.status}' status"
+
+    await _apply_zero_scores_for_unanswered(db, session)
+
+    session.game_state.quiz_phase = QuizPhase.SEGMENT_COMPLETE
+    await hub.broadcast(event_uuid, GameEndedMessage().model_dump())
+    await hub.broadcast(
+        event_uuid,
+        PhaseChangedMessage(
+            phase=QuizPhase.SEGMENT_COMPLETE,
+            question_index=session.game_state.current_question_index,
+            total_questions=session.game_state.total_questions,
+        ).model_dump(),
+    )
+
+    # Persist segment completion
+    segment_row.status = SegmentStatus.COMPLETED.value
+    segment_row.ended_at = datetime.now(timezone.utc)
+    await db.commit()
+
+    # Broadcast segment completion payload
+    segment_lb = await _get_segment_leaderboard(db, segment_row.id)
+    event_lb = await _get_event_leaderboard(db, event_uuid, session)
+    await hub.broadcast(
+        event_uuid,
+        SegmentCompleteMessage(
+            segment_id=segment_row.id,
+            segment_title=segment_row.title or "Segment",
+            presenter_name=segment_row.presenter_name,
+            segment_leaderboard=segment_lb,
+            event_leaderboard=event_lb,
+            segment_winner=segment_lb[0] if segment_lb else None,
+            event_leader=event_lb[0] if event_lb else None,
+        ).model_dump(),
+    )
+
+    # If all segments are complete, emit final results
+    await _emit_event_completion(db, session, event_uuid)
+    return None
+
+
+async def force_complete_event(db: AsyncSession, event_uuid: UUID, user_id: UUID) -> str | None:
+    """Host override: skip every unfinished segment and emit the event's final results.
+
+    Returns an error message when the caller is not the host.
+    """
+    event_row = await db.get(Event, event_uuid)
+    if not event_row or event_row.host_id != user_id:
+        return "Only the host can force the event to complete"
+
+    await _skip_unfinished_segments(db, event_uuid)
+    await db.commit()
+
+    session = await hub.get_or_create_session(event_uuid)
+    await _emit_event_completion(db, session, event_uuid)
+    return None
+
+
+async def _skip_unfinished_segments(db: AsyncSession, event_uuid: UUID) -> None:
+    """Mark every segment that isn't done as SKIPPED (not committed)."""
+    result = await db.execute(
+        select(Segment).where(
+            Segment.event_id == event_uuid,
+            Segment.status.notin_(_DONE_STATUS_VALUES),
+        )
+    )
+    now = datetime.now(timezone.utc)
+    for segment in result.scalars().all():
+        segment.previous_status = segment.status
+        segment.status = SegmentStatus.SKIPPED.value
+        segment.ended_at = now
+
+
+async def finalize_overdue_event(db: AsyncSession, event_row: Event) -> None:
+    """Finish an event that ran past its max duration and release its hub session.
+
+    Unfinished segments are skipped, connected clients get the final results and
+    GameEnded, and the session is dropped.
+    """
+    await _skip_unfinished_segments(db, event_row.id)
+    event_row.previous_status = event_row.status
+    event_row.status = EventStatus.FINISHED.value
+    event_row.ended_at = datetime.now(timezone.utc)
+    await db.commit()
+
+    session = hub.event_sessions.get(event_row.id)
+    if session is None:
+        return
+
+    session.game_state.quiz_phase = QuizPhase.EVENT_COMPLETE
+    leaderboard = await _get_event_leaderboard(db, event_row.id, session)
+    await hub.broadcast(
+        event_row.id,
+        EventCompleteMessage(
+            event_id=event_row.id,
+            final_leaderboard=leaderboard,
+            winner=leaderboard[0] if leaderboard else None,
+            segment_winners=await _get_segment_winners(db, event_row.id),
+        ).model_dump(),
+    )
+    await hub.broadcast(event_row.id, GameEndedMessage().model_dump())
+    await hub.close_session(event_row.id)
+
+
+async def _authenticated_identities(
+    event_uuid: UUID, token: str | None, session_token: str | None
+) -> set[UUID] | None:
+    """User ids a socket may join as, from its access token or participant session token.
+
+    An account may join as itself or as any of its participant rows in the event.
+    Returns None when the socket presented no valid credentials.
+    """
+    account_id = get_user_id_from_token(token) if token else None
+    if account_id is None and not session_token:
+        return None
+
+    identities: set[UUID] = set()
+    async with async_session_maker() as db:
+        if account_id is not None:
+            identities.add(account_id)
+            result = await db.execute(
+                select(EventParticipant.id).where(
+                    EventParticipant.event_id == event_uuid,
+                    EventParticipant.user_id == account_id,
+                )
+            )
+            identities.update(result.scalars().all())
+        if session_token:
+            participant_id = await db.scalar(
+                select(EventParticipant.id).where(
+                    EventParticipant.event_id == event_uuid,
+                    EventParticipant.session_token == session_token,
+                )
+            )
+            if participant_id:
+                identities.add(participant_id)
+    return identities or None
+
+
 @router.websocket("/ws/event/{event_id}")
-async def websocket_event(websocket: WebSocket, event_id: str):
-    """WebSocket endpoint for quiz game events."""
+async def websocket_event(
+    websocket: WebSocket,
+    event_id: str,
+    token: str | None = None,
+    session_token: str | None = None,
+):
+    """WebSocket endpoint for quiz game events.
+
+    Sockets must authenticate with ?token= (access token) or ?session_token= (participant
+    session) and can only join as the identity those credentials belong to; a join
+    without valid credentials is refused and the socket closed. Upgrades beyond
+    settings.ws_max_connections open sockets are refused.
+    """
+    if not hub.try_open_socket():
+        await _refuse_upgrade(websocket, "Server is at its WebSocket connection limit, try again later")
+        return
+    try:
+        await _serve_event_socket(websocket, event_id, token, session_token)
+    finally:
+        hub.close_socket()
+
+
+async def _refuse_upgrade(websocket: WebSocket, reason: str) -> None:
+    """Turn an upgrade away with a 503, or close code 1013 where the server can't send one."""
+    if "websocket.http.response" in websocket.scope.get("extensions", {}):
+        await websocket.send_denial_response(JSONResponse({"detail": reason}, status_code=503))
+    else:
+        await websocket.close(code=1013, reason=reason)
+
+
+async def _serve_event_socket(
+    websocket: WebSocket,
+    event_id: str,
+    token: str | None,
+    session_token: str | None,
+) -> None:
+    """Accept a socket and run its message loop until it disconnects."""
     await websocket.accept()
 
     event_uuid = UUID(event_id)
+    identities = await _authenticated_identities(event_uuid, token, session_token)
     user_id: UUID | None = None
+    # Set while this socket's participant waits for host approval
+    pending_user_id: UUID | None = None
 
     try:
         while True:
             data = await websocket.receive_json()
-            message = parse_client_message(data)
+            try:
+                message = parse_client_message(data)
+            except ValidationError:
+                # e.g. an answer longer than can be stored; the socket stays open
+                await websocket.send_json(ErrorMessage(message="Invalid message").model_dump())
+                continue
 
             if message is None:
                 await websocket.send_json(
@@ -391,12 +2483,60 @@ async def websocket_event(websocket: WebSocket, event_id: str):
 
             msg_type = data.get("type")
 
-            if msg_type == "join":
+            if pending_user_id:
+                session = hub.event_sessions.get(event_uuid)
+                if session and session.connections.get(pending_user_id) is websocket:
+                    # The host approved this joiner
+                    user_id, pending_user_id = pending_user_id, None
+                elif msg_type not in {"join", "pong"}:
+                    await websocket.send_json(
+                        ErrorMessage(message="Waiting for the host to approve your join request").model_dump()
+                    )
+                    continue
+
+            if msg_type == "join":
+                if identities is None:
+                    # No credentials, or none that verify: nobody to join as
+                    await websocket.send_json(
+                        ErrorMessage(message="Sign in or use your session token to join").model_dump()
+                    )
+                    await websocket.close(code=1008)
+                    return
+                if message.user_id not in identities:
+                    await websocket.send_json(
+                        ErrorMessage(message="You can only join as yourself").model_dump()
+                    )
+                    continue
+
+                if message.reconnect_token:
+                    token_user_id = hub.redeem_reconnect_token(event_uuid, message.reconnect_token)
+                    if token_user_id is None or token_user_id != message.user_id:
+                        await websocket.send_json(
+                            ErrorMessage(message="Invalid or expired reconnect token").model_dump()
+                        )
+                        continue
+
+                async with async_session_maker() as db:
+                    joiner = await db.get(EventParticipant, message.user_id)
+                    if joiner and joiner.event_id == event_uuid:
+                        if joiner.join_status == JoinStatus.REJECTED.value:
+                            await websocket.send_json(
+                                ErrorMessage(message="The host declined your request to join this event").model_dump()
+                            )
+                            continue
+                        if joiner.join_status == JoinStatus.PENDING_APPROVAL.value:
+                            pending_user_id = joiner.id
+                            await _hold_for_host_approval(db, event_uuid, joiner, websocket)
+                            continue
+
                 user_id = message.user_id
                 
                 # Check if this is a reconnection
                 connection_state = hub.get_connection_state(event_uuid, user_id)
-                is_reconnection = connection_state == 'temporarily_disconnected'
+                is_reconnection = (
+                    connection_state == 'temporarily_disconnected'
+                    or message.reconnect_token is not None
+                )
                 
                 if is_reconnection:
                     await hub.reconnect(event_uuid, user_id, websocket)
@@ -409,7 +2549,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                 # Derive late-join status based on current quiz phase
                 current_phase = session.game_state.quiz_phase
                 question_started_at = session.game_state.question_started_at
-                is_question_active = current_phase == QuizPhase.SHOWING_QUESTION and question_started_at is not None
+                is_question_active = current_phase in QUESTION_OPEN_PHASES and question_started_at is not None
 
                 join_status = JoinStatus.JOINED.value
                 is_late_joiner = False
@@ -419,6 +2559,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
 
                 username = data.get("username", "Anonymous")
                 avatar_url = data.get("avatar_url")
+                branding = None
 
                 # Try to hydrate from DB if participant exists
                 async with async_session_maker() as db:
@@ -430,6 +2571,12 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                             join_status = participant_row.join_status or join_status
                             is_late_joiner = participant_row.is_late_joiner or is_late_joiner
                             joined_at = participant_row.join_timestamp or joined_at
+                            if message.team_id and participant_row.team_id != message.team_id:
+                                if not await _assign_team(db, event_uuid, participant_row, message.team_id):
+                                    await websocket.send_json(
+                                        ErrorMessage(message="That team is not part of this event").model_dump()
+                                    )
+                        branding = await _get_event_branding(db, event_uuid)
                     except Exception:
                         pass  # Use defaults if DB lookup fails
 
@@ -437,9 +2584,13 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                 state = hub.get_game_state(event_uuid)
                 participants = list(state.participants.values()) if state else []
 
-                # Send connected message
+                # Send connected message with a fresh reconnect token
                 await websocket.send_json(
-                    ConnectedMessage(participants=participants).model_dump()
+                    ConnectedMessage(
+                        participants=participants,
+                        reconnect_token=hub.issue_reconnect_token(event_uuid, user_id),
+                        branding=branding,
+                    ).model_dump()
                 )
 
                 # If reconnecting, send state restoration
@@ -469,7 +2620,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                     question_started_at = None
                     current_question_id = state.current_question_id if state else None
                     
-                    if state and state.quiz_phase == QuizPhase.SHOWING_QUESTION:
+                    if state and state.quiz_phase in QUESTION_OPEN_PHASES:
                         time_limit = state.time_limit_seconds
                         question_started_at = state.question_started_at
                     
@@ -518,7 +2669,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                 ):
                     session.game_state.presenter_paused = False
                     session.game_state.presenter_pause_reason = None
-                    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+                    session.game_state.quiz_phase = session.game_state.question_phase
                     session.game_state.question_started_at = datetime.now(timezone.utc)
 
                     question_index = session.game_state.current_question_index
@@ -532,7 +2683,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                         await hub.broadcast(
                             event_uuid,
                             PhaseChangedMessage(
-                                phase=QuizPhase.SHOWING_QUESTION,
+                                phase=session.game_state.quiz_phase,
                                 question_index=question_index,
                                 total_questions=total_questions,
                             ).model_dump(),
@@ -548,6 +2699,14 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                                 total_questions=total_questions,
                                 time_limit=session.game_state.time_limit_seconds,
                                 index=question_index,
+                                question_type=current_question.get(
+                                    "question_type", QuestionType.MULTIPLE_CHOICE.value
+                                ),
+                                bonus_multiplier=current_question.get("bonus_multiplier"),
+                                question_started_at=session.game_state.question_started_at,
+                                timer_display_mode=session.game_state.timer_display_mode,
+                                rng_seed=session.game_state.rng_seed,
+                                shown_options=session.game_state.answer_options,
                             ).model_dump(),
                         )
 
@@ -558,7 +2717,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                 ):
                     session.game_state.presenter_paused = False
                     session.game_state.presenter_pause_reason = None
-                    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+                    session.game_state.quiz_phase = session.game_state.question_phase
                     session.game_state.question_started_at = datetime.now(timezone.utc)
 
                     question_index = session.game_state.current_question_index
@@ -572,7 +2731,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                         await hub.broadcast(
                             event_uuid,
                             PhaseChangedMessage(
-                                phase=QuizPhase.SHOWING_QUESTION,
+                                phase=session.game_state.quiz_phase,
                                 question_index=question_index,
                                 total_questions=total_questions,
                             ).model_dump(),
@@ -588,9 +2747,80 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                                 total_questions=total_questions,
                                 time_limit=session.game_state.time_limit_seconds,
                                 index=question_index,
+                                question_type=current_question.get(
+                                    "question_type", QuestionType.MULTIPLE_CHOICE.value
+                                ),
+                                bonus_multiplier=current_question.get("bonus_multiplier"),
+                                question_started_at=session.game_state.question_started_at,
+                                timer_display_mode=session.game_state.timer_display_mode,
+                                rng_seed=session.game_state.rng_seed,
+                                shown_options=session.game_state.answer_options,
                             ).model_dump(),
                         )
 
+            elif msg_type == "selecting_answer" and user_id:
+                session = await hub.get_or_create_session(event_uuid)
+                await _broadcast_selecting(event_uuid, session, user_id, message.question_id)
+
+            elif msg_type == "select_answer" and user_id:
+                session = await hub.get_or_create_session(event_uuid)
+                if message.question_id != session.game_state.current_question_id:
+                    await websocket.send_json(
+                        ErrorMessage(message="Stale answer for previous question").model_dump()
+                    )
+                    continue
+                if not session.game_state.answer_lock:
+                    await websocket.send_json(
+                        ErrorMessage(message=ANSWER_ERROR_MESSAGES['lock_disabled']).model_dump()
+                    )
+                    continue
+                if _invalid_numeric_answer(session, message.selected_answer):
+                    await websocket.send_json(
+                        ErrorMessage(message="Please enter a number").model_dump()
+                    )
+                    continue
+
+                success, error_reason = await hub.record_selection(
+                    event_uuid, user_id, message.selected_answer
+                )
+                if success:
+                    await websocket.send_json(
+                        AnswerSelectedMessage(
+                            question_id=message.question_id,
+                            selected_answer=message.selected_answer,
+                        ).model_dump()
+                    )
+                    await _broadcast_selecting(event_uuid, session, user_id, message.question_id)
+                else:
+                    await websocket.send_json(
+                        ErrorMessage(
+                            message=ANSWER_ERROR_MESSAGES.get(error_reason, 'Failed to record answer')
+                        ).model_dump()
+                    )
+
+            elif msg_type == "lock_answer" and user_id:
+                session = await hub.get_or_create_session(event_uuid)
+                if message.question_id != session.game_state.current_question_id:
+                    await websocket.send_json(
+                        ErrorMessage(message="Stale answer for previous question").model_dump()
+                    )
+                    continue
+
+                submission_time = datetime.now(timezone.utc)
+                locked_answer, error_reason = await hub.lock_answer(
+                    event_uuid, user_id, submitted_at=submission_time
+                )
+                if locked_answer is not None:
+                    await _score_recorded_answer(
+                        event_uuid, session, user_id, locked_answer, submission_time
+                    )
+                else:
+                    await websocket.send_json(
+                        ErrorMessage(
+                            message=ANSWER_ERROR_MESSAGES.get(error_reason, 'Failed to record answer')
+                        ).model_dump()
+                    )
+
             elif msg_type == "pong" and user_id:
                 # Handle heartbeat pong response
                 hub.handle_pong(user_id)
@@ -604,7 +2834,19 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                     )
                     continue
 
+                if session.game_state.answer_lock:
+                    await websocket.send_json(
+                        ErrorMessage(message=ANSWER_ERROR_MESSAGES['lock_required']).model_dump()
+                    )
+                    continue
+                if _invalid_numeric_answer(session, message.selected_answer):
+                    await websocket.send_json(
+                        ErrorMessage(message="Please enter a number").model_dump()
+                    )
+                    continue
+
                 submission_time = datetime.now(timezone.utc)
+                changed = user_id in session.game_state.answers_received
                 success, error_reason = await hub.record_answer(
                     event_uuid,
                     user_id,
@@ -612,37 +2854,19 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                     submitted_at=submission_time,
                 )
                 if success:
-                    async with async_session_maker() as db:
-                        try:
-                            await _score_answer_submission(
-                                db,
-                                session=session,
-                                participant_id=user_id,
-                                selected_answer=message.selected_answer,
-                                submitted_at=submission_time,
-                            )
-                            await db.commit()
-                        except Exception:
-                            await db.rollback()
-                            raise
-
-                    await hub.broadcast(
+                    await _score_recorded_answer(
                         event_uuid,
-                        AnswerReceivedMessage(user_id=user_id).model_dump(),
+                        session,
+                        user_id,
+                        message.selected_answer,
+                        submission_time,
+                        changed=changed,
                     )
                 else:
                     # Send specific error message to user
-                    error_messages = {
-                        'duplicate': 'You have already submitted an answer for this question',
-                        'too_late': 'Time expired. Your answer was not recorded',
-                        'no_question': 'No active question to answer',
-                        'no_session': 'Event session not found',
-                        'late_join': 'You can start answering with the next question',
-                        'paused': 'Quiz is paused while the presenter reconnects',
-                    }
                     await websocket.send_json(
                         ErrorMessage(
-                            message=error_messages.get(error_reason, 'Failed to record answer')
+                            message=ANSWER_ERROR_MESSAGES.get(error_reason, 'Failed to record answer')
                         ).model_dump()
                     )
 
@@ -651,7 +2875,10 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                 async with async_session_maker() as db:
                     try:
                         question_count = message.question_count or 10
-                        questions = await aggregate_event_questions(db, event_uuid, question_count)
+                        event_row = await db.get(Event, event_uuid)
+                        questions = await aggregate_event_questions(
+                            db, event_uuid, question_count, rng_seed=event_row.rng_seed if event_row else None
+                        )
 
                         if not questions:
                             await websocket.send_json(
@@ -730,6 +2957,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                             .values(presenter_user_id=message.presenter_user_id)
                         )
                         await db.commit()
+                        hub.invalidate_controller_cache(event_uuid)
 
                         # Update GameState cache
                         session = await hub.get_or_create_session(event_uuid)
@@ -997,6 +3225,7 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                             .values(presenter_user_id=next_presenter_id)
                         )
                         await db.commit()
+                        hub.invalidate_controller_cache(event_uuid)
 
                         # Update GameState cache
                         session.game_state.current_presenter_id = next_presenter_id
@@ -1016,318 +3245,199 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                         await db.rollback()
                         raise
 
-            elif msg_type == "skip_mega_quiz":
+            elif msg_type == "go_to_segment" and user_id:
                 async with async_session_maker() as db:
                     try:
                         session = await hub.get_or_create_session(event_uuid)
-                        final_lb = await _get_event_leaderboard(db, event_uuid, session)
-                        segment_winners = await _get_segment_winners(db, event_uuid)
-                        winner = final_lb[0] if final_lb else None
-
-                        event_complete = EventCompleteMessage(
-                            event_id=event_uuid,
-                            final_leaderboard=final_lb,
-                            winner=winner,
-                            segment_winners=segment_winners,
+                        switched, error = await _switch_to_segment(
+                            db, session, event_uuid, message.segment_id, user_id
                         )
-                        session.game_state.quiz_phase = QuizPhase.EVENT_COMPLETE
-                        session.game_state.presenter_paused = False
-                        session.game_state.presenter_pause_reason = None
+                        if error:
+                            await websocket.send_json(ErrorMessage(message=error).model_dump())
+                            continue
 
-                        await hub.broadcast(event_uuid, event_complete.model_dump())
+                        await hub.broadcast(event_uuid, switched.model_dump())
                         await hub.broadcast(
                             event_uuid,
                             PhaseChangedMessage(
-                                phase=QuizPhase.EVENT_COMPLETE,
-                                question_index=session.game_state.current_question_index,
-                                total_questions=session.game_state.total_questions,
+                                phase=QuizPhase.NOT_STARTED,
+                                question_index=0,
+                                total_questions=0,
                             ).model_dump(),
                         )
                     except Exception:
                         await db.rollback()
                         raise
 
-            elif msg_type == "start_game" and user_id:
+            elif msg_type == "announce" and user_id:
                 async with async_session_maker() as db:
                     try:
-                        segment = await _get_active_segment_with_event(db, event_uuid)
-                        if not segment:
-                            await websocket.send_json(
-                                ErrorMessage(message="No segment with questions available").model_dump()
-                            )
-                            continue
-
-                        if not _can_control_segment(segment.event, segment, user_id):
-                            await websocket.send_json(
-                                ErrorMessage(message="Only the host or presenter can start the quiz").model_dump()
-                            )
-                            continue
+                        error = await send_announcement(db, event_uuid, user_id, message.text)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                        # Load questions for the segment
-                        q_result = await db.execute(
-                            select(Question).where(Question.segment_id == segment.id).order_by(Question.order_index)
-                        )
-                        questions = q_result.scalars().all()
-                        if not questions:
-                            await websocket.send_json(
-                                ErrorMessage(message="No questions found for this segment").model_dump()
-                            )
-                            continue
+            elif msg_type == "buzz" and user_id:
+                error = await buzz_in(event_uuid, user_id)
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                        session = await hub.get_or_create_session(event_uuid)
-                        session.game_state.scored_question_ids.clear()
-                        session.game_state.current_segment_id = segment.id
-                        session.game_state.current_presenter_id = segment.presenter_user_id or user_id
-                        session.game_state.questions = [
-                            {
-                                "id": q.id, 
-                                "text": q.question_text, 
-                                "correct_answer": q.correct_answer,
-                                "fake_answers": q.fake_answers or []
-                            } for q in questions
-                        ]
-                        session.game_state.total_questions = len(questions)
-                        session.game_state.current_question_index = 0
-                        session.game_state.current_question_id = questions[0].id
-                        session.game_state.presenter_paused = False
-                        session.game_state.presenter_pause_reason = None
-                        session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
-                        session.game_state.time_limit_seconds = segment.event.time_per_question or session.game_state.time_limit_seconds
-                        await hub.clear_answers(event_uuid)
-
-                        # Pause if no connected participants (excluding current presenter)
-                        presenter_id = session.game_state.current_presenter_id
-                        connected_non_presenters = [
-                            p for p in session.game_state.participants.values()
-                            if p.user_id != presenter_id and p.online is not False
-                        ]
-                        no_connected_participants = len(connected_non_presenters) == 0
-
-                        if no_connected_participants:
-                            session.game_state.presenter_paused = True
-                            session.game_state.presenter_pause_reason = "no_participants"
-                            session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
-                            session.game_state.question_started_at = None
-                        else:
-                            session.game_state.question_started_at = datetime.now(timezone.utc)
+            elif msg_type == "reset_buzz" and user_id:
+                async with async_session_maker() as db:
+                    try:
+                        error = await reset_buzzer(db, event_uuid, user_id)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
+
+            elif msg_type == "replay" and user_id:
+                missed = hub.messages_since(event_uuid, message.since_seq)
+                if missed is None:
+                    # Fell further behind than the buffer reaches
+                    await hub.send_resync(event_uuid, user_id)
+                else:
+                    for missed_message in missed:
+                        await websocket.send_json(missed_message)
 
-                        # Update segment status to reflect quiz in progress
-                        segment.status = SegmentStatus.QUIZZING.value
-                        segment.quiz_started_at = datetime.now(timezone.utc)
-                        await db.commit()
+            elif msg_type == "discard_question" and user_id:
+                async with async_session_maker() as db:
+                    try:
+                        error = await discard_question(db, event_uuid, user_id)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                        time_limit = session.game_state.time_limit_seconds
-                        await hub.broadcast(event_uuid, GameStartedMessage().model_dump())
-                        if session.game_state.presenter_paused:
-                            await hub.broadcast(
-                                event_uuid,
-                                PresenterPausedMessage(
-                                    presenter_id=session.game_state.current_presenter_id or user_id,
-                                    presenter_name=segment.presenter_name or "Presenter",
-                                    segment_id=segment.id,
-                                    question_index=session.game_state.current_question_index,
-                                    total_questions=session.game_state.total_questions,
-                                    reason="no_participants",
-                                ).model_dump(),
-                            )
-                            await hub.broadcast(
-                                event_uuid,
-                                PhaseChangedMessage(
-                                    phase=QuizPhase.PRESENTER_PAUSED,
-                                    question_index=0,
-                                    total_questions=len(questions),
-                                ).model_dump(),
-                            )
-                        else:
-                            await hub.broadcast(
-                                event_uuid,
-                                PhaseChangedMessage(
-                                    phase=QuizPhase.SHOWING_QUESTION,
-                                    question_index=0,
-                                    total_questions=len(questions),
-                                ).model_dump(),
-                            )
-                            await hub.broadcast(
-                                event_uuid,
-                                _build_question_payload(
-                                    question_id=questions[0].id,
-                                    question_text=questions[0].question_text,
-                                    correct_answer=questions[0].correct_answer,
-                                    fake_answers=questions[0].fake_answers or [],
-                                    total_questions=len(questions),
-                                    time_limit=time_limit,
-                                    index=0,
-                                ).model_dump(),
-                            )
+            elif msg_type in {"undo_stroke", "delete_stroke"} and user_id:
+                async with async_session_maker() as db:
+                    try:
+                        error = await remove_canvas_stroke(
+                            db,
+                            event_uuid,
+                            user_id,
+                            message.stroke_id if msg_type == "delete_stroke" else None,
+                        )
                     except Exception:
                         await db.rollback()
                         raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-            elif msg_type == "next_question" and user_id:
+            elif msg_type == "request_leaderboard" and user_id:
                 session = await hub.get_or_create_session(event_uuid)
-                # Ensure there is an active quiz
-                if not session.game_state.questions:
+                async with async_session_maker() as db:
+                    try:
+                        error = await _send_leaderboard_snapshot(db, session, event_uuid, websocket)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
+
+            elif msg_type == "get_status" and user_id:
+                await _send_status(event_uuid, websocket)
+
+            elif msg_type == "resume" and user_id:
+                if message.user_id != user_id:
                     await websocket.send_json(
-                        ErrorMessage(message="No active quiz to advance").model_dump()
+                        ErrorMessage(message="Can only resume your own session").model_dump()
                     )
                     continue
+                await _send_resume(event_uuid, websocket, user_id, message.last_question_id)
 
-                # Authorization check: host or presenter
+            elif msg_type == "force_complete_event" and user_id:
                 async with async_session_maker() as db:
                     try:
-                        event_row = await db.get(Event, event_uuid)
-                        segment_id = session.game_state.current_segment_id
-                        segment_row = await db.get(Segment, segment_id) if segment_id else None
-                        if not event_row or not segment_row or not _can_control_segment(event_row, segment_row, user_id):
-                            await websocket.send_json(
-                                ErrorMessage(message="Only the host or presenter can change questions").model_dump()
-                            )
-                            continue
-
-                        await _apply_zero_scores_for_unanswered(db, session)
-
-                        next_index = session.game_state.current_question_index + 1
-                        questions = session.game_state.questions
-                        if next_index >= len(questions):
-                            session.game_state.quiz_phase = QuizPhase.SEGMENT_COMPLETE
-                            await hub.broadcast(event_uuid, GameEndedMessage().model_dump())
-                            await hub.broadcast(
-                                event_uuid,
-                                PhaseChangedMessage(
-                                    phase=QuizPhase.SEGMENT_COMPLETE,
-                                    question_index=session.game_state.current_question_index,
-                                    total_questions=len(questions),
-                                ).model_dump(),
-                            )
+                        error = await force_complete_event(db, event_uuid, user_id)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                            # Mark segment complete and broadcast results
-                            if segment_id:
-                                segment_row = await db.get(Segment, segment_id)
-                                if segment_row:
-                                    segment_row.status = SegmentStatus.COMPLETED.value
-                                    segment_row.ended_at = datetime.now(timezone.utc)
-                                    await db.commit()
-
-                                    segment_lb = await _get_segment_leaderboard(db, segment_id)
-                                    event_lb = await _get_event_leaderboard(db, event_uuid, session)
-                                    await hub.broadcast(
-                                        event_uuid,
-                                        SegmentCompleteMessage(
-                                            segment_id=segment_id,
-                                            segment_title=segment_row.title or "Segment",
-                                            presenter_name=segment_row.presenter_name,
-                                            segment_leaderboard=segment_lb,
-                                            event_leaderboard=event_lb,
-                                            segment_winner=segment_lb[0] if segment_lb else None,
-                                            event_leader=event_lb[0] if event_lb else None,
-                                        ).model_dump(),
-                                    )
+            elif msg_type in ("approve_join", "reject_join") and user_id:
+                decide = approve_join if msg_type == "approve_join" else reject_join
+                async with async_session_maker() as db:
+                    try:
+                        error = await decide(db, event_uuid, user_id, message.user_id)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                                    # If all segments complete, broadcast final event leaderboard
-                                    completion = await _maybe_emit_completion_payload(db, event_uuid)
-                                    if completion:
-                                        session.game_state.quiz_phase = (
-                                            QuizPhase.MEGA_QUIZ_READY
-                                            if isinstance(completion, MegaQuizReadyMessage)
-                                            else QuizPhase.EVENT_COMPLETE
-                                        )
-
-                                        await hub.broadcast(event_uuid, completion.model_dump())
-                                        await hub.broadcast(
-                                            event_uuid,
-                                            PhaseChangedMessage(
-                                                phase=session.game_state.quiz_phase,
-                                                question_index=session.game_state.current_question_index,
-                                                total_questions=session.game_state.total_questions,
-                                            ).model_dump(),
-                                        )
-                            continue
+            elif msg_type == "skip_mega_quiz":
+                async with async_session_maker() as db:
+                    try:
+                        session = await hub.get_or_create_session(event_uuid)
+                        final_lb = await _get_event_leaderboard(db, event_uuid, session)
+                        segment_winners = await _get_segment_winners(db, event_uuid)
+                        winner = final_lb[0] if final_lb else None
 
-                        session.game_state.current_question_index = next_index
-                        session.game_state.current_question_id = questions[next_index]["id"]
+                        event_complete = EventCompleteMessage(
+                            event_id=event_uuid,
+                            final_leaderboard=final_lb,
+                            winner=winner,
+                            segment_winners=segment_winners,
+                        )
+                        session.game_state.quiz_phase = QuizPhase.EVENT_COMPLETE
                         session.game_state.presenter_paused = False
-                        session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
-                        session.game_state.question_started_at = datetime.now(timezone.utc)
-                        await hub.clear_answers(event_uuid)
+                        session.game_state.presenter_pause_reason = None
 
-                        time_limit = session.game_state.time_limit_seconds
+                        await hub.broadcast(event_uuid, event_complete.model_dump())
                         await hub.broadcast(
                             event_uuid,
                             PhaseChangedMessage(
-                                phase=QuizPhase.SHOWING_QUESTION,
-                                question_index=next_index,
-                                total_questions=len(questions),
+                                phase=QuizPhase.EVENT_COMPLETE,
+                                question_index=session.game_state.current_question_index,
+                                total_questions=session.game_state.total_questions,
                             ).model_dump(),
                         )
+                    except Exception:
+                        await db.rollback()
+                        raise
 
-                        await hub.broadcast(
-                            event_uuid,
-                            _build_question_payload(
-                                question_id=questions[next_index]["id"],
-                                question_text=questions[next_index]["text"],
-                                correct_answer=questions[next_index]["correct_answer"],
-                                fake_answers=questions[next_index].get("fake_answers", []),
-                                total_questions=len(questions),
-                                time_limit=time_limit,
-                                index=next_index,
-                            ).model_dump(),
-                        )
+            elif msg_type == "start_game" and user_id:
+                async with async_session_maker() as db:
+                    try:
+                        error = await start_event_game(db, event_uuid, user_id)
                     except Exception:
                         await db.rollback()
                         raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
+
+            elif msg_type == "next_question" and user_id:
+                async with async_session_maker() as db:
+                    try:
+                        error = await advance_question(db, event_uuid, user_id)
+                    except Exception:
+                        await db.rollback()
+                        raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
             elif msg_type == "reveal_answer" and user_id:
                 session = await hub.get_or_create_session(event_uuid)
-                if not session.game_state.questions:
-                    await websocket.send_json(ErrorMessage(message="No active question to reveal").model_dump())
-                    continue
+                error = await reveal_current_question(event_uuid, session, user_id)
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
-                current_index = session.game_state.current_question_index
-                question_data = session.game_state.questions[current_index]
-                question = Question(
-                    id=question_data["id"],
-                    segment_id=session.game_state.current_segment_id,
-                    question_text=question_data["text"],
-                    correct_answer=question_data["correct_answer"],
-                    order_index=current_index,
-                )
-
-                # Authorization check
+            elif msg_type == "reveal_final_leaderboard" and user_id:
+                session = await hub.get_or_create_session(event_uuid)
                 async with async_session_maker() as db:
                     try:
-                        event_row = await db.get(Event, event_uuid)
-                        segment_row = await db.get(Segment, session.game_state.current_segment_id)
-                        if not event_row or not segment_row or not _can_control_segment(event_row, segment_row, user_id):
-                            await websocket.send_json(
-                                ErrorMessage(message="Only the host or presenter can reveal answers").model_dump()
-                            )
-                            continue
-
-                        session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
-                        await _apply_zero_scores_for_unanswered(db, session)
-                        segment_lb = await _get_segment_leaderboard(db, segment_row.id) if segment_row else []
-                        event_lb = await _get_event_leaderboard(db, event_uuid, session)
-
-                        reveal_message = _build_reveal_payload(
-                            question=question,
-                            question_index=current_index,
-                            answers=session.game_state.answers_received.values(),
-                        )
-                        reveal_message.segment_leaderboard = segment_lb
-                        reveal_message.event_leaderboard = event_lb
-
-                        await hub.broadcast(event_uuid, reveal_message.model_dump())
-                        await hub.broadcast(
-                            event_uuid,
-                            PhaseChangedMessage(
-                                phase=QuizPhase.REVEALING_ANSWER,
-                                question_index=current_index,
-                                total_questions=session.game_state.total_questions,
-                            ).model_dump(),
-                        )
+                        error = await _reveal_final_leaderboard(db, session, event_uuid, user_id)
                     except Exception:
                         await db.rollback()
                         raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
             elif msg_type == "show_leaderboard" and user_id:
                 session = await hub.get_or_create_session(event_uuid)
@@ -1341,198 +3451,54 @@ async def websocket_event(websocket: WebSocket, event_id: str):
                             )
                             continue
 
+                        team_rankings = (
+                            await _get_team_leaderboard(db, event_uuid) if event_row.team_mode else []
+                        )
                         await hub.broadcast(
                             event_uuid,
-                            LeaderboardMessage(rankings=[]).model_dump(),
+                            LeaderboardMessage(rankings=[], team_rankings=team_rankings).model_dump(),
                         )
                     except Exception:
                         await db.rollback()
                         raise
 
             elif msg_type == "end_game" and user_id:
-                session = await hub.get_or_create_session(event_uuid)
                 async with async_session_maker() as db:
                     try:
-                        event_row = await db.get(Event, event_uuid)
-                        segment_row = await db.get(Segment, session.game_state.current_segment_id) if session.game_state.current_segment_id else None
-                        if not event_row or not segment_row or not _can_control_segment(event_row, segment_row, user_id):
-                            await websocket.send_json(
-                                ErrorMessage(message="Only the host or presenter can end the quiz").model_dump()
-                            )
-                            continue
-
-                        await _apply_zero_scores_for_unanswered(db, session)
-
-                        session.game_state.quiz_phase = QuizPhase.SEGMENT_COMPLETE
-                        await hub.broadcast(event_uuid, GameEndedMessage().model_dump())
-                        await hub.broadcast(
-                            event_uuid,
-                            PhaseChangedMessage(
-                                phase=QuizPhase.SEGMENT_COMPLETE,
-                                question_index=session.game_state.current_question_index,
-                                total_questions=session.game_state.total_questions,
-                            ).model_dump(),
-                        )
-
-                        # Persist segment completion
-                        segment_row.status = SegmentStatus.COMPLETED.value
-                        segment_row.ended_at = datetime.now(timezone.utc)
-                        await db.commit()
-
-                        # Broadcast segment completion payload
-                        segment_lb = await _get_segment_leaderboard(db, segment_row.id)
-                        event_lb = await _get_event_leaderboard(db, event_uuid, session)
-                        await hub.broadcast(
-                            event_uuid,
-                            SegmentCompleteMessage(
-                                segment_id=segment_row.id,
-                                segment_title=segment_row.title or "Segment",
-                                presenter_name=segment_row.presenter_name,
-                                segment_leaderboard=segment_lb,
-                                event_leaderboard=event_lb,
-                                segment_winner=segment_lb[0] if segment_lb else None,
-                                event_leader=event_lb[0] if event_lb else None,
-                            ).model_dump(),
-                        )
-
-                        # If all segments are complete, emit final results
-                        completion = await _maybe_emit_completion_payload(db, event_uuid)
-                        if completion:
-                            session.game_state.quiz_phase = (
-                                QuizPhase.MEGA_QUIZ_READY
-                                if isinstance(completion, MegaQuizReadyMessage)
-                                else QuizPhase.EVENT_COMPLETE
-                            )
-                            await hub.broadcast(event_uuid, completion.model_dump())
-                            await hub.broadcast(
-                                event_uuid,
-                                PhaseChangedMessage(
-                                    phase=session.game_state.quiz_phase,
-                                    question_index=session.game_state.current_question_index,
-                                    total_questions=session.game_state.total_questions,
-                                ).model_dump(),
-                            )
+                        error = await end_event_game(db, event_uuid, user_id)
                     except Exception:
                         await db.rollback()
                         raise
+                if error:
+                    await websocket.send_json(ErrorMessage(message=error).model_dump())
 
     except WebSocketDisconnect:
+        if pending_user_id and not user_id:
+            session = hub.event_sessions.get(event_uuid)
+            if session and session.connections.get(pending_user_id) is websocket:
+                # Approved after their last message; clean up like any participant
+                user_id = pending_user_id
+            else:
+                await hub.release_pending(event_uuid, pending_user_id)
         if user_id:
             # Check if disconnected user was the current presenter
             async with async_session_maker() as db:
                 try:
-                    from sqlalchemy import select
-                    from app.models import Event, Segment, EventParticipant
-
-                    session = await hub.get_or_create_session(event_uuid)
-                    current_presenter_id = session.game_state.current_presenter_id
-                    current_segment_id = session.game_state.current_segment_id
-
-                    if current_presenter_id == user_id and current_segment_id:
-                        # Get presenter name
-                        participant_result = await db.execute(
-                            select(EventParticipant).where(
-                                EventParticipant.event_id == event_uuid,
-                                EventParticipant.id == user_id
-                            )
-                        )
-                        participant = participant_result.scalar_one_or_none()
-
-                        if participant:
-                            session.game_state.presenter_paused = True
-                            session.game_state.presenter_pause_reason = "presenter_disconnected"
-                            session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
-                            session.game_state.question_started_at = None
-
-                            await hub.broadcast(
-                                event_uuid,
-                                PresenterPausedMessage(
-                                    presenter_id=user_id,
-                                    presenter_name=participant.display_name,
-                                    segment_id=current_segment_id,
-                                    question_index=session.game_state.current_question_index,
-                                    total_questions=session.game_state.total_questions,
-                                    reason="presenter_disconnected",
-                                ).model_dump(),
-                            )
-
-                            await hub.broadcast(
-                                event_uuid,
-                                PhaseChangedMessage(
-                                    phase=QuizPhase.PRESENTER_PAUSED,
-                                    question_index=session.game_state.current_question_index,
-                                    total_questions=session.game_state.total_questions,
-                                ).model_dump(),
-                            )
-
-                            # Get event to find host
-                            event_result = await db.execute(
-                                select(Event).where(Event.id == event_uuid)
-                            )
-                            event = event_result.scalar_one_or_none()
-
-                            if event and event.host_id:
-                                await hub.send_to_user(
-                                    event_uuid,
-                                    event.host_id,
-                                    PresenterOverrideNeededMessage(
-                                        presenter_id=user_id,
-                                        presenter_name=participant.display_name,
-                                        segment_id=current_segment_id,
-                                    ).model_dump(),
-                                )
-                                # Send notification to host only
-                                await hub.send_to_user(
-                                    event_uuid,
-                                    event.host_id,
-                                    PresenterDisconnectedMessage(
-                                        presenter_id=user_id,
-                                        presenter_name=participant.display_name,
-                                        segment_id=current_segment_id
-                                    ).model_dump()
-                                )
+                    await _handle_presenter_disconnect(db, event_uuid, user_id)
                 except Exception:
                     pass  # Don't let exceptions during cleanup prevent disconnect
 
             await hub.disconnect(event_uuid, user_id)
-            await hub.broadcast(
+            presenter_name = (
+                participant.display_name
+                if "participant" in locals() and participant
+                else "Presenter"
+            )
+            hub.schedule_departure(
                 event_uuid,
-                ParticipantLeftMessage(user_id=user_id, online=False).model_dump(),
+                user_id,
+                lambda: _announce_participant_left(event_uuid, user_id, presenter_name),
             )
-
-            # If everyone disconnected during an active quiz, pause with reason
-            session = await hub.get_or_create_session(event_uuid)
-            if (
-                session
-                and session.game_state.quiz_phase not in {QuizPhase.NOT_STARTED, QuizPhase.EVENT_COMPLETE, QuizPhase.MEGA_QUIZ_READY}
-                and hub.get_participant_count(event_uuid) == 0
-            ):
-                presenter_name = "Presenter"
-                if "participant" in locals() and participant:
-                    presenter_name = participant.display_name
-                session.game_state.presenter_paused = True
-                session.game_state.presenter_pause_reason = "all_disconnected"
-                session.game_state.quiz_phase = QuizPhase.PRESENTER_PAUSED
-                session.game_state.question_started_at = None
-                await hub.broadcast(
-                    event_uuid,
-                    PresenterPausedMessage(
-                        presenter_id=session.game_state.current_presenter_id or user_id,
-                        presenter_name=presenter_name,
-                        segment_id=session.game_state.current_segment_id or UUID(int=0),
-                        question_index=session.game_state.current_question_index,
-                        total_questions=session.game_state.total_questions,
-                        reason="all_disconnected",
-                    ).model_dump(),
-                )
-                await hub.broadcast(
-                    event_uuid,
-                    PhaseChangedMessage(
-                        phase=QuizPhase.PRESENTER_PAUSED,
-                        question_index=session.game_state.current_question_index,
-                        total_questions=session.game_state.total_questions,
-                    ).model_dump(),
-                )
     except Exception:
         logging.exception("WebSocket handler failed for event %s", event_id)
         if user_id:
@@ -5,15 +5,11 @@ from datetime import datetime, timezone
 from typing import TYPE_CHECKING
 from uuid import UUID
 
+from app.config import get_settings
+
 if TYPE_CHECKING:
     from fastapi import WebSocket
 
-# Heartbeat configuration
-HEARTBEAT_INTERVAL = 15  # Send ping every 15 seconds
-GRACE_PERIOD = 30  # Mark offline after 30 seconds without pong
-CLEANUP_INTERVAL = 60  # Clean up stale connections every 60 seconds
-
-
 class HeartbeatManager:
     """Manages WebSocket heartbeats for connection tracking."""
 
@@ -34,11 +30,12 @@ class HeartbeatManager:
             websocket: WebSocket connection to ping
         """
         self._active_heartbeats[participant_id] = datetime.now(timezone.utc)
+        interval = max(get_settings().ws_heartbeat_interval_seconds, 1)
         
         async def heartbeat_loop():
             try:
                 while True:
-                    await asyncio.sleep(HEARTBEAT_INTERVAL)
+                    await asyncio.sleep(interval)
                     await websocket.send_json({"type": "ping"})
             except (asyncio.CancelledError, Exception):
                 # Heartbeat stopped or connection closed
@@ -83,7 +80,7 @@ class HeartbeatManager:
 
     def is_connection_healthy(self, participant_id: UUID) -> bool:
         """
-        Check if a connection is healthy (received pong within grace period).
+        Check if a connection is healthy (received pong within the heartbeat timeout).
         
         Args:
             participant_id: Participant to check
@@ -96,7 +93,7 @@ class HeartbeatManager:
             return False
         
         time_since_heartbeat = (datetime.now(timezone.utc) - last_heartbeat).total_seconds()
-        return time_since_heartbeat <= GRACE_PERIOD
+        return time_since_heartbeat <= get_settings().ws_heartbeat_timeout_seconds
 
     async def cleanup_stale_connections(self) -> list[UUID]:
         """
@@ -107,10 +104,11 @@ class HeartbeatManager:
         """
         stale_participants = []
         now = datetime.now(timezone.utc)
+        timeout = get_settings().ws_heartbeat_timeout_seconds
         
         for participant_id, last_heartbeat in list(self._active_heartbeats.items()):
             time_since_heartbeat = (now - last_heartbeat).total_seconds()
-            if time_since_heartbeat > GRACE_PERIOD:
+            if time_since_heartbeat > timeout:
                 stale_participants.append(participant_id)
         
         return stale_participants
@@ -1,19 +1,34 @@
 """WebSocket Hub - manages connections and game state."""
 
 import asyncio
+import secrets
+from collections import deque
+from collections.abc import Awaitable, Callable
 from dataclasses import dataclass, field
-from datetime import datetime, timezone
+from datetime import datetime, timedelta, timezone
 from typing import Any, Set
 from uuid import UUID
 
 from fastapi import WebSocket
 
 from app.config import get_settings
-from app.ws.messages import ParticipantInfo, QuizPhase
+from app.ws.messages import ParticipantInfo, QuizPhase, StateResyncMessage
 from app.ws.heartbeat import heartbeat_manager
 
 settings = get_settings()
 
+# Phases after the reveal in which answers for the current question are rejected
+ANSWERS_CLOSED_PHASES = {
+    QuizPhase.REVEALING_ANSWER,
+    QuizPhase.SHOWING_LEADERBOARD,
+    QuizPhase.BETWEEN_QUESTIONS,
+    QuizPhase.SEGMENT_COMPLETE,
+    QuizPhase.EVENT_COMPLETE,
+}
+
+# Phases in which the current question is open
+QUESTION_OPEN_PHASES = {QuizPhase.SHOWING_QUESTION, QuizPhase.BUZZING}
+
 
 @dataclass
 class GameState:
@@ -26,6 +41,10 @@ class GameState:
     current_question_index: int = 0
     question_started_at: datetime | None = None
     time_limit_seconds: int = 30
+    # The event's time_per_question, for questions without their own time limit
+    default_time_limit_seconds: int = 30
+    # Who started the current segment while it waited for questions; it starts as them
+    waiting_starter_id: UUID | None = None
     quiz_phase: QuizPhase = QuizPhase.NOT_STARTED
     presenter_paused: bool = False
     presenter_pause_reason: str | None = None
@@ -38,6 +57,61 @@ class GameState:
     answers_received: dict[UUID, str] = field(default_factory=dict)
     total_questions: int = 0
     scored_question_ids: Set[UUID] = field(default_factory=set)
+    # Leaderboards were withheld from the final reveal and await the host's release
+    final_leaderboard_frozen: bool = False
+    # Last participant_selecting broadcast per user, for rate limiting
+    selecting_broadcast_at: dict[UUID, datetime] = field(default_factory=dict)
+    # Buzzer rounds: questions open in BUZZING and only the buzz winner may answer
+    buzzer_mode: bool = False
+    buzz_winner_id: UUID | None = None
+    buzz_deadline: datetime | None = None
+    # Client-side timer display ("count_down" or "count_up"), echoed in question broadcasts
+    timer_display_mode: str = "count_down"
+    # Event rng_seed; makes option shuffles reproducible across reruns (None is random)
+    rng_seed: int | None = None
+    # Option order each question of the segment was first sent in, reused by resends and the reveal
+    answer_options: dict[UUID, list[str]] = field(default_factory=dict)
+    # Broadcast server-timed countdown, time-up and reveal cues
+    audio_cues: bool = False
+    # Two-step answering: selections stay provisional until locked (or the timer runs out)
+    answer_lock: bool = False
+    # Unlocked selection and when it was made, per participant
+    provisional_answers: dict[UUID, tuple[str, datetime]] = field(default_factory=dict)
+    # The presenter plays along: answers, is scored and counts toward "everyone answered"
+    host_can_answer: bool = False
+    # (rank, score) per participant at the last reveal, keyed by segment id or "event"
+    reveal_leaderboard_baselines: dict[str, dict[UUID, tuple[int, Any]]] = field(default_factory=dict)
+    # (question id, question_started_at) of the last question whose reveal has begun
+    revealed_question: tuple[UUID, datetime | None] | None = None
+
+    @property
+    def question_phase(self) -> QuizPhase:
+        """Phase a newly shown question opens in."""
+        return QuizPhase.BUZZING if self.buzzer_mode else QuizPhase.SHOWING_QUESTION
+
+    def counts_as_answerer(self, user_id: UUID) -> bool:
+        """Whether the user answers questions (the presenter only with host_can_answer)."""
+        return self.host_can_answer or user_id != self.current_presenter_id
+
+    def claim_reveal(self, question_id: UUID) -> bool:
+        """Mark the question as revealed; False if this showing of it already was.
+
+        Keyed on the start time too, so a question shown again can be revealed again.
+        """
+        key = (question_id, self.question_started_at)
+        if self.revealed_question == key:
+            return False
+        self.revealed_question = key
+        return True
+
+
+@dataclass
+class ReconnectToken:
+    """A short-lived token letting a client resume its session after a drop."""
+
+    event_id: UUID
+    user_id: UUID
+    expires_at: datetime
 
 
 @dataclass
@@ -49,6 +123,23 @@ class EventSession:
     game_state: GameState = field(default_factory=lambda: GameState(event_id=UUID(int=0)))
     # Track participant connection states: 'connected', 'temporarily_disconnected', 'disconnected'
     connection_states: dict[UUID, str] = field(default_factory=dict)
+    # Sockets of joiners waiting for host approval; they get no broadcasts and don't count
+    pending_connections: dict[UUID, WebSocket] = field(default_factory=dict)
+    # (user, segment, permission) -> when a granted control check stops being trusted
+    controller_grants: dict[tuple[UUID, UUID, str | None], datetime] = field(default_factory=dict)
+    # Sends the current question's countdown and time-up cues
+    cue_task: asyncio.Task | None = None
+    # Sends the current question's periodic time_remaining ticks
+    countdown_task: asyncio.Task | None = None
+    # Reveals the current question when its timer runs out (settings.auto_reveal_on_time_up)
+    reveal_task: asyncio.Task | None = None
+    # Users who dropped and are inside the reconnect window; they still count as present
+    departures: dict[UUID, asyncio.Task] = field(default_factory=dict)
+    # Sequence number of the last broadcast, and the most recent broadcasts for replay
+    broadcast_seq: int = 0
+    recent_broadcasts: deque[dict[str, Any]] = field(
+        default_factory=lambda: deque(maxlen=max(settings.ws_replay_buffer_size, 0))
+    )
 
     def __post_init__(self):
         self.game_state = GameState(event_id=self.event_id)
@@ -59,6 +150,9 @@ class Hub:
 
     def __init__(self):
         self.event_sessions: dict[UUID, EventSession] = {}
+        self.reconnect_tokens: dict[str, ReconnectToken] = {}
+        # WebSockets currently open on this server, joined or not
+        self.open_sockets = 0
         self._lock = asyncio.Lock()
 
     def _get_or_create_session_unsafe(self, event_id: UUID) -> EventSession:
@@ -80,6 +174,7 @@ class Hub:
             session = self._get_or_create_session_unsafe(event_id)
             session.connections[user_id] = websocket
             session.connection_states[user_id] = 'connected'
+            self._cancel_departure(session, user_id)
             
             # Start heartbeat tracking for this connection
             await heartbeat_manager.start_heartbeat(user_id, websocket)
@@ -113,6 +208,82 @@ class Hub:
                 if participant:
                     participant.online = False
 
+    def schedule_departure(
+        self, event_id: UUID, user_id: UUID, on_left: Callable[[], Awaitable[None]]
+    ) -> None:
+        """Run ``on_left`` once a dropped user has stayed away for the reconnect window.
+
+        Reconnecting inside the window cancels it, so brief drops never show as leaving.
+        """
+        session = self.event_sessions.get(event_id)
+        if session is None:
+            return
+        self._cancel_departure(session, user_id)
+        session.departures[user_id] = asyncio.create_task(
+            self._depart_after_window(session, user_id, on_left)
+        )
+
+    async def _depart_after_window(
+        self, session: EventSession, user_id: UUID, on_left: Callable[[], Awaitable[None]]
+    ) -> None:
+        await asyncio.sleep(max(settings.ws_reconnect_window_seconds, 0))
+        session.departures.pop(user_id, None)
+        if user_id in session.connections:
+            return
+        session.connection_states[user_id] = 'disconnected'
+        await on_left()
+
+    def _cancel_departure(self, session: EventSession, user_id: UUID) -> None:
+        task = session.departures.pop(user_id, None)
+        if task:
+            task.cancel()
+
+    async def hold_pending(self, event_id: UUID, user_id: UUID, websocket: WebSocket) -> None:
+        """Park a joiner's socket until the host approves or rejects them."""
+        async with self._lock:
+            session = self._get_or_create_session_unsafe(event_id)
+            session.pending_connections[user_id] = websocket
+
+    async def release_pending(self, event_id: UUID, user_id: UUID) -> WebSocket | None:
+        """Remove a pending joiner, returning their socket if they were still waiting."""
+        async with self._lock:
+            session = self.event_sessions.get(event_id)
+            if session is None:
+                return None
+            return session.pending_connections.pop(user_id, None)
+
+    def is_pending(self, event_id: UUID, user_id: UUID) -> bool:
+        """True while the user is waiting for host approval."""
+        session = self.event_sessions.get(event_id)
+        return session is not None and user_id in session.pending_connections
+
+    async def close_session(self, event_id: UUID) -> None:
+        """Drop an event's session, closing its sockets and forgetting its reconnect tokens."""
+        async with self._lock:
+            session = self.event_sessions.pop(event_id, None)
+            self.reconnect_tokens = {
+                token: entry
+                for token, entry in self.reconnect_tokens.items()
+                if entry.event_id != event_id
+            }
+        if session is None:
+            return
+
+        for task in (
+            session.cue_task,
+            session.countdown_task,
+            session.reveal_task,
+            *session.departures.values(),
+        ):
+            if task:
+                task.cancel()
+        for user_id, websocket in {**session.connections, **session.pending_connections}.items():
+            heartbeat_manager.stop_heartbeat(user_id)
+            try:
+                await websocket.close()
+            except Exception:
+                pass  # Already gone
+
     async def add_participant(
         self, event_id: UUID, participant: ParticipantInfo
     ) -> None:
@@ -122,8 +293,15 @@ class Hub:
             participant.online = True
             session.game_state.participants[participant.user_id] = participant
 
-    async def broadcast(self, event_id: UUID, message: dict[str, Any]) -> None:
-        """Broadcast a message to all connections in an event."""
+    async def broadcast(
+        self, event_id: UUID, message: dict[str, Any], replayable: bool = True
+    ) -> None:
+        """Broadcast a message to all connections in an event.
+
+        Replayable messages get a ``seq`` number and are kept so a client that
+        reconnects can fetch what it missed; pass ``replayable=False`` for
+        ephemeral indicators that are stale by the time anyone could replay them.
+        """
         # Get connections to broadcast to (read operation, can be outside lock for better performance)
         if event_id not in self.event_sessions:
             return
@@ -131,19 +309,88 @@ class Hub:
         session = self.event_sessions[event_id]
         # Create a snapshot of connections to avoid holding lock during network I/O
         async with self._lock:
+            if replayable:
+                session.broadcast_seq += 1
+                message = {**message, "seq": session.broadcast_seq}
+                session.recent_broadcasts.append(message)
             connections = dict(session.connections.items())
         
         disconnected = []
+        lagged = []
         for user_id, websocket in connections.items():
             try:
-                await websocket.send_json(message)
+                await self._send_with_timeout(websocket, message)
+            except asyncio.TimeoutError:
+                # A slow client missed this message but is still there
+                lagged.append(user_id)
             except Exception:
                 disconnected.append(user_id)
 
+        for user_id in lagged:
+            if not await self._resync(session, user_id, connections[user_id]):
+                disconnected.append(user_id)
+
         # Handle disconnections (this acquires lock internally)
         for user_id in disconnected:
             await self.disconnect(event_id, user_id)
 
+    def messages_since(self, event_id: UUID, last_seq: int) -> list[dict[str, Any]] | None:
+        """Broadcasts after ``last_seq``, oldest first.
+
+        Returns None when some of them have already left the buffer, in which
+        case the client needs a full resync instead.
+        """
+        session = self.event_sessions.get(event_id)
+        if session is None or last_seq >= session.broadcast_seq:
+            return []
+        buffer = session.recent_broadcasts
+        oldest_seq = buffer[0]["seq"] if buffer else session.broadcast_seq + 1
+        if last_seq + 1 < oldest_seq:
+            return None
+        return [message for message in buffer if message["seq"] > last_seq]
+
+    async def send_resync(self, event_id: UUID, user_id: UUID) -> None:
+        """Send one connected user the current game state."""
+        session = self.event_sessions.get(event_id)
+        websocket = session.connections.get(user_id) if session else None
+        if websocket is not None and not await self._resync(session, user_id, websocket):
+            await self.disconnect(event_id, user_id)
+
+    async def _send_with_timeout(self, websocket: WebSocket, message: dict[str, Any]) -> None:
+        """Send to one client, giving up after the configured send timeout."""
+        timeout = settings.ws_send_timeout_seconds
+        if timeout > 0:
+            await asyncio.wait_for(websocket.send_json(message), timeout=timeout)
+        else:
+            await websocket.send_json(message)
+
+    async def _resync(self, session: EventSession, user_id: UUID, websocket: WebSocket) -> bool:
+        """Send a lagging client the current game state so it can catch up.
+
+        Returns False only when the connection turned out to be closed; a resync
+        that is itself slow leaves the client connected for the heartbeat to judge.
+        """
+        state = session.game_state
+        question_open = state.quiz_phase in QUESTION_OPEN_PHASES
+        resync = StateResyncMessage(
+            event_id=session.event_id,
+            segment_id=state.current_segment_id,
+            current_phase=state.quiz_phase,
+            current_question_id=state.current_question_id,
+            question_index=state.current_question_index,
+            total_questions=state.total_questions,
+            time_limit=state.time_limit_seconds if question_open else None,
+            question_started_at=state.question_started_at if question_open else None,
+            participants=list(state.participants.values()),
+        )
+        try:
+            await self._send_with_timeout(websocket, resync.model_dump(mode="json"))
+        except asyncio.TimeoutError:
+            pass
+        except Exception:
+            return False
+        return True
+
     async def broadcast_to_event(self, event_id: UUID, message: dict[str, Any]) -> None:
         """Alias for broadcast - broadcasts a message to all connections in an event."""
         await self.broadcast(event_id, message)
@@ -185,32 +432,127 @@ class Hub:
     async def record_answer(
         self, event_id: UUID, user_id: UUID, answer: str, submitted_at: datetime | None = None
     ) -> tuple[bool, str | None]:
-        """Record a participant's answer.
+        """Record a participant's answer, replacing their earlier one when changes are allowed.
 
         Returns:
             Tuple of (success: bool, error_reason: str | None)
-            error_reason can be: 'duplicate', 'too_late', 'no_question', or None
+            error_reason can be: 'duplicate', 'too_late', 'no_question', 'revealed',
+            'presenter', or None
         """
         session = self.event_sessions.get(event_id)
         if not session:
             return False, 'no_session'
 
+        error_reason = self._answer_rejection(session, user_id, submitted_at)
+        if error_reason:
+            return False, error_reason
+
+        session.game_state.answers_received[user_id] = answer
+        return True, None
+
+    async def record_selection(
+        self, event_id: UUID, user_id: UUID, answer: str, selected_at: datetime | None = None
+    ) -> tuple[bool, str | None]:
+        """Record or replace a participant's provisional answer in lock mode.
+
+        The selection only counts once locked; it is refused wherever an answer would be.
+        """
+        session = self.event_sessions.get(event_id)
+        if not session:
+            return False, 'no_session'
+
+        selected_at = selected_at or datetime.now(timezone.utc)
+        error_reason = self._answer_rejection(session, user_id, selected_at)
+        if error_reason:
+            return False, error_reason
+
+        session.game_state.provisional_answers[user_id] = (answer, selected_at)
+        return True, None
+
+    async def lock_answer(
+        self, event_id: UUID, user_id: UUID, submitted_at: datetime | None = None
+    ) -> tuple[str | None, str | None]:
+        """Lock in a participant's provisional answer as their final answer.
+
+        Returns:
+            Tuple of (locked answer | None, error_reason | None); error_reason is
+            'no_selection' when nothing was selected, or any record_answer reason
+        """
+        session = self.event_sessions.get(event_id)
+        if not session:
+            return None, 'no_session'
+
+        selection = session.game_state.provisional_answers.get(user_id)
+        if selection is None:
+            return None, 'no_selection'
+        success, error_reason = await self.record_answer(
+            event_id, user_id, selection[0], submitted_at=submitted_at
+        )
+        if not success:
+            return None, error_reason
+        session.game_state.provisional_answers.pop(user_id, None)
+        return selection[0], None
+
+    async def auto_lock_selections(self, event_id: UUID) -> list[tuple[UUID, str, datetime]]:
+        """Lock every pending selection as of when it was made, e.g. once time runs out.
+
+        Returns (user_id, answer, selected_at) for each selection that was locked.
+        """
+        session = self.event_sessions.get(event_id)
+        if not session:
+            return []
+
+        locked = []
+        for user_id, (answer, selected_at) in list(session.game_state.provisional_answers.items()):
+            success, _ = await self.record_answer(event_id, user_id, answer, submitted_at=selected_at)
+            if success:
+                locked.append((user_id, answer, selected_at))
+        session.game_state.provisional_answers.clear()
+        return locked
+
+    def _answer_changes_allowed(self, session: EventSession) -> bool:
+        """Whether a second submission replaces the first (settings.allow_answer_changes).
+
+        Locked-in answers and buzzer answers stay final.
+        """
+        state = session.game_state
+        return settings.allow_answer_changes and not state.answer_lock and not state.buzzer_mode
+
+    def _answer_rejection(
+        self, session: EventSession, user_id: UUID, submitted_at: datetime | None
+    ) -> str | None:
+        """Why an answer from this participant can't be accepted right now, if at all."""
         if session.game_state.presenter_paused:
-            return False, 'paused'
+            return 'paused'
+
+        if not session.game_state.counts_as_answerer(user_id):
+            return 'presenter'
 
         # Check if a question is active
         if not session.game_state.question_started_at:
-            return False, 'no_question'
+            return 'no_question'
+
+        # Once the answer has been revealed the question is closed for scoring
+        if session.game_state.quiz_phase in ANSWERS_CLOSED_PHASES:
+            return 'revealed'
+
+        # In buzzer rounds only the buzz winner may answer, within their window
+        if session.game_state.quiz_phase == QuizPhase.BUZZING:
+            if session.game_state.buzz_winner_id != user_id:
+                return 'not_buzz_winner'
+            deadline = session.game_state.buzz_deadline
+            if deadline and (submitted_at or datetime.now(timezone.utc)) > deadline:
+                return 'too_late'
 
         # Late joiners cannot answer a question that started before they joined
         participant = session.game_state.participants.get(user_id)
         if participant and participant.joined_at and session.game_state.question_started_at:
             if participant.joined_at > session.game_state.question_started_at:
-                return False, 'late_join'
+                return 'late_join'
 
-        # Don't allow duplicate answers
-        if user_id in session.game_state.answers_received:
-            return False, 'duplicate'
+        # Don't allow duplicate answers, unless they change an answer before the reveal
+        if user_id in session.game_state.answers_received and not self._answer_changes_allowed(session):
+            return 'duplicate'
 
         # Validate timing - answer must be submitted within time limit
         submission_time = submitted_at or datetime.now(timezone.utc)
@@ -220,21 +562,59 @@ class Hub:
         grace_ms = max(settings.answer_timeout_grace_ms, 0)
 
         if elapsed_ms >= time_limit_ms + grace_ms:
-            return False, 'too_late'
-
-        session.game_state.answers_received[user_id] = answer
-        return True, None
+            return 'too_late'
+        return None
 
     async def clear_answers(self, event_id: UUID) -> None:
         """Clear all answers for the current question."""
         session = self.event_sessions.get(event_id)
         if session:
             session.game_state.answers_received.clear()
+            session.game_state.provisional_answers.clear()
+            session.game_state.buzz_winner_id = None
+            session.game_state.buzz_deadline = None
+
+    async def record_buzz(
+        self, event_id: UUID, user_id: UUID, now: datetime | None = None
+    ) -> tuple[bool, str | None]:
+        """Grant the first buzz of a buzzer round exclusive answer rights.
+
+        Buzzes are ordered by the hub lock, so exactly one concurrent buzz wins.
+
+        Returns:
+            Tuple of (success: bool, error_reason: str | None)
+            error_reason can be: 'no_session', 'not_buzzing', 'claimed', 'already_answered', or None
+        """
+        async with self._lock:
+            session = self.event_sessions.get(event_id)
+            if not session:
+                return False, 'no_session'
+            state = session.game_state
+            if state.quiz_phase != QuizPhase.BUZZING or state.presenter_paused:
+                return False, 'not_buzzing'
+            if state.buzz_winner_id is not None:
+                return False, 'claimed'
+            if user_id in state.answers_received:
+                return False, 'already_answered'
+
+            state.buzz_winner_id = user_id
+            state.buzz_deadline = (now or datetime.now(timezone.utc)) + timedelta(
+                seconds=settings.buzz_answer_window_seconds
+            )
+            return True, None
+
+    async def reset_buzz(self, event_id: UUID) -> None:
+        """Reopen the buzzer; whoever already answered this question stays locked out."""
+        async with self._lock:
+            session = self.event_sessions.get(event_id)
+            if session:
+                session.game_state.buzz_winner_id = None
+                session.game_state.buzz_deadline = None
 
     def get_participant_count(self, event_id: UUID) -> int:
-        """Get the number of connected participants in an event."""
+        """Get the number of connected participants in an event, counting those inside the reconnect window."""
         session = self.event_sessions.get(event_id)
-        return len(session.connections) if session else 0
+        return len(session.connections.keys() | session.departures.keys()) if session else 0
 
     def get_participant_info(self, event_id: UUID, user_id: UUID) -> ParticipantInfo | None:
         session = self.event_sessions.get(event_id)
@@ -272,6 +652,7 @@ class Hub:
             session = self._get_or_create_session_unsafe(event_id)
             session.connections[user_id] = websocket
             session.connection_states[user_id] = 'connected'
+            self._cancel_departure(session, user_id)
             
             # Restart heartbeat tracking
             await heartbeat_manager.start_heartbeat(user_id, websocket)
@@ -285,7 +666,10 @@ class Hub:
 
     async def cleanup_stale_connections(self) -> dict[UUID, list[UUID]]:
         """
-        Find and mark stale connections as temporarily disconnected.
+        Close connections that stopped answering pings and mark them temporarily disconnected.
+
+        Closing the socket ends its receive loop, so the handler runs the same
+        departure cleanup as for a client-initiated close.
         
         Returns:
             Dictionary of event_id -> list of stale participant IDs
@@ -293,12 +677,17 @@ class Hub:
         stale_by_event: dict[UUID, list[UUID]] = {}
         stale_participants = await heartbeat_manager.cleanup_stale_connections()
         
-        for event_id, session in self.event_sessions.items():
+        for event_id, session in list(self.event_sessions.items()):
             event_stale = []
             for participant_id in stale_participants:
-                if participant_id in session.connections:
+                websocket = session.connections.get(participant_id)
+                if websocket is not None:
                     # Mark as temporarily disconnected
                     await self.disconnect(event_id, participant_id, permanent=False)
+                    try:
+                        await websocket.close(code=1001)
+                    except Exception:
+                        pass
                     event_stale.append(participant_id)
             
             if event_stale:
@@ -306,6 +695,90 @@ class Hub:
         
         return stale_by_event
 
+    def issue_reconnect_token(self, event_id: UUID, user_id: UUID) -> str:
+        """
+        Issue a reconnect token for a participant, replacing any earlier one.
+
+        Args:
+            event_id: Event the participant joined
+            user_id: Participant the token identifies
+
+        Returns:
+            Opaque token string
+        """
+        now = datetime.now(timezone.utc)
+        self.reconnect_tokens = {
+            token: entry
+            for token, entry in self.reconnect_tokens.items()
+            if entry.expires_at > now
+            and not (entry.event_id == event_id and entry.user_id == user_id)
+        }
+        token = secrets.token_urlsafe(32)
+        self.reconnect_tokens[token] = ReconnectToken(
+            event_id=event_id,
+            user_id=user_id,
+            expires_at=now + timedelta(seconds=settings.ws_reconnect_token_ttl_seconds),
+        )
+        return token
+
+    def redeem_reconnect_token(self, event_id: UUID, token: str) -> UUID | None:
+        """
+        Consume a reconnect token.
+
+        Args:
+            event_id: Event the client is reconnecting to
+            token: Token presented by the client
+
+        Returns:
+            The participant ID the token was issued to, or None if invalid or expired
+        """
+        entry = self.reconnect_tokens.pop(token, None)
+        if entry is None or entry.event_id != event_id:
+            return None
+        if entry.expires_at <= datetime.now(timezone.utc):
+            return None
+        return entry.user_id
+
+    def is_controller_cached(
+        self, event_id: UUID, user_id: UUID, segment_id: UUID, permission: str | None = None
+    ) -> bool:
+        """True if the user recently passed the same segment control check."""
+        session = self.event_sessions.get(event_id)
+        if session is None:
+            return False
+        expires_at = session.controller_grants.get((user_id, segment_id, permission))
+        return expires_at is not None and expires_at > datetime.now(timezone.utc)
+
+    def cache_controller(
+        self, event_id: UUID, user_id: UUID, segment_id: UUID, permission: str | None = None
+    ) -> None:
+        """Remember a granted segment control check for ws_controller_cache_ttl_seconds."""
+        ttl = settings.ws_controller_cache_ttl_seconds
+        session = self.event_sessions.get(event_id)
+        if session is None or ttl <= 0:
+            return
+        session.controller_grants[(user_id, segment_id, permission)] = (
+            datetime.now(timezone.utc) + timedelta(seconds=ttl)
+        )
+
+    def invalidate_controller_cache(self, event_id: UUID) -> None:
+        """Forget cached control checks, e.g. after the presenter or their permissions change."""
+        session = self.event_sessions.get(event_id)
+        if session is not None:
+            session.controller_grants.clear()
+
+    def try_open_socket(self) -> bool:
+        """Count a new WebSocket, refusing it once settings.ws_max_connections are open."""
+        limit = settings.ws_max_connections
+        if limit > 0 and self.open_sockets >= limit:
+            return False
+        self.open_sockets += 1
+        return True
+
+    def close_socket(self) -> None:
+        """Stop counting a WebSocket admitted by try_open_socket."""
+        self.open_sockets = max(self.open_sockets - 1, 0)
+
     def get_connection_state(self, event_id: UUID, user_id: UUID) -> str | None:
         """
         Get the connection state for a participant.
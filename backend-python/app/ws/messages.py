@@ -21,6 +21,12 @@ class QuizPhase(str, Enum):
     MEGA_QUIZ = "mega_quiz"
     EVENT_COMPLETE = "event_complete"
     PRESENTER_PAUSED = "presenter_paused"
+    BUZZING = "buzzing"  # Question is up; the first participant to buzz gets to answer
+    WAITING_FOR_QUESTIONS = "waiting_for_questions"  # Segment started before it had questions
+
+
+# Matches participant_answers.selected_answer (VARCHAR(500))
+MAX_SELECTED_ANSWER_LENGTH = 500
 
 
 # Client -> Server messages
@@ -28,15 +34,41 @@ class JoinMessage(BaseModel):
     type: str = "join"
     user_id: UUID
     session_code: str
+    reconnect_token: str | None = None
+    team_id: UUID | None = None  # Team picked in team-mode events
 
 
 class AnswerMessage(BaseModel):
     type: str = "answer"
     question_id: UUID
-    selected_answer: str
+    selected_answer: str = Field(..., max_length=MAX_SELECTED_ANSWER_LENGTH)
     response_time_ms: int
 
 
+class SelectingAnswerMessage(BaseModel):
+    """Ephemeral hint that a participant is choosing an answer; never recorded."""
+    type: str = "selecting_answer"
+    question_id: UUID
+
+
+class SelectAnswerMessage(BaseModel):
+    """Provisional answer in lock mode; can be changed until locked."""
+    type: str = "select_answer"
+    question_id: UUID
+    selected_answer: str = Field(..., max_length=MAX_SELECTED_ANSWER_LENGTH)
+
+
+class LockAnswerMessage(BaseModel):
+    """Lock in the provisional answer so it is scored."""
+    type: str = "lock_answer"
+    question_id: UUID
+
+
+class RevealFinalLeaderboardMessage(BaseModel):
+    """Host releases the leaderboard withheld from a segment's final reveal."""
+    type: str = "reveal_final_leaderboard"
+
+
 class StartGameMessage(BaseModel):
     type: str = "start_game"
 
@@ -95,6 +127,84 @@ class ResumeSegmentMessage(BaseModel):
     segment_id: UUID
 
 
+class GoToSegmentMessage(BaseModel):
+    """Host jumps to a specific segment of the event."""
+    type: str = "go_to_segment"
+    segment_id: UUID
+
+
+class ForceCompleteEventMessage(BaseModel):
+    """Host override: finish the event even though some segments never ran."""
+    type: str = "force_complete_event"
+
+
+class ApproveJoinMessage(BaseModel):
+    """Host lets a pending joiner into the event."""
+    type: str = "approve_join"
+    user_id: UUID
+
+
+class RejectJoinMessage(BaseModel):
+    """Host turns a pending joiner away."""
+    type: str = "reject_join"
+    user_id: UUID
+
+
+class BuzzMessage(BaseModel):
+    """Participant claims the right to answer in a buzzer round."""
+    type: str = "buzz"
+
+
+class ResetBuzzMessage(BaseModel):
+    """Host or presenter reopens the buzzer for the current question."""
+    type: str = "reset_buzz"
+
+
+class RequestLeaderboardMessage(BaseModel):
+    """Client asks for full leaderboards after receiving reveal deltas."""
+    type: str = "request_leaderboard"
+
+
+class GetStatusMessage(BaseModel):
+    """Client polls for the current phase and participant count."""
+    type: str = "get_status"
+
+
+class ResumeMessage(BaseModel):
+    """Reconnected client asks for the open question and its own answer state."""
+    type: str = "resume"
+    user_id: UUID
+    last_question_id: UUID | None = None  # Last question the client saw before dropping
+
+
+class DiscardQuestionMessage(BaseModel):
+    """Host or presenter throws out the current question and moves on."""
+    type: str = "discard_question"
+
+
+class ReplayMessage(BaseModel):
+    """Reconnecting client asks for the broadcasts it missed."""
+    type: str = "replay"
+    since_seq: int = Field(..., ge=0)
+
+
+class AnnounceMessage(BaseModel):
+    """Host or presenter pushes a free-form message to the room."""
+    type: str = "announce"
+    text: str
+
+
+class UndoStrokeMessage(BaseModel):
+    """Remove the sender's most recent canvas stroke."""
+    type: str = "undo_stroke"
+
+
+class DeleteStrokeMessage(BaseModel):
+    """Remove one canvas stroke; the host may remove anyone's, others only their own."""
+    type: str = "delete_stroke"
+    stroke_id: UUID
+
+
 # Server -> Client messages
 class ParticipantInfo(BaseModel):
     user_id: UUID
@@ -109,6 +219,8 @@ class ParticipantInfo(BaseModel):
 class ConnectedMessage(BaseModel):
     type: str = "connected"
     participants: list[ParticipantInfo]
+    reconnect_token: str | None = None
+    branding: dict[str, Any] | None = None
 
 
 class ParticipantJoinedMessage(BaseModel):
@@ -138,6 +250,12 @@ class QuestionMessage(BaseModel):
     text: str
     answers: list[str]
     time_limit: int
+    # Authoritative start of the timer; with time_limit clients can count up or down
+    question_started_at: datetime
+    timer_display_mode: str = "count_down"
+    question_type: str = "multiple_choice"
+    is_bonus: bool = False
+    bonus_multiplier: float = 1.0
 
 
 class TimeUpdateMessage(BaseModel):
@@ -148,12 +266,35 @@ class TimeUpdateMessage(BaseModel):
 class AnswerReceivedMessage(BaseModel):
     type: str = "answer_received"
     user_id: UUID
+    # Answerer's run of correct answers and its bonus percentage; only sent with instant
+    # answer feedback, since a streak gives away whether the answer was right
+    streak: int | None = None
+    streak_bonus: int | None = None
+
+
+class ParticipantSelectingMessage(BaseModel):
+    type: str = "participant_selecting"
+    user_id: UUID
+
+
+class AnswerSelectedMessage(BaseModel):
+    """Confirms a participant's provisional selection to them only."""
+    type: str = "answer_selected"
+    question_id: UUID
+    selected_answer: str
 
 
 class AnswerDistribution(BaseModel):
     answer: str
     count: int
     percentage: float
+    distance: float | None = None
+
+
+class NumericGuess(BaseModel):
+    user_id: UUID
+    guess: float
+    distance: float
 
 
 class RevealMessage(BaseModel):
@@ -162,14 +303,72 @@ class RevealMessage(BaseModel):
     question_number: int
     question_text: str
     correct_answer: str
+    explanation: str | None = None
     distribution: list[AnswerDistribution]
+    guesses: list[NumericGuess] = Field(default_factory=list)
     segment_leaderboard: list[dict[str, Any]]
     event_leaderboard: list[dict[str, Any]]
+    team_leaderboard: list[dict[str, Any]] = Field(default_factory=list)
+    # When set, the leaderboard holds only the top N and entries changed since the last reveal
+    segment_leaderboard_is_delta: bool = False
+    event_leaderboard_is_delta: bool = False
+
+
+class AnswerResultMessage(BaseModel):
+    """Private per-user correctness sent instead of the reveal in per-user mode."""
+    type: str = "answer_result"
+    question_id: UUID
+    is_correct: bool
+    selected_answer: str | None = None
+
+
+class YourAnswerResultMessage(BaseModel):
+    """Private correctness and points sent to the answerer right away when instant feedback is on."""
+    type: str = "your_answer_result"
+    question_id: UUID
+    is_correct: bool
+    points: int
+
+
+class FinalLeaderboardMessage(BaseModel):
+    type: str = "final_leaderboard"
+    segment_leaderboard: list[dict[str, Any]]
+    event_leaderboard: list[dict[str, Any]]
+
+
+class LeaderboardSnapshotMessage(BaseModel):
+    """Full leaderboards sent to a client that requested them."""
+    type: str = "leaderboard_snapshot"
+    segment_leaderboard: list[dict[str, Any]]
+    event_leaderboard: list[dict[str, Any]]
+    team_leaderboard: list[dict[str, Any]] = Field(default_factory=list)
+
+
+class StatusMessage(BaseModel):
+    """Current phase and participant count, sent only to the client that asked."""
+    type: str = "status"
+    phase: QuizPhase
+    participant_count: int
+    question_index: int
+    total_questions: int
+
+
+class ResumedMessage(BaseModel):
+    """Where the quiz is for a resuming client; an open question is re-sent after it."""
+    type: str = "resumed"
+    phase: QuizPhase
+    question_id: UUID | None = None
+    question_index: int = 0
+    total_questions: int = 0
+    has_answered: bool = False
+    your_answer: str | None = None
+    question_changed: bool = False  # The open question isn't the one the client last saw
 
 
 class LeaderboardMessage(BaseModel):
     type: str = "leaderboard"
     rankings: list[dict[str, Any]]
+    team_rankings: list[dict[str, Any]] = Field(default_factory=list)
 
 
 class PhaseChangedMessage(BaseModel):
@@ -323,6 +522,20 @@ class StateRestoredMessage(BaseModel):
     participants: list[dict[str, Any]] = Field(default_factory=list)
 
 
+class StateResyncMessage(BaseModel):
+    """Current game state for a client that fell behind on broadcasts."""
+    type: str = "state_resync"
+    event_id: UUID
+    segment_id: UUID | None
+    current_phase: QuizPhase
+    current_question_id: UUID | None = None
+    question_index: int = 0
+    total_questions: int = 0
+    time_limit: int | None = None
+    question_started_at: datetime | None = None
+    participants: list[ParticipantInfo] = Field(default_factory=list)
+
+
 class PongMessage(BaseModel):
     type: str = "pong"
 
@@ -343,6 +556,103 @@ class PresentationStartedMessage(BaseModel):
     presenter_name: str
 
 
+class SegmentSwitchedMessage(BaseModel):
+    """Notify all clients that the host switched the current segment."""
+    type: str = "segment_switched"
+    segment_id: UUID
+    segment_title: str | None = None
+    presenter_id: UUID | None = None
+    presenter_name: str
+    segment_status: str
+
+
+class UpcomingPresenterMessage(BaseModel):
+    """Preview of who presents next, sent on the final question of a segment."""
+    type: str = "upcoming_presenter"
+    segment_id: UUID
+    segment_title: str | None = None
+    presenter_id: UUID | None = None
+    presenter_name: str
+
+
+class JoinRequestedMessage(BaseModel):
+    """Sent to the host when someone asks to join an event that requires approval."""
+    type: str = "join_requested"
+    user_id: UUID
+    username: str
+    avatar_url: str | None = None
+
+
+class JoinPendingMessage(BaseModel):
+    """Tells a joiner they are waiting for the host's approval."""
+    type: str = "join_pending"
+    event_id: UUID
+
+
+class JoinRejectedMessage(BaseModel):
+    """Tells a pending joiner the host turned them away."""
+    type: str = "join_rejected"
+    event_id: UUID
+
+
+class BuzzWinnerMessage(BaseModel):
+    """First buzz of the round; only this participant may answer until the buzzer is reset."""
+    type: str = "buzz_winner"
+    user_id: UUID
+    answer_window_seconds: int
+
+
+class BuzzResetMessage(BaseModel):
+    """The buzzer is open again."""
+    type: str = "buzz_reset"
+
+
+class QuestionDiscardedMessage(BaseModel):
+    """The current question was thrown out; its answers no longer count."""
+    type: str = "question_discarded"
+    question_id: UUID
+
+
+class StrokeRemovedMessage(BaseModel):
+    """A canvas stroke was undone or deleted; clients drop it locally."""
+    type: str = "stroke_removed"
+    stroke_id: UUID
+
+
+class TimeRemainingMessage(BaseModel):
+    """Server-timed time left on the open question, so client countdowns don't drift."""
+    type: str = "time_remaining"
+    question_id: UUID
+    remaining_seconds: float
+
+
+class CountdownTickMessage(BaseModel):
+    """Audio cue: one of the question's final seconds, timed by the server."""
+    type: str = "countdown_tick"
+    question_id: UUID
+    seconds_remaining: int
+
+
+class TimeUpMessage(BaseModel):
+    """Audio cue: the server timer for the question expired."""
+    type: str = "time_up"
+    question_id: UUID
+
+
+class RevealCueMessage(BaseModel):
+    """Audio cue: the answer is about to be revealed."""
+    type: str = "reveal_cue"
+    question_id: UUID
+
+
+class AnnouncementMessage(BaseModel):
+    """Free-form announcement from the host or presenter."""
+    type: str = "announcement"
+    text: str
+    from_user_id: UUID
+    from_name: str
+
+
 class WaitingForPresenterMessage(BaseModel):
     """Notify participants they are waiting for presenter selection."""
     type: str = "waiting_for_presenter"
@@ -356,6 +666,9 @@ def parse_client_message(data: dict[str, Any]) -> BaseModel | None:
     parsers = {
         "join": JoinMessage,
         "answer": AnswerMessage,
+        "selecting_answer": SelectingAnswerMessage,
+        "select_answer": SelectAnswerMessage,
+        "lock_answer": LockAnswerMessage,
         "start_game": StartGameMessage,
         "next_question": NextQuestionMessage,
         "reveal_answer": RevealAnswerMessage,
@@ -368,6 +681,21 @@ def parse_client_message(data: dict[str, Any]) -> BaseModel | None:
         "select_presenter": SelectPresenterMessage,
         "start_presentation": StartPresentationMessage,
         "resume_segment": ResumeSegmentMessage,
+        "go_to_segment": GoToSegmentMessage,
+        "announce": AnnounceMessage,
+        "undo_stroke": UndoStrokeMessage,
+        "delete_stroke": DeleteStrokeMessage,
+        "replay": ReplayMessage,
+        "discard_question": DiscardQuestionMessage,
+        "request_leaderboard": RequestLeaderboardMessage,
+        "get_status": GetStatusMessage,
+        "resume": ResumeMessage,
+        "buzz": BuzzMessage,
+        "reset_buzz": ResetBuzzMessage,
+        "force_complete_event": ForceCompleteEventMessage,
+        "approve_join": ApproveJoinMessage,
+        "reject_join": RejectJoinMessage,
+        "reveal_final_leaderboard": RevealFinalLeaderboardMessage,
         "pong": PongMessage,
     }
     parser = parsers.get(msg_type)
@@ -0,0 +1,3 @@
+-- Remove reveal_order column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS reveal_order;
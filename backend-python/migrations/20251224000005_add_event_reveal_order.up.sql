@@ -0,0 +1,6 @@
+-- Add reveal_order column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS reveal_order VARCHAR(20) NOT NULL DEFAULT 'option';
+
+COMMENT ON COLUMN events.reveal_order IS
+'Reveal distribution ordering: option (answer option order) or count (most-picked first)';
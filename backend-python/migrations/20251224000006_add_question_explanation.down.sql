@@ -0,0 +1,3 @@
+-- Remove explanation column from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS explanation;
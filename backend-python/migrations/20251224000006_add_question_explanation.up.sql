@@ -0,0 +1,5 @@
+-- Add explanation column to questions table for post-reveal review
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS explanation TEXT;
+
+COMMENT ON COLUMN questions.explanation IS 'Brief rationale for the correct answer (AI-generated or manual)';
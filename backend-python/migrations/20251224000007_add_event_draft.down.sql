@@ -0,0 +1,4 @@
+-- Remove draft storage from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS draft,
+DROP COLUMN IF EXISTS draft_updated_at;
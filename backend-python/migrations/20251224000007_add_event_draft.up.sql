@@ -0,0 +1,6 @@
+-- Add draft storage to events table for client auto-save
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS draft JSONB,
+ADD COLUMN IF NOT EXISTS draft_updated_at TIMESTAMP WITH TIME ZONE;
+
+COMMENT ON COLUMN events.draft IS 'Unpublished event edits saved by the client; promoted by publish';
@@ -0,0 +1,5 @@
+-- Remove numeric-range question support from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS question_type,
+DROP COLUMN IF EXISTS tolerance,
+DROP COLUMN IF EXISTS tolerance_mode;
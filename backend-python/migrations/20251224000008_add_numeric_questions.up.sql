@@ -0,0 +1,9 @@
+-- Add numeric-range question support to questions table
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS question_type VARCHAR(50) NOT NULL DEFAULT 'multiple_choice',
+ADD COLUMN IF NOT EXISTS tolerance FLOAT,
+ADD COLUMN IF NOT EXISTS tolerance_mode VARCHAR(20);
+
+COMMENT ON COLUMN questions.question_type IS 'multiple_choice or numeric';
+COMMENT ON COLUMN questions.tolerance IS 'Acceptable distance from the correct answer for numeric questions';
+COMMENT ON COLUMN questions.tolerance_mode IS 'absolute or percent';
@@ -0,0 +1,3 @@
+-- Remove numeric_scoring_mode column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS numeric_scoring_mode;
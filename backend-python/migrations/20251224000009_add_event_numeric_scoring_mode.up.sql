@@ -0,0 +1,6 @@
+-- Add numeric_scoring_mode column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS numeric_scoring_mode VARCHAR(20) NOT NULL DEFAULT 'tolerance';
+
+COMMENT ON COLUMN events.numeric_scoring_mode IS
+'Numeric question scoring: tolerance (full credit within tolerance) or proximity (points scale with closeness)';
@@ -0,0 +1,3 @@
+-- Remove weight column from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS weight;
@@ -0,0 +1,6 @@
+-- Add weight column to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS weight DOUBLE PRECISION NOT NULL DEFAULT 1.0;
+
+COMMENT ON COLUMN segments.weight IS
+'Multiplier applied to this segment''s scores when computing the master leaderboard';
@@ -0,0 +1,5 @@
+-- Remove idempotency_key column from events table
+DROP INDEX IF EXISTS uq_event_host_idempotency_key;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS idempotency_key;
@@ -0,0 +1,9 @@
+-- Add idempotency_key column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS idempotency_key VARCHAR(255);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_event_host_idempotency_key
+ON events (host_id, idempotency_key);
+
+COMMENT ON COLUMN events.idempotency_key IS
+'Idempotency-Key header from the creating request; repeated creates with the same key return this event';
@@ -0,0 +1,3 @@
+-- Remove last_manual_question_at column from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS last_manual_question_at;
@@ -0,0 +1,6 @@
+-- Add last_manual_question_at column to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS last_manual_question_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN segments.last_manual_question_at IS
+'When a host last added a question by hand; automatic generation pauses for a cooldown after it';
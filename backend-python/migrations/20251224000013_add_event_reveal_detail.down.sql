@@ -0,0 +1,3 @@
+-- Remove reveal_detail column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS reveal_detail;
@@ -0,0 +1,6 @@
+-- Add reveal_detail column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS reveal_detail VARCHAR(20) NOT NULL DEFAULT 'full';
+
+COMMENT ON COLUMN events.reveal_detail IS
+'Reveal verbosity: full (distribution and answer), correct_only (answer only) or per_user (private correctness only)';
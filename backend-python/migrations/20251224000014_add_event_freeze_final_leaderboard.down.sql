@@ -0,0 +1,3 @@
+-- Remove freeze_final_leaderboard column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS freeze_final_leaderboard;
@@ -0,0 +1,6 @@
+-- Add freeze_final_leaderboard column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS freeze_final_leaderboard BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.freeze_final_leaderboard IS
+'Withhold leaderboards from a segment''s final reveal until the host sends reveal_final_leaderboard';
@@ -0,0 +1,3 @@
+-- Remove enable_ai_quality_scoring column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS enable_ai_quality_scoring;
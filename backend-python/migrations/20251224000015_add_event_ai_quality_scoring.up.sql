@@ -0,0 +1,6 @@
+-- Add enable_ai_quality_scoring column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS enable_ai_quality_scoring BOOLEAN;
+
+COMMENT ON COLUMN events.enable_ai_quality_scoring IS
+'Per-event override for AI quality scoring of generated questions; NULL uses the global setting';
@@ -0,0 +1,5 @@
+-- Remove presenter permission flags from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS can_end,
+DROP COLUMN IF EXISTS can_reveal,
+DROP COLUMN IF EXISTS can_advance;
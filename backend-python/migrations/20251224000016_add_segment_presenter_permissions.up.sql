@@ -0,0 +1,12 @@
+-- Add presenter permission flags to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS can_advance BOOLEAN NOT NULL DEFAULT TRUE,
+ADD COLUMN IF NOT EXISTS can_reveal BOOLEAN NOT NULL DEFAULT TRUE,
+ADD COLUMN IF NOT EXISTS can_end BOOLEAN NOT NULL DEFAULT TRUE;
+
+COMMENT ON COLUMN segments.can_advance IS
+'Whether the segment presenter may move to the next question';
+COMMENT ON COLUMN segments.can_reveal IS
+'Whether the segment presenter may reveal answers';
+COMMENT ON COLUMN segments.can_end IS
+'Whether the segment presenter may end the quiz';
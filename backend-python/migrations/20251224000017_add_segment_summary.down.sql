@@ -0,0 +1,3 @@
+-- Remove summary column from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS summary;
@@ -0,0 +1,6 @@
+-- Add summary column to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS summary TEXT;
+
+COMMENT ON COLUMN segments.summary IS
+'Cached AI-generated summary of the segment transcript';
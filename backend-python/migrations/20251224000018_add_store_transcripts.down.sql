@@ -0,0 +1,6 @@
+-- Remove transcript storage flags from events and segments tables
+ALTER TABLE segments
+DROP COLUMN IF EXISTS store_transcripts;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS store_transcripts;
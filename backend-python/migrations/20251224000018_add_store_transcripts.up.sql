@@ -0,0 +1,11 @@
+-- Add transcript storage flags to events and segments tables
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS store_transcripts BOOLEAN NOT NULL DEFAULT TRUE;
+
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS store_transcripts BOOLEAN;
+
+COMMENT ON COLUMN events.store_transcripts IS
+'Whether presentation transcripts are saved; when false they are only used in memory for question generation';
+COMMENT ON COLUMN segments.store_transcripts IS
+'Per-segment override of events.store_transcripts (NULL follows the event)';
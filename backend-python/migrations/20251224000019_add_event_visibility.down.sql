@@ -0,0 +1,3 @@
+-- Remove visibility column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS visibility;
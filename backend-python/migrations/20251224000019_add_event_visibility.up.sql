@@ -0,0 +1,6 @@
+-- Add visibility column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS visibility VARCHAR(20) NOT NULL DEFAULT 'unlisted';
+
+COMMENT ON COLUMN events.visibility IS
+'Who can find and join the event: public (listed), unlisted (join code only) or private (invite only)';
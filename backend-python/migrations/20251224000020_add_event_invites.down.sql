@@ -0,0 +1,2 @@
+-- Remove invite tokens for private events
+DROP TABLE IF EXISTS event_invites;
@@ -0,0 +1,15 @@
+-- Add invite tokens for private events
+CREATE TABLE IF NOT EXISTS event_invites (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    event_id UUID NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    token VARCHAR(64) NOT NULL UNIQUE,
+    max_uses INTEGER NOT NULL DEFAULT 1,
+    use_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_event_invites_event ON event_invites(event_id);
+
+COMMENT ON TABLE event_invites IS 'Invite tokens required to join private events';
+COMMENT ON COLUMN event_invites.use_count IS 'Number of new participants that have joined with this invite';
@@ -0,0 +1,3 @@
+-- Remove hide_answer_progress column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS hide_answer_progress;
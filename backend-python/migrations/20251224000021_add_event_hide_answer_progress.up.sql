@@ -0,0 +1,6 @@
+-- Add hide_answer_progress column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS hide_answer_progress BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.hide_answer_progress IS
+'When true, answer_received is only sent to the answering participant, so the room sees participation only at reveal';
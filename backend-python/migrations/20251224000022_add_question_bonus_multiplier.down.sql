@@ -0,0 +1,3 @@
+-- Remove bonus_multiplier column from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS bonus_multiplier;
@@ -0,0 +1,6 @@
+-- Add bonus_multiplier column to questions table
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS bonus_multiplier DOUBLE PRECISION NOT NULL DEFAULT 1.0;
+
+COMMENT ON COLUMN questions.bonus_multiplier IS
+'Multiplier applied to the points earned on this question; above 1.0 marks a bonus question';
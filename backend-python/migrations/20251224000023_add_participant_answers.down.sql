@@ -0,0 +1,2 @@
+-- Remove persisted participant answers
+DROP TABLE IF EXISTS participant_answers;
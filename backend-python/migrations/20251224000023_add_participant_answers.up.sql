@@ -0,0 +1,18 @@
+-- Persist each participant's scored answers
+CREATE TABLE IF NOT EXISTS participant_answers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    event_id UUID NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    segment_id UUID NOT NULL REFERENCES segments(id) ON DELETE CASCADE,
+    question_id UUID NOT NULL REFERENCES questions(id) ON DELETE CASCADE,
+    participant_id UUID NOT NULL REFERENCES event_participants(id) ON DELETE CASCADE,
+    selected_answer VARCHAR(500) NOT NULL,
+    is_correct BOOLEAN NOT NULL DEFAULT FALSE,
+    points INTEGER NOT NULL DEFAULT 0,
+    response_time_ms INTEGER,
+    answered_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_participant_answers_event ON participant_answers(event_id);
+CREATE INDEX idx_participant_answers_participant ON participant_answers(participant_id);
+
+COMMENT ON TABLE participant_answers IS 'Scored answers per participant and question, used for review';
@@ -0,0 +1,3 @@
+-- Remove expires_at column from events table
+DROP INDEX IF EXISTS idx_events_expires_at;
+ALTER TABLE events DROP COLUMN IF EXISTS expires_at;
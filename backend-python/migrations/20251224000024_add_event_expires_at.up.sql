@@ -0,0 +1,8 @@
+-- Add expires_at column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS expires_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX IF NOT EXISTS idx_events_expires_at ON events(expires_at);
+
+COMMENT ON COLUMN events.expires_at IS
+'Join code stops working after this if the event was never started; NULL never expires';
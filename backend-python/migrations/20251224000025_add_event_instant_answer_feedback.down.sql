@@ -0,0 +1,3 @@
+-- Remove instant_answer_feedback column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS instant_answer_feedback;
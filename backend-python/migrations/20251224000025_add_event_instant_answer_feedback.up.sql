@@ -0,0 +1,6 @@
+-- Add instant_answer_feedback column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS instant_answer_feedback BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.instant_answer_feedback IS
+'When true, each participant privately learns if their answer was correct as soon as they submit it';
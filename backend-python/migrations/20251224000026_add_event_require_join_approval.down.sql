@@ -0,0 +1,3 @@
+-- Remove require_join_approval column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS require_join_approval;
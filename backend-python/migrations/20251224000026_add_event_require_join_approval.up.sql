@@ -0,0 +1,6 @@
+-- Add require_join_approval column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS require_join_approval BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.require_join_approval IS
+'When true, new participants join as pending_approval and wait for the host to approve or reject them';
@@ -0,0 +1,3 @@
+-- Remove branding column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS branding;
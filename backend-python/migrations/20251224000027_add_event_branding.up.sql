@@ -0,0 +1,6 @@
+-- Add branding column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS branding JSONB;
+
+COMMENT ON COLUMN events.branding IS
+'Optional logo URL and hex colors clients use to theme the room; delivered in the connected message';
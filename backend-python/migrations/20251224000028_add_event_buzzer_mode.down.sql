@@ -0,0 +1,3 @@
+-- Remove buzzer_mode column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS buzzer_mode;
@@ -0,0 +1,6 @@
+-- Add buzzer_mode column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS buzzer_mode BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.buzzer_mode IS
+'When true, questions open in a buzzing phase and only the first participant to buzz may answer';
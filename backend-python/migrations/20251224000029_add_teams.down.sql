@@ -0,0 +1,8 @@
+-- Remove teams for team-mode events
+ALTER TABLE events
+DROP COLUMN IF EXISTS team_mode;
+
+ALTER TABLE event_participants
+DROP COLUMN IF EXISTS team_id;
+
+DROP TABLE IF EXISTS teams;
@@ -0,0 +1,22 @@
+-- Add teams for team-mode events
+CREATE TABLE IF NOT EXISTS teams (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    event_id UUID NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_team_event_name UNIQUE (event_id, name)
+);
+
+CREATE INDEX idx_teams_event ON teams(event_id);
+
+ALTER TABLE event_participants
+ADD COLUMN IF NOT EXISTS team_id UUID REFERENCES teams(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_event_participants_team ON event_participants(team_id);
+
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS team_mode BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON TABLE teams IS 'Teams participants join in team-mode events';
+COMMENT ON COLUMN event_participants.team_id IS 'Team the participant chose when joining; their segment scores count toward it';
+COMMENT ON COLUMN events.team_mode IS 'When true, reveals and leaderboards include team standings aggregated from member scores';
@@ -0,0 +1,3 @@
+-- Remove timer_display_mode column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS timer_display_mode;
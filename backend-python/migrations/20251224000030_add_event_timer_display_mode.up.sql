@@ -0,0 +1,6 @@
+-- Add timer_display_mode column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS timer_display_mode VARCHAR(20) NOT NULL DEFAULT 'count_down';
+
+COMMENT ON COLUMN events.timer_display_mode IS
+'How clients show the question timer: count_down (remaining) or count_up (elapsed); sent with each question';
@@ -0,0 +1,4 @@
+-- Remove started_at and max_duration_seconds columns from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS max_duration_seconds,
+DROP COLUMN IF EXISTS started_at;
@@ -0,0 +1,9 @@
+-- Add started_at and max_duration_seconds columns to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ,
+ADD COLUMN IF NOT EXISTS max_duration_seconds INTEGER;
+
+COMMENT ON COLUMN events.started_at IS
+'When the first quiz of the event started; max_duration_seconds counts from here';
+COMMENT ON COLUMN events.max_duration_seconds IS
+'Live events running longer than this are finalized automatically; NULL means unlimited';
@@ -0,0 +1,3 @@
+-- Remove notes column from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS notes;
@@ -0,0 +1,6 @@
+-- Add notes column to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS notes TEXT;
+
+COMMENT ON COLUMN segments.notes IS
+'Presenter slides/notes given to the AI as extra context alongside the transcript when generating questions';
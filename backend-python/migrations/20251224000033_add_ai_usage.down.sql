@@ -0,0 +1,2 @@
+-- Remove AI token usage records
+DROP TABLE IF EXISTS ai_usage;
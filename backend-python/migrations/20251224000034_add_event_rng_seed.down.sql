@@ -0,0 +1,3 @@
+-- Remove rng_seed column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS rng_seed;
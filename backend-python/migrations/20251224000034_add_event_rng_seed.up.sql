@@ -0,0 +1,6 @@
+-- Add rng_seed column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS rng_seed BIGINT;
+
+COMMENT ON COLUMN events.rng_seed IS
+'Seeds answer option shuffles and distractor ordering so a rerun is identical; NULL means random';
@@ -0,0 +1,3 @@
+-- Remove audio_cues column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS audio_cues;
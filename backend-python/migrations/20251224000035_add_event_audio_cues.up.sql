@@ -0,0 +1,6 @@
+-- Add audio_cues column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS audio_cues BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.audio_cues IS
+'When true, the server broadcasts countdown_tick, time_up and reveal_cue messages so clients play effects in sync';
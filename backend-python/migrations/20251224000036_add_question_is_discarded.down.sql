@@ -0,0 +1,3 @@
+-- Remove is_discarded column from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS is_discarded;
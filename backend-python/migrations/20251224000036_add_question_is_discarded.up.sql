@@ -0,0 +1,6 @@
+-- Add is_discarded column to questions table
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS is_discarded BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN questions.is_discarded IS
+'Thrown out mid-quiz by the host or presenter; its answers were revoked and it is skipped by scoring, stats and replays';
@@ -0,0 +1,3 @@
+-- Remove redact_pii column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS redact_pii;
@@ -0,0 +1,6 @@
+-- Add redact_pii column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS redact_pii BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.redact_pii IS
+'When true, emails and phone numbers are masked in transcripts before they are stored or sent to an AI provider';
@@ -0,0 +1,3 @@
+-- Remove generation_failures column from segments table
+ALTER TABLE segments
+DROP COLUMN IF EXISTS generation_failures;
@@ -0,0 +1,6 @@
+-- Add generation_failures column to segments table
+ALTER TABLE segments
+ADD COLUMN IF NOT EXISTS generation_failures INTEGER NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN segments.generation_failures IS
+'Consecutive question generation attempts that produced no usable question; reset on success or when a fallback question is inserted';
@@ -0,0 +1,3 @@
+-- Remove segment_winner_order column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS segment_winner_order;
@@ -0,0 +1,6 @@
+-- Add segment_winner_order column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS segment_winner_order VARCHAR(20) NOT NULL DEFAULT 'segment';
+
+COMMENT ON COLUMN events.segment_winner_order IS
+'Ordering of segment winners in event_complete: segment (presentation order) or score (highest winning score first)';
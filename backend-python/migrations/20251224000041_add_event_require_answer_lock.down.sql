@@ -0,0 +1,3 @@
+-- Remove require_answer_lock column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS require_answer_lock;
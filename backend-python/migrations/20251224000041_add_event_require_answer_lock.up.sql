@@ -0,0 +1,6 @@
+-- Add require_answer_lock column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS require_answer_lock BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.require_answer_lock IS
+'When true, participants select answers provisionally and only locked answers are scored';
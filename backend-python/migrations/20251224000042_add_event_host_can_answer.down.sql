@@ -0,0 +1,3 @@
+-- Remove host_can_answer column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS host_can_answer;
@@ -0,0 +1,6 @@
+-- Add host_can_answer column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS host_can_answer BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN events.host_can_answer IS
+'When true, the presenter answers questions and is scored and ranked like a participant';
@@ -0,0 +1,3 @@
+-- Remove question_quality_threshold column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS question_quality_threshold;
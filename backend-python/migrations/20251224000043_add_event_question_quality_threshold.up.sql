@@ -0,0 +1,6 @@
+-- Add question_quality_threshold column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS question_quality_threshold DOUBLE PRECISION;
+
+COMMENT ON COLUMN events.question_quality_threshold IS
+'Per-event minimum quality score (0-1) for keeping generated questions; NULL uses the global setting';
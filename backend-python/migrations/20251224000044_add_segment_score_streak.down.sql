@@ -0,0 +1,3 @@
+-- Remove current_streak column from segment_scores table
+ALTER TABLE segment_scores
+DROP COLUMN IF EXISTS current_streak;
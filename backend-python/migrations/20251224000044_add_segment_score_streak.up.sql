@@ -0,0 +1,6 @@
+-- Add current_streak column to segment_scores table
+ALTER TABLE segment_scores
+ADD COLUMN IF NOT EXISTS current_streak INTEGER NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN segment_scores.current_streak IS
+'Consecutive correct answers in the segment; reset by a wrong or missed answer';
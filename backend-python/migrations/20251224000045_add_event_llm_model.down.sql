@@ -0,0 +1,3 @@
+-- Remove llm_model column from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS llm_model;
@@ -0,0 +1,6 @@
+-- Add llm_model column to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS llm_model VARCHAR(100);
+
+COMMENT ON COLUMN events.llm_model IS
+'Per-event AI provider model (e.g. gpt-4o); NULL uses the provider''s configured model';
@@ -0,0 +1,4 @@
+-- Remove negative scoring columns from events table
+ALTER TABLE events
+DROP COLUMN IF EXISTS wrong_answer_penalty,
+DROP COLUMN IF EXISTS allow_negative_scoring;
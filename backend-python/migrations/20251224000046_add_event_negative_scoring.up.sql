@@ -0,0 +1,9 @@
+-- Add negative scoring columns to events table
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS allow_negative_scoring BOOLEAN,
+ADD COLUMN IF NOT EXISTS wrong_answer_penalty INTEGER;
+
+COMMENT ON COLUMN events.allow_negative_scoring IS
+'Per-event switch for subtracting points on wrong answers; NULL uses the global setting';
+COMMENT ON COLUMN events.wrong_answer_penalty IS
+'Points a wrong answer loses when negative scoring is allowed; NULL uses the global setting';
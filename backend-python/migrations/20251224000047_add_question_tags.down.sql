@@ -0,0 +1,3 @@
+-- Remove tags column from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS tags;
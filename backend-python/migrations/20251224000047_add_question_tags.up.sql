@@ -0,0 +1,6 @@
+-- Add tags column to questions table
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS tags JSONB;
+
+COMMENT ON COLUMN questions.tags IS
+'Lowercase topic tags for per-tag accuracy and category leaderboards; NULL when untagged';
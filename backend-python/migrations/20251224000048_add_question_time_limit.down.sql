@@ -0,0 +1,3 @@
+-- Remove time_limit_seconds column from questions table
+ALTER TABLE questions
+DROP COLUMN IF EXISTS time_limit_seconds;
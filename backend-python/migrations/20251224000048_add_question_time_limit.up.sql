@@ -0,0 +1,6 @@
+-- Add time_limit_seconds column to questions table
+ALTER TABLE questions
+ADD COLUMN IF NOT EXISTS time_limit_seconds INTEGER;
+
+COMMENT ON COLUMN questions.time_limit_seconds IS
+'Seconds to answer this question; NULL uses the event''s time_per_question';
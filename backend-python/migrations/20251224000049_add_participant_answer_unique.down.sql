@@ -0,0 +1,3 @@
+-- Drop the one-answer-per-question constraint from participant_answers
+ALTER TABLE participant_answers
+DROP CONSTRAINT IF EXISTS uq_participant_answer_question;
@@ -0,0 +1,9 @@
+-- Allow one stored answer per participant and question
+DELETE FROM participant_answers older
+USING participant_answers newer
+WHERE older.question_id = newer.question_id
+  AND older.participant_id = newer.participant_id
+  AND (older.answered_at, older.id) < (newer.answered_at, newer.id);
+
+ALTER TABLE participant_answers
+ADD CONSTRAINT uq_participant_answer_question UNIQUE (question_id, participant_id);
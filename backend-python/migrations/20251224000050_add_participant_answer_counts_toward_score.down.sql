@@ -0,0 +1,3 @@
+-- Remove counts_toward_score column from participant_answers table
+ALTER TABLE participant_answers
+DROP COLUMN IF EXISTS counts_toward_score;
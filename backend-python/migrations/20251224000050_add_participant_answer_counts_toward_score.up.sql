@@ -0,0 +1,6 @@
+-- Add counts_toward_score column to participant_answers table
+ALTER TABLE participant_answers
+ADD COLUMN IF NOT EXISTS counts_toward_score BOOLEAN NOT NULL DEFAULT TRUE;
+
+COMMENT ON COLUMN participant_answers.counts_toward_score IS
+'FALSE once the participant''s score was reset; the answer stays as history but no longer adds to totals';
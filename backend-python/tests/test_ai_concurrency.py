@@ -0,0 +1,40 @@
+"""Tests for the per-provider AI call concurrency limit."""
+
+import asyncio
+from types import SimpleNamespace
+from unittest.mock import MagicMock
+
+import pytest
+
+from app.config import get_settings
+from app.services.ai import ClaudeProvider
+from app.services.ai import limiter
+
+
+@pytest.mark.anyio
+async def test_limit_of_one_serializes_concurrent_calls(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ai_max_concurrent_calls", 1)
+    monkeypatch.setattr(limiter, "_semaphores", {})
+
+    in_flight = 0
+    max_in_flight = 0
+
+    async def fake_create(**kwargs):
+        nonlocal in_flight, max_in_flight
+        in_flight += 1
+        max_in_flight = max(max_in_flight, in_flight)
+        await asyncio.sleep(0.05)
+        in_flight -= 1
+        return SimpleNamespace(content=[SimpleNamespace(text='["B", "C", "D"]')])
+
+    provider = ClaudeProvider(api_key="test-key")
+    provider.client = MagicMock()
+    provider.client.messages.create = fake_create
+
+    results = await asyncio.gather(
+        provider.generate_fake_answers("Q1?", "A"),
+        provider.generate_fake_answers("Q2?", "A"),
+    )
+
+    assert results == [["B", "C", "D"], ["B", "C", "D"]]
+    assert max_in_flight == 1
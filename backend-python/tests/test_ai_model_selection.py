@@ -0,0 +1,37 @@
+"""Tests for picking the AI provider model per event."""
+
+from unittest.mock import AsyncMock, MagicMock
+
+import pytest
+
+from app.services.ai import ClaudeProvider, OpenAIProvider, create_ai_provider
+
+
+@pytest.mark.anyio
+async def test_openai_request_uses_the_chosen_model():
+    provider = OpenAIProvider(api_key="test-key", model="gpt-4o")
+    response = MagicMock()
+    response.choices = [MagicMock()]
+    response.choices[0].message.content = '["Paris", "Rome", "Madrid"]'
+    provider.client.chat.completions.create = AsyncMock(return_value=response)
+
+    await provider.generate_fake_answers("Capital of Germany?", "Berlin")
+
+    assert provider.client.chat.completions.create.await_args.kwargs["model"] == "gpt-4o"
+
+
+@pytest.mark.anyio
+async def test_claude_request_uses_the_chosen_model():
+    provider = ClaudeProvider(api_key="test-key", model="claude-3-5-haiku-20241022")
+    response = MagicMock()
+    response.content = [MagicMock(text='["Paris", "Rome", "Madrid"]')]
+    provider.client.messages.create = AsyncMock(return_value=response)
+
+    await provider.generate_fake_answers("Capital of Germany?", "Berlin")
+
+    assert provider.client.messages.create.await_args.kwargs["model"] == "claude-3-5-haiku-20241022"
+
+
+def test_factory_falls_back_to_the_configured_model():
+    assert create_ai_provider("openai", "gpt-4o-mini").model == "gpt-4o-mini"
+    assert create_ai_provider("claude").model == "claude-3-sonnet-20240229"
@@ -0,0 +1,99 @@
+"""Tests for per-event AI token usage and cost estimates."""
+
+import json
+import uuid
+from types import SimpleNamespace
+from unittest.mock import MagicMock, patch
+from uuid import UUID
+
+import pytest
+
+from app.config import get_settings
+from app.models import AIUsage, Event
+from app.routes.segments import _generate_questions_for_transcript
+
+TRANSCRIPT = (
+    "The Eiffel Tower was completed in 1889 as the entrance arch for the World's Fair "
+    "in Paris, and it remained the tallest man-made structure for forty-one years."
+)
+
+
+@pytest.mark.anyio
+async def test_usage_is_aggregated_with_cost_estimate(client, test_session, monkeypatch):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "default_ai_provider", "claude")
+    monkeypatch.setattr(settings, "ai_token_prices", "claude-3-sonnet-20240229=3:15")
+
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event_response = await client.post("/api/quizzes", json={"title": "Paid"}, headers=headers)
+    event_id = UUID(event_response.json()["id"])
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = UUID(segment.json()["id"])
+
+    async def fake_create(**kwargs):
+        return SimpleNamespace(
+            content=[SimpleNamespace(text=json.dumps({
+                "question": "When was the Eiffel Tower completed?",
+                "correct_answer": "1889",
+                "fake_answers": ["1901", "1875", "1925"],
+            }))],
+            usage=SimpleNamespace(input_tokens=1000, output_tokens=200),
+        )
+
+    anthropic_client = MagicMock()
+    anthropic_client.messages.create = fake_create
+    event = await test_session.get(Event, event_id)
+    with patch("app.services.ai.claude.AsyncAnthropic", return_value=anthropic_client):
+        for _ in range(2):
+            generated = await _generate_questions_for_transcript(
+                db=test_session,
+                segment_id=segment_id,
+                transcript_text=TRANSCRIPT,
+                event=event,
+                settings=settings,
+            )
+            assert len(generated) == 1
+    # A model missing from the price table is counted but not priced
+    test_session.add(
+        AIUsage(event_id=event_id, provider="openai", model="unpriced-model", input_tokens=50, output_tokens=5)
+    )
+    await test_session.commit()
+
+    response = await client.get(f"/api/events/{event_id}/ai-usage", headers=headers)
+
+    assert response.status_code == 200
+    body = response.json()
+    assert body["input_tokens"] == 2050
+    assert body["output_tokens"] == 405
+    assert body["estimated_cost_usd"] == pytest.approx(2 * (1000 * 3 + 200 * 15) / 1_000_000)
+    claude, unpriced = body["models"]
+    assert claude == {
+        "provider": "claude",
+        "model": "claude-3-sonnet-20240229",
+        "calls": 2,
+        "input_tokens": 2000,
+        "output_tokens": 400,
+        "estimated_cost_usd": pytest.approx(0.012),
+    }
+    assert unpriced["estimated_cost_usd"] is None
+
+
+@pytest.mark.anyio
+async def test_ai_usage_is_host_only(client, test_event):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"other_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+
+    response = await client.get(f"/api/events/{test_event.id}/ai-usage", headers=headers)
+
+    assert response.status_code == 403
@@ -0,0 +1,67 @@
+"""Tests for telling only the presenter that everyone has answered."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _notify_all_answered
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo, QuizPhase
+
+
+def _received_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+async def _open_question(event_id, presenter, players):
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    state.current_presenter_id = presenter
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+    for user_id in [presenter, *players]:
+        state.participants[user_id] = ParticipantInfo(user_id=user_id, username=str(user_id))
+    return session
+
+
+@pytest.mark.anyio
+async def test_all_answered_goes_only_to_the_presenter():
+    event_id = uuid4()
+    presenter, players = uuid4(), [uuid4(), uuid4()]
+    session = await _open_question(event_id, presenter, players)
+    sockets = {user_id: AsyncMock() for user_id in [presenter, *players]}
+    session.connections.update(sockets)
+
+    try:
+        await hub.record_answer(event_id, players[0], "A")
+        assert await _notify_all_answered(event_id, session) is False
+
+        await hub.record_answer(event_id, players[1], "B")
+        assert await _notify_all_answered(event_id, session) is True
+
+        presenter_messages = [call.args[0] for call in sockets[presenter].send_json.call_args_list]
+        assert presenter_messages == [
+            {"type": "all_answered", "answer_count": 2, "total_participants": 2}
+        ]
+        for player in players:
+            assert "all_answered" not in _received_types(sockets[player])
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_all_answered_is_broadcast_when_presenter_is_not_connected():
+    event_id = uuid4()
+    presenter, players = uuid4(), [uuid4()]
+    session = await _open_question(event_id, presenter, players)
+    player_socket = AsyncMock()
+    session.connections[players[0]] = player_socket
+
+    try:
+        await hub.record_answer(event_id, players[0], "A")
+        assert await _notify_all_answered(event_id, session) is True
+        assert "all_answered" in _received_types(player_socket)
+    finally:
+        hub.event_sessions.pop(event_id, None)
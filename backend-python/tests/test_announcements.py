@@ -0,0 +1,62 @@
+"""Tests for host and presenter announcements."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant
+from app.ws.game_handler import MAX_ANNOUNCEMENT_LENGTH, send_announcement
+from app.ws.hub import hub
+
+
+def _announcements(websocket: AsyncMock) -> list[dict]:
+    return [
+        call.args[0]
+        for call in websocket.send_json.call_args_list
+        if call.args[0]["type"] == "announcement"
+    ]
+
+
+@pytest.mark.anyio
+async def test_host_announcement_reaches_everyone_and_participant_is_rejected(
+    test_session, test_user, test_event
+):
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Audience Member",
+        session_token="announce_token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    sockets = [AsyncMock(), AsyncMock()]
+    session.connections[test_user.id] = sockets[0]
+    session.connections[participant.id] = sockets[1]
+
+    try:
+        error = await send_announcement(
+            test_session, test_event.id, test_user.id, "  5 minute\nbreak\x07  "
+        )
+        assert error is None
+        for websocket in sockets:
+            received = _announcements(websocket)
+            assert len(received) == 1
+            assert received[0]["text"] == "5 minute break"
+            assert received[0]["from_user_id"] == test_user.id
+
+        error = await send_announcement(test_session, test_event.id, participant.id, "Free pizza!")
+        assert error == "Only the host or presenter can make announcements"
+        assert all(len(_announcements(websocket)) == 1 for websocket in sockets)
+
+        too_long = await send_announcement(
+            test_session, test_event.id, test_user.id, "x" * (MAX_ANNOUNCEMENT_LENGTH + 1)
+        )
+        assert too_long is not None
+        empty = await send_announcement(test_session, test_event.id, test_user.id, " \t ")
+        assert empty == "Announcement cannot be empty"
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,90 @@
+"""Tests for changing an answer while the question is still open."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from sqlalchemy import select
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.config import get_settings
+from app.models import EventParticipant, ParticipantAnswer, Question, Segment, SegmentScore
+from app.ws import game_handler
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _submit(event_id, session, user_id, answer: str) -> tuple[bool, str | None]:
+    """Submit an answer the way the socket handler does."""
+    submitted_at = datetime.now(timezone.utc)
+    changed = user_id in session.game_state.answers_received
+    success, error = await hub.record_answer(event_id, user_id, answer, submitted_at=submitted_at)
+    if success:
+        await game_handler._score_recorded_answer(
+            event_id, session, user_id, answer, submitted_at, changed=changed
+        )
+    return success, error
+
+
+@pytest.mark.anyio
+async def test_changing_wrong_to_right_rescores_once(test_engine, test_session, test_event, monkeypatch):
+    monkeypatch.setattr(get_settings(), "allow_answer_changes", True)
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    question = Question(
+        id=uuid4(),
+        segment_id=segment.id,
+        question_text="2+2?",
+        correct_answer="4",
+        fake_answers=["3", "5"],
+        order_index=0,
+    )
+    participant = EventParticipant(
+        id=uuid4(), event_id=test_event.id, display_name="Player", device_id=uuid4()
+    )
+    test_session.add_all([segment, question, participant])
+    await test_session.commit()
+
+    viewer = AsyncMock()
+    await hub.connect(test_event.id, uuid4(), viewer)
+    session = hub.event_sessions[test_event.id]
+    state = session.game_state
+    state.questions = [{"id": question.id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3", "5"]}]
+    state.current_question_id = question.id
+    state.current_segment_id = segment.id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.time_limit_seconds = 30
+    state.question_started_at = datetime.now(timezone.utc)
+
+    try:
+        assert await _submit(test_event.id, session, participant.id, "3") == (True, None)
+        assert await _submit(test_event.id, session, participant.id, "4") == (True, None)
+
+        assert state.answers_received == {participant.id: "4"}
+        answer = (
+            await test_session.execute(
+                select(ParticipantAnswer).where(ParticipantAnswer.participant_id == participant.id)
+            )
+        ).scalar_one()
+        await test_session.refresh(answer)
+        score = (
+            await test_session.execute(
+                select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+            )
+        ).scalar_one()
+        await test_session.refresh(score)
+        await test_session.refresh(participant)
+        assert answer.is_correct and answer.points > 0
+        assert participant.total_score == score.score == answer.points
+        assert (score.questions_answered, score.questions_correct) == (1, 1)
+
+        # Once the answer is revealed it can no longer change
+        state.quiz_phase = QuizPhase.REVEALING_ANSWER
+        assert await _submit(test_event.id, session, participant.id, "5") == (False, "revealed")
+    finally:
+        await hub.close_session(test_event.id)
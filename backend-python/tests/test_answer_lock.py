@@ -0,0 +1,96 @@
+"""Tests for two-step answering where only locked answers count."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _auto_lock_selections, _score_answer_submission
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _open_question(event_id):
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    question_id = uuid4()
+    state.questions = [{"id": question_id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3", "5"]}]
+    state.current_question_id = question_id
+    state.current_segment_id = uuid4()
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc) - timedelta(seconds=3)
+    state.answer_lock = True
+    return session
+
+
+@pytest.mark.anyio
+async def test_only_the_locked_selection_scores():
+    event_id = uuid4()
+    session = await _open_question(event_id)
+    participant = uuid4()
+
+    try:
+        assert await hub.record_selection(event_id, participant, "3") == (True, None)
+        assert await hub.record_selection(event_id, participant, "5") == (True, None)
+        assert await hub.record_selection(event_id, participant, "4") == (True, None)
+        # Selecting alone records nothing
+        assert session.game_state.answers_received == {}
+
+        submitted_at = datetime.now(timezone.utc)
+        locked, error = await hub.lock_answer(event_id, participant, submitted_at=submitted_at)
+        assert (locked, error) == ("4", None)
+        assert session.game_state.answers_received == {participant: "4"}
+
+        # Once locked the selection can no longer change
+        assert await hub.record_selection(event_id, participant, "3") == (False, "duplicate")
+        assert await hub.lock_answer(event_id, participant) == (None, "no_selection")
+
+        with patch(
+            "app.ws.game_handler.record_answer", new=AsyncMock(side_effect=lambda db, answer: answer)
+        ) as record_answer, patch(
+            "app.ws.game_handler.current_streak", new=AsyncMock(return_value=0)
+        ):
+            answer = await _score_answer_submission(
+                MagicMock(),
+                session=session,
+                participant_id=participant,
+                selected_answer=locked,
+                submitted_at=submitted_at,
+            )
+        assert answer.selected_answer == "4"
+        assert answer.is_correct is True
+        assert answer.points > 0
+        assert record_answer.await_count == 1
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_unlocked_selection_is_locked_when_time_runs_out():
+    event_id = uuid4()
+    session = await _open_question(event_id)
+    hesitant, idle = uuid4(), uuid4()
+
+    try:
+        await hub.record_selection(event_id, hesitant, "5")
+        await hub.record_selection(event_id, hesitant, "4")
+        # The question closes before the participant locks in
+        db = MagicMock()
+        db.commit = AsyncMock()
+        with patch(
+            "app.ws.game_handler.record_answer", new=AsyncMock(side_effect=lambda db, answer: answer)
+        ), patch(
+            "app.ws.game_handler.current_streak", new=AsyncMock(return_value=0)
+        ):
+            scored = await _auto_lock_selections(db, event_id, session, "tolerance")
+
+        assert [(a.participant_id, a.selected_answer, a.is_correct) for a in scored] == [
+            (hesitant, "4", True)
+        ]
+        assert session.game_state.answers_received == {hesitant: "4"}
+        assert idle not in session.game_state.answers_received
+        assert session.game_state.provisional_answers == {}
+        db.commit.assert_awaited_once()
+    finally:
+        hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,116 @@
+"""Tests that participants can't learn the correct answer before the reveal."""
+
+import uuid
+from datetime import datetime, timezone
+from uuid import UUID, uuid4
+
+import pytest
+from sqlalchemy import select
+
+from app.models import EventParticipant, ParticipantAnswer
+from app.ws.game_handler import _build_question_payload
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _event_with_question(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Secrets"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    question = await client.post(
+        f"/api/segments/{segment.json()['id']}/questions",
+        json={"question_text": "Capital of France?", "correct_answer": "Paris", "fake_answers": ["Lyon", "Nice"]},
+        headers=headers,
+    )
+    joined = await client.post(
+        "/api/events/join",
+        json={
+            "code": event.json()["join_code"],
+            "device_fingerprint": str(uuid.uuid4()),
+            "display_name": "Curious",
+        },
+    )
+    return headers, event.json(), segment.json(), question.json(), joined.json()["sessionToken"]
+
+
+@pytest.mark.anyio
+async def test_participant_question_list_hides_correct_answer(client):
+    headers, _, segment, _, session_token = await _event_with_question(client)
+    url = f"/api/segments/{segment['id']}/questions"
+
+    assert (await client.get(url)).status_code == 403
+
+    participant_view = await client.get(url, headers={"X-Session-Token": session_token})
+    assert participant_view.status_code == 200
+    item = participant_view.json()["items"][0]
+    assert item["question_text"] == "Capital of France?"
+    for secret in ("correct_answer", "fake_answers", "explanation", "source_transcript"):
+        assert secret not in item
+
+    host_view = await client.get(url, headers=headers)
+    assert host_view.json()["items"][0]["correct_answer"] == "Paris"
+
+
+def test_question_broadcast_does_not_mark_the_correct_option():
+    message = _build_question_payload(
+        question_id=uuid4(),
+        question_text="Capital of France?",
+        correct_answer="Paris",
+        fake_answers=["Lyon", "Nice"],
+        total_questions=1,
+        time_limit=30,
+        index=0,
+    ).model_dump()
+
+    assert sorted(message["answers"]) == ["Lyon", "Nice", "Paris"]
+    assert all(isinstance(answer, str) for answer in message["answers"])
+    for secret in ("correct_answer", "is_correct", "correct_index", "explanation"):
+        assert secret not in message
+
+
+@pytest.mark.anyio
+async def test_open_question_is_not_listed_in_mistakes_until_revealed(client, test_session):
+    _, event, segment, question, session_token = await _event_with_question(client)
+    event_id = UUID(event["id"])
+    participant = (
+        await test_session.execute(
+            select(EventParticipant).where(EventParticipant.session_token == session_token)
+        )
+    ).scalar_one()
+    test_session.add(
+        ParticipantAnswer(
+            id=uuid4(),
+            event_id=event_id,
+            segment_id=UUID(segment["id"]),
+            question_id=UUID(question["id"]),
+            participant_id=participant.id,
+            selected_answer="Lyon",
+            is_correct=False,
+            points=0,
+        )
+    )
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(event_id)
+    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    session.game_state.current_question_id = UUID(question["id"])
+    session.game_state.question_started_at = datetime.now(timezone.utc)
+    url = f"/api/events/{event_id}/my-mistakes"
+
+    try:
+        while_open = await client.get(url, headers={"X-Session-Token": session_token})
+        assert while_open.json() == []
+
+        session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+        after_reveal = await client.get(url, headers={"X-Session-Token": session_token})
+        assert [m["correct_answer"] for m in after_reveal.json()] == ["Paris"]
+    finally:
+        hub.event_sessions.pop(event_id, None)
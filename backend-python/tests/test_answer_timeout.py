@@ -210,3 +210,18 @@ async def test_answer_timing_with_timezone_aware_datetime(hub, event_id, user_id
 
     assert success is True
     assert error is None
+
+
+@pytest.mark.asyncio
+async def test_answer_rejected_after_reveal(hub, event_id, user_id):
+    """Answers sent once the answer is revealed should not be recorded or scored."""
+    session = await hub.get_or_create_session(event_id)
+    session.game_state.question_started_at = datetime.now(timezone.utc)
+    session.game_state.time_limit_seconds = 30
+    session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+
+    success, error = await hub.record_answer(event_id, user_id, "A")
+
+    assert success is False
+    assert error == 'revealed'
+    assert user_id not in session.game_state.answers_received
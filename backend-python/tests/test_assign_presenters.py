@@ -0,0 +1,84 @@
+"""Tests for bulk-assigning presenters to segments."""
+
+import uuid
+from uuid import UUID, uuid4
+
+import pytest
+from sqlalchemy import select
+
+from app.models import EventParticipant, Segment
+
+
+async def _register(client, prefix: str) -> tuple[dict, UUID]:
+    response = await client.post(
+        "/api/auth/register",
+        json={"username": f"{prefix}_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    body = response.json()
+    return {"Authorization": f"Bearer {body['token']}"}, UUID(body["user"]["id"])
+
+
+async def _presenter_ids(test_session, segment_ids: list[str]) -> list[UUID | None]:
+    test_session.expire_all()
+    result = await test_session.execute(
+        select(Segment).where(Segment.id.in_([UUID(s) for s in segment_ids]))
+    )
+    by_id = {str(segment.id): segment.presenter_user_id for segment in result.scalars().all()}
+    return [by_id[segment_id] for segment_id in segment_ids]
+
+
+@pytest.mark.anyio
+async def test_bulk_assign_presenters(client, test_session):
+    headers, _ = await _register(client, "host")
+    event = await client.post("/api/quizzes", json={"title": "Panel"}, headers=headers)
+    event_id = event.json()["id"]
+    segment_ids = []
+    for name in ("First", "Second"):
+        segment = await client.post(
+            f"/api/quizzes/{event_id}/questions",
+            json={"presenter_name": name},
+            headers=headers,
+        )
+        segment_ids.append(segment.json()["id"])
+
+    speaker_headers, speaker_a = await _register(client, "speaker")
+    _, speaker_b = await _register(client, "speaker")
+    _, outsider = await _register(client, "outsider")
+    test_session.add_all(
+        EventParticipant(
+            id=uuid4(),
+            event_id=UUID(event_id),
+            user_id=user_id,
+            device_id=uuid4(),
+            display_name="Speaker",
+        )
+        for user_id in (speaker_a, speaker_b)
+    )
+    await test_session.commit()
+
+    response = await client.put(
+        f"/api/events/{event_id}/presenters",
+        json={"assignments": {segment_ids[0]: str(speaker_a), segment_ids[1]: str(speaker_b)}},
+        headers=headers,
+    )
+
+    assert response.status_code == 200
+    assert [s["presenter_user_id"] for s in response.json()] == [str(speaker_a), str(speaker_b)]
+    assert await _presenter_ids(test_session, segment_ids) == [speaker_a, speaker_b]
+
+    # One invalid target rejects the whole batch
+    response = await client.put(
+        f"/api/events/{event_id}/presenters",
+        json={"assignments": {segment_ids[0]: str(speaker_b), segment_ids[1]: str(outsider)}},
+        headers=headers,
+    )
+
+    assert response.status_code == 400
+    assert await _presenter_ids(test_session, segment_ids) == [speaker_a, speaker_b]
+
+    response = await client.put(
+        f"/api/events/{event_id}/presenters",
+        json={"assignments": {segment_ids[0]: str(speaker_b)}},
+        headers=speaker_headers,
+    )
+    assert response.status_code == 403
@@ -0,0 +1,69 @@
+"""Tests for server-timed audio cue broadcasts."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.config import get_settings
+from app.ws.game_handler import _schedule_question_cues
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _open_question(event_id, audio_cues=True):
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    state.audio_cues = audio_cues
+    state.current_question_id = uuid4()
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.time_limit_seconds = 1
+    state.question_started_at = datetime.now(timezone.utc)
+
+    received = []
+    viewer = AsyncMock()
+    viewer.send_json.side_effect = lambda message: received.append(
+        (message["type"], datetime.now(timezone.utc), message)
+    )
+    session.connections[uuid4()] = viewer
+    return session, received
+
+
+@pytest.mark.anyio
+async def test_time_up_is_broadcast_when_server_timer_expires(monkeypatch):
+    monkeypatch.setattr(get_settings(), "countdown_tick_seconds", 0)
+    event_id = uuid4()
+    session, received = await _open_question(event_id)
+    deadline = session.game_state.question_started_at + timedelta(seconds=1)
+
+    try:
+        _schedule_question_cues(event_id, session)
+        await session.cue_task
+
+        assert [kind for kind, _, _ in received] == ["time_up"]
+        _, sent_at, message = received[0]
+        assert message["question_id"] == str(session.game_state.current_question_id)
+        assert deadline <= sent_at < deadline + timedelta(milliseconds=250)
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_no_cues_after_question_closes_or_when_disabled(monkeypatch):
+    monkeypatch.setattr(get_settings(), "countdown_tick_seconds", 0)
+    event_id = uuid4()
+    session, received = await _open_question(event_id)
+
+    try:
+        _schedule_question_cues(event_id, session)
+        session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+        await session.cue_task
+        assert received == []
+
+        session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+        session.game_state.audio_cues = False
+        _schedule_question_cues(event_id, session)
+        assert session.cue_task is None
+    finally:
+        hub.event_sessions.pop(event_id, None)
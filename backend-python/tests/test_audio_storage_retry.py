@@ -0,0 +1,67 @@
+"""Tests for retrying transient S3 failures in audio storage."""
+
+from unittest.mock import AsyncMock, MagicMock
+from uuid import uuid4
+
+import pytest
+from botocore.exceptions import ClientError
+
+from app.config import get_settings
+from app.services.audio_storage import AudioStorageService, StorageUnavailableError
+
+
+def _client_error(status: int, code: str) -> ClientError:
+    return ClientError(
+        {"Error": {"Code": code}, "ResponseMetadata": {"HTTPStatusCode": status}},
+        "PutObject",
+    )
+
+
+def _storage_with(put_object: AsyncMock) -> AudioStorageService:
+    s3 = MagicMock()
+    s3.put_object = put_object
+    client = MagicMock()
+    client.__aenter__ = AsyncMock(return_value=s3)
+    client.__aexit__ = AsyncMock(return_value=False)
+    storage = AudioStorageService()
+    storage.session = MagicMock()
+    storage.session.client.return_value = client
+    return storage
+
+
+@pytest.fixture(autouse=True)
+def no_backoff(monkeypatch):
+    monkeypatch.setattr(get_settings(), "s3_retry_backoff_seconds", 0)
+    monkeypatch.setattr(get_settings(), "s3_max_retries", 2)
+
+
+@pytest.mark.anyio
+async def test_upload_succeeds_after_one_transient_failure():
+    put_object = AsyncMock(side_effect=[_client_error(503, "ServiceUnavailable"), {}])
+    storage = _storage_with(put_object)
+    segment_id = uuid4()
+
+    path = await storage.store_chunk(segment_id, 0, b"audio")
+
+    assert path == f"{segment_id}/chunk_0000.webm"
+    assert put_object.await_count == 2
+
+
+@pytest.mark.anyio
+async def test_upload_reports_unavailable_after_retries_exhausted():
+    put_object = AsyncMock(side_effect=_client_error(500, "InternalError"))
+    storage = _storage_with(put_object)
+
+    with pytest.raises(StorageUnavailableError, match="after 3 attempts"):
+        await storage.store_chunk(uuid4(), 0, b"audio")
+    assert put_object.await_count == 3
+
+
+@pytest.mark.anyio
+async def test_client_errors_are_not_retried():
+    put_object = AsyncMock(side_effect=_client_error(403, "AccessDenied"))
+    storage = _storage_with(put_object)
+
+    with pytest.raises(ClientError):
+        await storage.store_chunk(uuid4(), 0, b"audio")
+    assert put_object.await_count == 1
@@ -0,0 +1,41 @@
+"""Tests that audio ingestion only accepts the segment's host."""
+
+import uuid
+
+import pytest
+
+
+async def _register(client) -> dict[str, str]:
+    username = f"user_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    return {"Authorization": f"Bearer {register.json()['token']}"}
+
+
+@pytest.mark.anyio
+async def test_audio_chunk_from_non_host_is_refused(client):
+    host_headers = await _register(client)
+    event = await client.post("/api/quizzes", json={"title": "Audio Auth"}, headers=host_headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=host_headers,
+    )
+    segment_id = segment.json()["id"]
+    files = {"audio_chunk": ("chunk.webm", b"fake audio", "audio/webm")}
+
+    intruder_headers = await _register(client)
+    response = await client.post(
+        f"/api/segments/{segment_id}/audio-chunk?chunk_index=0",
+        files=files,
+        headers=intruder_headers,
+    )
+    assert response.status_code == 404
+
+    anonymous = await client.post(
+        f"/api/segments/{segment_id}/audio-chunk?chunk_index=0",
+        files=files,
+    )
+    assert anonymous.status_code in (401, 403)
@@ -0,0 +1,65 @@
+"""Tests for keeping slow clients connected when they fall behind on broadcasts."""
+
+import asyncio
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.config import get_settings
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_lagging_client_stays_connected_and_is_resynced(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_send_timeout_seconds", 0.05)
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    session.game_state.quiz_phase = QuizPhase.SHOWING_LEADERBOARD
+    session.game_state.current_question_index = 3
+
+    received: list[dict] = []
+
+    async def slow_send(message):
+        if message["type"] == "question":
+            # Stalls long enough to overflow the send timeout
+            await asyncio.sleep(1)
+        received.append(message)
+
+    slow_user, fast_user = uuid4(), uuid4()
+    slow_socket = AsyncMock()
+    slow_socket.send_json.side_effect = slow_send
+    fast_socket = AsyncMock()
+    session.connections[slow_user] = slow_socket
+    session.connections[fast_user] = fast_socket
+
+    try:
+        await hub.broadcast(event_id, {"type": "question"})
+
+        assert slow_user in session.connections
+        assert [message["type"] for message in received] == ["state_resync"]
+        assert received[0]["current_phase"] == QuizPhase.SHOWING_LEADERBOARD.value
+        assert received[0]["question_index"] == 3
+        fast_socket.send_json.assert_awaited_once_with({"type": "question", "seq": 1})
+
+        await hub.broadcast(event_id, {"type": "leaderboard"})
+        assert received[-1] == {"type": "leaderboard", "seq": 2}
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_closed_client_is_still_dropped():
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    closed_user = uuid4()
+    closed_socket = AsyncMock()
+    closed_socket.send_json.side_effect = RuntimeError("socket closed")
+    session.connections[closed_user] = closed_socket
+
+    try:
+        await hub.broadcast(event_id, {"type": "question"})
+        assert closed_user not in session.connections
+    finally:
+        hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,60 @@
+"""Tests for replaying broadcasts a client missed during a brief disconnect."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.config import get_settings
+from app.ws.hub import hub
+from app.ws.messages import ReplayMessage, parse_client_message
+
+
+def test_parse_replay_message():
+    message = parse_client_message({"type": "replay", "since_seq": 4})
+    assert isinstance(message, ReplayMessage)
+    assert message.since_seq == 4
+
+
+@pytest.mark.anyio
+async def test_client_fetches_exactly_the_missed_broadcasts():
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    user_id = uuid4()
+    websocket = AsyncMock()
+    session.connections[user_id] = websocket
+
+    try:
+        await hub.broadcast(event_id, {"type": "question", "n": 1})
+        last_seen = websocket.send_json.call_args.args[0]["seq"]
+
+        # Drop off while two messages go out, then come back
+        session.connections.pop(user_id)
+        await hub.broadcast(event_id, {"type": "reveal", "n": 2})
+        await hub.broadcast(event_id, {"type": "leaderboard", "n": 3})
+        session.connections[user_id] = websocket
+
+        missed = hub.messages_since(event_id, last_seen)
+        assert [message["n"] for message in missed] == [2, 3]
+        assert [message["seq"] for message in missed] == [last_seen + 1, last_seen + 2]
+        assert hub.messages_since(event_id, last_seen + 2) == []
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_gap_older_than_buffer_needs_resync(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_replay_buffer_size", 2)
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+
+    try:
+        for n in range(4):
+            await hub.broadcast(event_id, {"type": "tick", "n": n})
+        await hub.broadcast(event_id, {"type": "participant_selecting"}, replayable=False)
+
+        assert session.broadcast_seq == 4
+        assert hub.messages_since(event_id, 0) is None
+        assert [message["n"] for message in hub.messages_since(event_id, 2)] == [2, 3]
+    finally:
+        hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,76 @@
+"""Tests for bulk question deletion."""
+
+import uuid
+
+import pytest
+
+
+async def _setup_segment(client) -> tuple[dict[str, str], str]:
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Bulk"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    return headers, segment.json()["id"]
+
+
+@pytest.mark.anyio
+async def test_bulk_delete_removes_only_selected_questions(client):
+    headers, segment_id = await _setup_segment(client)
+
+    question_ids = []
+    for i in range(3):
+        response = await client.post(
+            f"/api/segments/{segment_id}/questions",
+            json={
+                "question_text": f"Question {i}?",
+                "correct_answer": f"Answer {i}",
+                "fake_answers": [f"Wrong {i}a", f"Wrong {i}b"],
+            },
+            headers=headers,
+        )
+        question_ids.append(response.json()["id"])
+
+    response = await client.request(
+        "DELETE",
+        f"/api/segments/{segment_id}/questions",
+        json={"question_ids": question_ids[:2]},
+        headers=headers,
+    )
+    assert response.status_code == 200
+    assert response.json()["deleted"] == 2
+
+    remaining = await client.get(f"/api/segments/{segment_id}/questions", headers=headers)
+    data = remaining.json()["items"]
+    assert [q["id"] for q in data] == [question_ids[2]]
+    # Generated answers live on the question row, so only the survivor's remain
+    assert data[0]["fake_answers"] == ["Wrong 2a", "Wrong 2b"]
+
+
+@pytest.mark.anyio
+async def test_bulk_delete_rejects_questions_from_other_segment(client):
+    headers, segment_id = await _setup_segment(client)
+    _, other_segment_id = await _setup_segment(client)
+
+    response = await client.request(
+        "DELETE",
+        f"/api/segments/{segment_id}/questions",
+        json={"question_ids": [str(uuid.uuid4())]},
+        headers=headers,
+    )
+    assert response.status_code == 400
+
+    response = await client.request(
+        "DELETE",
+        f"/api/segments/{other_segment_id}/questions",
+        json={"all": True},
+        headers=headers,
+    )
+    assert response.status_code == 404
@@ -0,0 +1,64 @@
+"""Tests for buzzer rounds where only the first participant to buzz may answer."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import buzz_in, reset_buzzer
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_only_first_buzzer_gets_answer_rights(test_session, test_user, test_event):
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.buzzer_mode = True
+    state.quiz_phase = state.question_phase
+    state.question_started_at = datetime.now(timezone.utc)
+    state.current_segment_id = uuid4()
+    first, second = uuid4(), uuid4()
+    sockets = {first: AsyncMock(), second: AsyncMock()}
+    session.connections.update(sockets)
+
+    try:
+        assert state.quiz_phase == QuizPhase.BUZZING
+        assert await buzz_in(test_event.id, first) is None
+        assert await buzz_in(test_event.id, second) == "Someone else buzzed in first"
+        assert state.buzz_winner_id == first
+
+        winners = [
+            call.args[0]["user_id"]
+            for call in sockets[second].send_json.call_args_list
+            if call.args[0]["type"] == "buzz_winner"
+        ]
+        assert winners == [first]
+
+        assert await hub.record_answer(test_event.id, second, "B") == (False, "not_buzz_winner")
+        assert await hub.record_answer(test_event.id, first, "A") == (True, None)
+
+        # Reset reopens the buzzer to the others; the first buzzer already answered
+        await hub.reset_buzz(test_event.id)
+        assert await buzz_in(test_event.id, first) == "You have already answered this question"
+        assert await buzz_in(test_event.id, second) is None
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_only_controllers_can_reset_the_buzzer(test_session, test_user, test_event):
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.buzzer_mode = True
+    state.quiz_phase = QuizPhase.BUZZING
+    state.current_segment_id = uuid4()
+    state.buzz_winner_id = uuid4()
+
+    try:
+        error = await reset_buzzer(test_session, test_event.id, uuid4())
+        assert error == "Only the host or presenter can reset the buzzer"
+        assert state.buzz_winner_id is not None
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,50 @@
+"""Tests for paging through an event's canvas strokes."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.models import CanvasStroke
+
+
+@pytest.mark.anyio
+async def test_pages_cover_every_stroke_once(client, test_session, test_event, test_user):
+    # Strokes drawn in the same instant are still ordered, by id
+    drawn_at = datetime(2025, 1, 1, tzinfo=timezone.utc)
+    strokes = [
+        CanvasStroke(
+            id=uuid4(),
+            event_id=test_event.id,
+            user_id=test_user.id,
+            stroke_data={"points": [{"x": i, "y": i}], "color": "#000", "width": 2},
+            stroke_order=i,
+            created_at=drawn_at,
+        )
+        for i in range(5)
+    ]
+    test_session.add_all(strokes)
+    await test_session.commit()
+
+    seen: list[str] = []
+    params: dict = {"limit": 2}
+    pages = 0
+    while True:
+        response = await client.get(f"/api/events/{test_event.id}/canvas", params=params)
+        assert response.status_code == 200
+        page = response.json()
+        seen.extend(stroke["id"] for stroke in page["strokes"])
+        pages += 1
+        if not page["has_more"]:
+            break
+        params = {"limit": 2, "after": page["next_cursor"]}
+
+    assert pages == 3
+    assert seen == sorted(str(stroke.id) for stroke in strokes)
+
+
+@pytest.mark.anyio
+async def test_unknown_cursor_is_rejected(client, test_event):
+    response = await client.get(f"/api/events/{test_event.id}/canvas", params={"after": str(uuid4())})
+
+    assert response.status_code == 400
@@ -0,0 +1,79 @@
+"""Tests for undoing and deleting shared canvas strokes."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import CanvasStroke, User
+from app.ws.game_handler import remove_canvas_stroke
+from app.ws.hub import hub
+
+
+def _stroke(event_id, user_id, seconds: int) -> CanvasStroke:
+    return CanvasStroke(
+        id=uuid4(),
+        event_id=event_id,
+        user_id=user_id,
+        stroke_data={"points": [{"x": 0, "y": 0}], "color": "#000", "width": 2},
+        created_at=datetime(2025, 1, 1, tzinfo=timezone.utc) + timedelta(seconds=seconds),
+    )
+
+
+async def _artist(test_session) -> User:
+    name = f"artist_{uuid4().hex[:8]}"
+    user = User(
+        id=uuid4(),
+        username=name,
+        display_name=name,
+        email=f"{name}@example.com",
+        password_hash="dummy_hash",
+    )
+    test_session.add(user)
+    await test_session.commit()
+    return user
+
+
+@pytest.mark.anyio
+async def test_undo_removes_only_the_senders_latest_stroke(test_session, test_event, test_user):
+    artist = await _artist(test_session)
+    first, latest = _stroke(test_event.id, artist.id, 0), _stroke(test_event.id, artist.id, 1)
+    hosts = _stroke(test_event.id, test_user.id, 2)
+    test_session.add_all([first, latest, hosts])
+    await test_session.commit()
+    viewer = AsyncMock()
+    await hub.connect(test_event.id, uuid4(), viewer)
+
+    try:
+        assert await remove_canvas_stroke(test_session, test_event.id, artist.id) is None
+
+        assert await test_session.get(CanvasStroke, latest.id) is None
+        assert await test_session.get(CanvasStroke, first.id) is not None
+        assert await test_session.get(CanvasStroke, hosts.id) is not None
+        removed = [
+            call.args[0]
+            for call in viewer.send_json.call_args_list
+            if call.args[0]["type"] == "stroke_removed"
+        ]
+        assert [message["stroke_id"] for message in removed] == [latest.id]
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_only_author_or_host_can_delete_a_stroke(test_session, test_event, test_user):
+    artist, bystander = await _artist(test_session), await _artist(test_session)
+    stroke = _stroke(test_event.id, artist.id, 0)
+    test_session.add(stroke)
+    await test_session.commit()
+
+    try:
+        error = await remove_canvas_stroke(test_session, test_event.id, bystander.id, stroke.id)
+        assert error == "Only the host or the stroke's author can delete it"
+        assert await test_session.get(CanvasStroke, stroke.id) is not None
+
+        assert await remove_canvas_stroke(test_session, test_event.id, test_user.id, stroke.id) is None
+        assert await test_session.get(CanvasStroke, stroke.id) is None
+    finally:
+        await hub.close_session(test_event.id)
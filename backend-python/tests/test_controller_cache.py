@@ -0,0 +1,70 @@
+"""Tests for caching host/presenter checks on WebSocket control messages."""
+
+from datetime import datetime, timezone
+from unittest.mock import patch
+from uuid import uuid4
+
+import pytest
+
+from app.models import Event, Question, Segment, SegmentStatus, User
+from app.ws.game_handler import advance_question
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_repeated_next_question_checks_presenter_once(test_session, test_event):
+    presenter = User(
+        id=uuid4(),
+        username="fast_presenter",
+        display_name="Fast Presenter",
+        email="fast_presenter@example.com",
+        password_hash="dummy_hash",
+    )
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Fast Presenter",
+        presenter_user_id=presenter.id,
+        status=SegmentStatus.QUIZZING.value,
+    )
+    questions = [
+        Question(id=uuid4(), segment_id=segment.id, question_text=f"Q{i}?", correct_answer="A", order_index=i)
+        for i in range(4)
+    ]
+    test_session.add(presenter)
+    await test_session.flush()
+    test_session.add(segment)
+    await test_session.flush()
+    test_session.add_all(questions)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.current_segment_id = segment.id
+    state.current_presenter_id = presenter.id
+    state.questions = [
+        {"id": q.id, "text": q.question_text, "correct_answer": "A", "fake_answers": ["B"]}
+        for q in questions
+    ]
+    state.total_questions = len(questions)
+    state.current_question_id = questions[0].id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+
+    try:
+        with patch.object(test_session, "get", wraps=test_session.get) as db_get:
+            assert await advance_question(test_session, test_event.id, presenter.id) is None
+            assert await advance_question(test_session, test_event.id, presenter.id) is None
+            event_lookups = [call for call in db_get.call_args_list if call.args[0] is Event]
+            assert len(event_lookups) == 1
+            assert state.current_question_index == 2
+
+            # Handing the segment to someone else drops the cached grant
+            hub.invalidate_controller_cache(test_event.id)
+            segment.presenter_user_id = None
+            await test_session.commit()
+            error = await advance_question(test_session, test_event.id, presenter.id)
+            assert error == "Only the host or presenter can change questions"
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
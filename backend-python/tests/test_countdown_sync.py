@@ -0,0 +1,70 @@
+"""Tests for server-timed time_remaining broadcasts."""
+
+import asyncio
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.config import get_settings
+from app.ws.game_handler import _cancel_question_cues, _schedule_question_cues
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _open_question(event_id):
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    state.current_question_id = uuid4()
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.time_limit_seconds = 2
+    state.question_started_at = datetime.now(timezone.utc)
+
+    ticks = []
+    viewer = AsyncMock()
+    viewer.send_json.side_effect = lambda message: ticks.append(message)
+    session.connections[uuid4()] = viewer
+    return session, ticks
+
+
+@pytest.mark.anyio
+async def test_ticks_follow_interval_and_stop_at_reveal(monkeypatch):
+    monkeypatch.setattr(get_settings(), "countdown_sync_interval_seconds", 0.2)
+    event_id = uuid4()
+    session, ticks = await _open_question(event_id)
+
+    try:
+        _schedule_question_cues(event_id, session)
+        await asyncio.sleep(0.5)
+
+        assert [tick["type"] for tick in ticks] == ["time_remaining"] * 3
+        remaining = [tick["remaining_seconds"] for tick in ticks]
+        assert remaining[0] == pytest.approx(2.0, abs=0.1)
+        assert [a - b for a, b in zip(remaining, remaining[1:])] == [
+            pytest.approx(0.2, abs=0.1)
+        ] * 2
+
+        session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+        _cancel_question_cues(session)
+        await asyncio.sleep(0.3)
+
+        assert len(ticks) == 3
+        assert session.countdown_task is None
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_no_ticks_when_disabled(monkeypatch):
+    monkeypatch.setattr(get_settings(), "countdown_sync_interval_seconds", 0)
+    event_id = uuid4()
+    session, ticks = await _open_question(event_id)
+
+    try:
+        _schedule_question_cues(event_id, session)
+
+        assert session.countdown_task is None
+        assert ticks == []
+    finally:
+        hub.event_sessions.pop(event_id, None)
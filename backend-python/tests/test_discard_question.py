@@ -0,0 +1,115 @@
+"""Tests for discarding the open question without it counting."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from sqlalchemy import func, select
+
+from app.models import (
+    EventParticipant,
+    ParticipantAnswer,
+    Question,
+    Segment,
+    SegmentScore,
+    SegmentStatus,
+)
+from app.ws.game_handler import _score_answer_submission, discard_question
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo, QuizPhase
+
+
+async def _open_quiz(test_session, test_event):
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Presenter",
+        status=SegmentStatus.QUIZZING.value,
+    )
+    questions = [
+        Question(id=uuid4(), segment_id=segment.id, question_text=f"Q{i}?", correct_answer="A", order_index=i)
+        for i in range(2)
+    ]
+    player = EventParticipant(
+        id=uuid4(), event_id=test_event.id, device_id=uuid4(), display_name="Player"
+    )
+    test_session.add_all([segment, player])
+    await test_session.flush()
+    test_session.add_all(questions)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.current_segment_id = segment.id
+    state.questions = [
+        {"id": q.id, "text": q.question_text, "correct_answer": "A", "fake_answers": ["B"]}
+        for q in questions
+    ]
+    state.total_questions = 2
+    state.current_question_index = 0
+    state.current_question_id = questions[0].id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+    state.participants[player.id] = ParticipantInfo(user_id=player.id, username="Player")
+    return session, segment, questions, player
+
+
+@pytest.mark.anyio
+async def test_discarded_question_does_not_count_and_game_advances(test_session, test_user, test_event):
+    session, segment, questions, player = await _open_quiz(test_session, test_event)
+    viewer = AsyncMock()
+    session.connections[uuid4()] = viewer
+
+    try:
+        assert (await hub.record_answer(test_event.id, player.id, "A"))[0]
+        answer = await _score_answer_submission(
+            test_session, session, player.id, "A", datetime.now(timezone.utc)
+        )
+        assert answer.points > 0
+
+        error = await discard_question(test_session, test_event.id, test_user.id)
+
+        assert error is None
+        answer_count = await test_session.scalar(
+            select(func.count()).select_from(ParticipantAnswer).where(
+                ParticipantAnswer.question_id == questions[0].id
+            )
+        )
+        assert answer_count == 0
+        score = await test_session.scalar(
+            select(SegmentScore).where(
+                SegmentScore.segment_id == segment.id, SegmentScore.participant_id == player.id
+            )
+        )
+        assert (score.score, score.questions_answered, score.questions_correct) == (0, 0, 0)
+        await test_session.refresh(player)
+        await test_session.refresh(questions[0])
+        assert player.total_score == 0
+        assert questions[0].is_discarded is True
+
+        state = session.game_state
+        assert state.current_question_index == 1
+        assert state.current_question_id == questions[1].id
+        assert state.quiz_phase == QuizPhase.SHOWING_QUESTION
+        assert state.answers_received == {}
+        sent = [call.args[0]["type"] for call in viewer.send_json.call_args_list]
+        assert sent.index("question_discarded") < sent.index("question")
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_only_controllers_can_discard_open_questions(test_session, test_user, test_event):
+    session, _, questions, player = await _open_quiz(test_session, test_event)
+
+    try:
+        error = await discard_question(test_session, test_event.id, player.id)
+        assert error == "Only the host or presenter can discard questions"
+
+        session.game_state.quiz_phase = QuizPhase.REVEALING_ANSWER
+        error = await discard_question(test_session, test_event.id, test_user.id)
+        assert error == "Only an open question can be discarded"
+        assert session.game_state.current_question_id == questions[0].id
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
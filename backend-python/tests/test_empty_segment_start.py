@@ -0,0 +1,157 @@
+"""Tests for starting a segment that has no questions."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.auth import create_access_token
+from app.config import get_settings
+from app.models import Question, Segment, SegmentStatus
+from app.ws.game_handler import end_event_game, start_event_game, start_waiting_segment
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo, QuizPhase
+
+
+async def _segments(test_session, test_event, test_user) -> tuple[Segment, Segment]:
+    """An empty first segment followed by one with a question."""
+    empty, ready = (
+        Segment(
+            id=uuid4(),
+            event_id=test_event.id,
+            presenter_name="Host",
+            presenter_user_id=test_user.id,
+            order_index=i,
+            status=status,
+        )
+        for i, status in enumerate((SegmentStatus.RECORDING.value, SegmentStatus.QUIZ_READY.value))
+    )
+    test_session.add_all([empty, ready])
+    await test_session.flush()
+    test_session.add(
+        Question(id=uuid4(), segment_id=ready.id, question_text="2+2?", correct_answer="4", order_index=0)
+    )
+    await test_session.commit()
+    return empty, ready
+
+
+async def _room(test_event, current_segment_id):
+    player_id = uuid4()
+    session = await hub.connect(test_event.id, player_id, AsyncMock())
+    session.game_state.participants[player_id] = ParticipantInfo(user_id=player_id, username="Player")
+    session.game_state.current_segment_id = current_segment_id
+    return session
+
+
+@pytest.mark.anyio
+async def test_empty_segment_skips_to_next_segment_with_questions(
+    test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "empty_segment_start", "skip")
+    empty, ready = await _segments(test_session, test_event, test_user)
+    session = await _room(test_event, empty.id)
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+
+        assert session.game_state.current_segment_id == ready.id
+        assert session.game_state.quiz_phase == QuizPhase.SHOWING_QUESTION
+        await test_session.refresh(empty)
+        await test_session.refresh(ready)
+        assert (empty.status, ready.status) == (
+            SegmentStatus.SKIPPED.value,
+            SegmentStatus.QUIZZING.value,
+        )
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_empty_segment_waits_then_starts_once_questions_arrive(
+    test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "empty_segment_start", "wait")
+    empty, _ = await _segments(test_session, test_event, test_user)
+    session = await _room(test_event, empty.id)
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        assert session.game_state.quiz_phase == QuizPhase.WAITING_FOR_QUESTIONS
+
+        test_session.add(
+            Question(id=uuid4(), segment_id=empty.id, question_text="3+3?", correct_answer="6", order_index=0)
+        )
+        empty.status = SegmentStatus.QUIZ_READY.value
+        await test_session.commit()
+
+        assert await start_waiting_segment(test_session, test_event.id, empty.id) is True
+        assert session.game_state.current_segment_id == empty.id
+        assert session.game_state.quiz_phase == QuizPhase.SHOWING_QUESTION
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_empty_segment_does_not_wait_when_nothing_is_generating(
+    test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "empty_segment_start", "wait")
+    empty, _ = await _segments(test_session, test_event, test_user)
+    empty.status = SegmentStatus.QUIZ_READY.value
+    await test_session.commit()
+    session = await _room(test_event, empty.id)
+
+    try:
+        error = await start_event_game(test_session, test_event.id, test_user.id)
+
+        assert error == "This segment has no questions and none are being generated"
+        assert session.game_state.quiz_phase == QuizPhase.NOT_STARTED
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_end_game_calls_off_the_wait_without_completing_the_segment(
+    test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "empty_segment_start", "wait")
+    empty, _ = await _segments(test_session, test_event, test_user)
+    session = await _room(test_event, empty.id)
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        assert await end_event_game(test_session, test_event.id, test_user.id) is None
+
+        assert session.game_state.quiz_phase == QuizPhase.NOT_STARTED
+        assert session.game_state.waiting_starter_id is None
+        await test_session.refresh(empty)
+        assert empty.status == SegmentStatus.RECORDING.value
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_adding_a_question_starts_a_waiting_segment(
+    client, test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "empty_segment_start", "wait")
+    empty, _ = await _segments(test_session, test_event, test_user)
+    session = await _room(test_event, empty.id)
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        # Recording stopped without generating anything
+        empty.status = SegmentStatus.QUIZ_READY.value
+        await test_session.commit()
+
+        response = await client.post(
+            f"/api/segments/{empty.id}/questions",
+            json={"question_text": "3+3?", "correct_answer": "6", "fake_answers": ["5", "7"]},
+            headers={"Authorization": f"Bearer {create_access_token(test_user.id, test_user.role)}"},
+        )
+
+        assert response.status_code == 201
+        assert session.game_state.current_segment_id == empty.id
+        assert session.game_state.quiz_phase == QuizPhase.SHOWING_QUESTION
+    finally:
+        await hub.close_session(test_event.id)
@@ -0,0 +1,88 @@
+"""Tests for per-event branding delivered to clients on connect."""
+
+import uuid
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from fastapi import WebSocketDisconnect
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.models import EventParticipant
+from app.ws import game_handler
+from app.ws.hub import hub
+
+
+async def _register(client) -> dict[str, str]:
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    return {"Authorization": f"Bearer {register.json()['token']}"}
+
+
+@pytest.mark.anyio
+async def test_branding_is_validated(client):
+    headers = await _register(client)
+
+    bad_color = await client.post(
+        "/api/quizzes",
+        json={"title": "Branded", "branding": {"primary_color": "red"}},
+        headers=headers,
+    )
+    assert bad_color.status_code == 422
+
+    unknown_key = await client.post(
+        "/api/quizzes",
+        json={"title": "Branded", "branding": {"font": "Comic Sans"}},
+        headers=headers,
+    )
+    assert unknown_key.status_code == 422
+
+
+@pytest.mark.anyio
+async def test_branding_is_sent_in_connected_message(client, test_engine, test_session, monkeypatch):
+    headers = await _register(client)
+    branding = {"logo_url": "https://example.com/logo.png", "primary_color": "#FF6600", "text_color": "#fff"}
+    created = await client.post(
+        "/api/quizzes", json={"title": "Branded", "branding": branding}, headers=headers
+    )
+    assert created.status_code == 200
+    assert created.json()["branding"] == branding
+    event_id = uuid.UUID(created.json()["id"])
+
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=event_id,
+        device_id=uuid4(),
+        display_name="Fan",
+        session_token="fan-session-token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    websocket = AsyncMock()
+    websocket.receive_json.side_effect = [
+        {"type": "join", "user_id": str(participant.id), "session_code": created.json()["join_code"]},
+        WebSocketDisconnect(),
+    ]
+
+    try:
+        await game_handler.websocket_event(
+            websocket, str(event_id), session_token="fan-session-token"
+        )
+
+        connected = [
+            call.args[0]
+            for call in websocket.send_json.call_args_list
+            if call.args[0]["type"] == "connected"
+        ]
+        assert len(connected) == 1
+        assert connected[0]["branding"] == branding
+    finally:
+        hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,71 @@
+"""Tests for deciding when an event's segments are all done."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Segment, SegmentStatus
+from app.ws.game_handler import _maybe_emit_completion_payload, force_complete_event
+from app.ws.hub import hub
+from app.ws.messages import EventCompleteMessage, QuizPhase
+
+
+def _segment(event_id, status: SegmentStatus, order_index: int) -> Segment:
+    return Segment(
+        id=uuid4(),
+        event_id=event_id,
+        presenter_name=f"Presenter {order_index}",
+        order_index=order_index,
+        status=status.value,
+    )
+
+
+def test_done_statuses():
+    assert SegmentStatus.COMPLETED.is_done
+    assert SegmentStatus.SKIPPED.is_done
+    assert not SegmentStatus.PENDING.is_done
+    assert SegmentStatus.PENDING.can_transition_to(SegmentStatus.SKIPPED)
+    assert not SegmentStatus.SKIPPED.can_transition_to(SegmentStatus.RECORDING)
+
+
+@pytest.mark.anyio
+async def test_skipped_and_completed_segments_complete_the_event(test_session, test_event):
+    test_session.add_all([
+        _segment(test_event.id, SegmentStatus.COMPLETED, 0),
+        _segment(test_event.id, SegmentStatus.SKIPPED, 1),
+    ])
+    await test_session.commit()
+
+    completion = await _maybe_emit_completion_payload(test_session, test_event.id)
+
+    assert isinstance(completion, EventCompleteMessage)
+
+
+@pytest.mark.anyio
+async def test_pending_segment_blocks_completion_until_host_forces_it(
+    test_session, test_user, test_event
+):
+    pending = _segment(test_event.id, SegmentStatus.PENDING, 1)
+    test_session.add_all([_segment(test_event.id, SegmentStatus.COMPLETED, 0), pending])
+    await test_session.commit()
+
+    assert await _maybe_emit_completion_payload(test_session, test_event.id) is None
+
+    session = await hub.get_or_create_session(test_event.id)
+    viewer = AsyncMock()
+    session.connections[uuid4()] = viewer
+    try:
+        error = await force_complete_event(test_session, test_event.id, uuid4())
+        assert error == "Only the host can force the event to complete"
+        assert viewer.send_json.call_count == 0
+
+        assert await force_complete_event(test_session, test_event.id, test_user.id) is None
+
+        await test_session.refresh(pending)
+        assert pending.status == SegmentStatus.SKIPPED.value
+        sent = [call.args[0]["type"] for call in viewer.send_json.call_args_list]
+        assert "event_complete" in sent
+        assert session.game_state.quiz_phase == QuizPhase.EVENT_COMPLETE
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
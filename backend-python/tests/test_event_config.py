@@ -0,0 +1,58 @@
+"""Tests for the single-document event configuration endpoint."""
+
+import uuid
+
+import pytest
+
+
+@pytest.mark.anyio
+async def test_event_config_is_scoped_to_the_audience(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes",
+        json={"title": "Configured", "time_per_question": 45, "num_fake_answers": 2},
+        headers=headers,
+    )
+    event_id = event.json()["id"]
+    await client.put(
+        f"/api/quizzes/{event_id}",
+        json={"numeric_scoring_mode": "proximity", "rng_seed": 7},
+        headers=headers,
+    )
+    joined = await client.post(
+        "/api/events/join",
+        json={
+            "code": event.json()["join_code"],
+            "device_fingerprint": str(uuid.uuid4()),
+            "display_name": "Viewer",
+        },
+    )
+    session_token = joined.json()["sessionToken"]
+
+    host_view = await client.get(f"/api/events/{event_id}/config", headers=headers)
+    assert host_view.status_code == 200
+    host_config = host_view.json()
+    assert host_config["time_per_question"] == 45
+    assert host_config["num_fake_answers"] == 2
+    assert host_config["numeric_scoring_mode"] == "proximity"
+    assert host_config["visibility"] == "unlisted"
+    assert host_config["rng_seed"] == 7
+    assert host_config["join_code"] == event.json()["join_code"]
+
+    participant_view = await client.get(
+        f"/api/events/{event_id}/config", headers={"X-Session-Token": session_token}
+    )
+    assert participant_view.status_code == 200
+    config = participant_view.json()
+    assert config["time_per_question"] == 45
+    assert config["numeric_scoring_mode"] == "proximity"
+    assert "max_duration_seconds" in config
+    for host_only in ("join_code", "rng_seed", "store_transcripts", "questions_to_generate"):
+        assert host_only not in config
+
+    anonymous = await client.get(f"/api/events/{event_id}/config")
+    assert anonymous.status_code == 403
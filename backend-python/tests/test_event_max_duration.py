@@ -0,0 +1,64 @@
+"""Tests for auto-finalizing events that exceed their maximum duration."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventStatus, Segment, SegmentStatus
+from app.services.event_expiry import find_overdue_events
+from app.ws.game_handler import finalize_overdue_event
+from app.ws.hub import hub
+
+
+@pytest.mark.anyio
+async def test_overdue_event_is_finalized_and_session_reaped(test_session, test_event):
+    started_at = datetime(2025, 1, 1, 12, 0, tzinfo=timezone.utc)
+    test_event.started_at = started_at
+    test_event.max_duration_seconds = 60
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Presenter",
+        status=SegmentStatus.QUIZZING.value,
+    )
+    test_session.add(segment)
+    await test_session.commit()
+
+    # Simulated clock: just inside the limit, then past it
+    assert await find_overdue_events(test_session, now=started_at + timedelta(seconds=59)) == []
+    overdue = await find_overdue_events(test_session, now=started_at + timedelta(seconds=60))
+    assert [event.id for event in overdue] == [test_event.id]
+
+    session = await hub.get_or_create_session(test_event.id)
+    viewer = AsyncMock()
+    session.connections[uuid4()] = viewer
+
+    try:
+        await finalize_overdue_event(test_session, overdue[0])
+
+        await test_session.refresh(test_event)
+        await test_session.refresh(segment)
+        assert test_event.status == EventStatus.FINISHED.value
+        assert test_event.ended_at is not None
+        assert segment.status == SegmentStatus.SKIPPED.value
+        assert test_event.id not in hub.event_sessions
+
+        sent = [call.args[0]["type"] for call in viewer.send_json.call_args_list]
+        assert sent == ["event_complete", "game_ended"]
+        viewer.close.assert_awaited()
+
+        # Finished events are no longer candidates
+        assert await find_overdue_events(test_session, now=started_at + timedelta(hours=1)) == []
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_event_without_max_duration_never_expires(test_session, test_event):
+    test_event.started_at = datetime(2025, 1, 1, tzinfo=timezone.utc)
+    await test_session.commit()
+
+    assert test_event.max_duration_seconds is None
+    assert await find_overdue_events(test_session, now=datetime(2030, 1, 1, tzinfo=timezone.utc)) == []
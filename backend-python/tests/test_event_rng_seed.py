@@ -0,0 +1,58 @@
+"""Tests for reproducible option shuffles and distractor ordering with an event seed."""
+
+from unittest.mock import AsyncMock, MagicMock
+from uuid import uuid4
+
+import pytest
+
+from app.services.ai import fake_answers
+from app.services.ai.fake_answers import get_or_generate_fake_answers
+from app.ws.game_handler import _build_question_payload
+
+QUESTION = "Which planet is largest?"
+
+
+def _options(question_id, distractors: list[str], rng_seed: int | None) -> list[str]:
+    return _build_question_payload(
+        question_id=question_id,
+        question_text=QUESTION,
+        correct_answer="Jupiter",
+        fake_answers=distractors,
+        total_questions=1,
+        time_limit=30,
+        index=0,
+        rng_seed=rng_seed,
+    ).answers
+
+
+def test_seeded_shuffle_is_reproducible():
+    distractors = ["Saturn", "Neptune", "Earth", "Mars", "Venus"]
+    question_ids = [uuid4() for _ in range(10)]
+
+    first_run = [_options(question_id, distractors, 42) for question_id in question_ids]
+    second_run = [_options(question_id, distractors, 42) for question_id in question_ids]
+    other_seed = [_options(question_id, distractors, 7) for question_id in question_ids]
+
+    assert first_run == second_run
+    assert first_run != other_seed
+
+
+@pytest.mark.anyio
+async def test_seeded_generation_gives_identical_option_order(monkeypatch):
+    provider = MagicMock()
+    # A rerun regenerates from scratch and the provider returns the distractors reordered
+    provider.generate_fake_answers = AsyncMock(
+        side_effect=[["Saturn", "Neptune", "Mars"], ["Mars", "Saturn", "Neptune"]]
+    )
+    question_id = uuid4()
+
+    runs = []
+    for _ in range(2):
+        monkeypatch.setattr(fake_answers, "_cache", {})
+        distractors = await get_or_generate_fake_answers(
+            QUESTION, "Jupiter", ai_provider=provider, rng_seed=42
+        )
+        runs.append((distractors, _options(question_id, distractors, 42)))
+
+    assert runs[0] == runs[1]
+    assert sorted(runs[0][0]) == ["Mars", "Neptune", "Saturn"]
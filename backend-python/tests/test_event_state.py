@@ -0,0 +1,57 @@
+"""Tests for the REST game state snapshot."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_state_mid_question_hides_correct_answer(client, test_session, test_event):
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Player",
+        session_token="state_token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.questions = [
+        {
+            "id": str(uuid4()),
+            "text": "Capital of France?",
+            "correct_answer": "Paris",
+            "fake_answers": ["Lyon", "Nice"],
+        }
+    ]
+    state.total_questions = 1
+    state.current_question_index = 0
+    state.time_limit_seconds = 30
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+
+    try:
+        response = await client.get(
+            f"/api/events/{test_event.id}/state",
+            headers={"X-Session-Token": "state_token"},
+        )
+        assert response.status_code == 200
+        data = response.json()
+        assert data["phase"] == "showing_question"
+        assert data["time_remaining_seconds"] > 0
+        assert data["answer_pending"] is True
+        assert sorted(data["answers"]) == ["Lyon", "Nice", "Paris"]
+        assert "correct_answer" not in data
+
+        anonymous = await client.get(f"/api/events/{test_event.id}/state")
+        assert anonymous.status_code == 403
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -85,3 +85,66 @@ async def test_join_event_invalid_code(client):
     """Test joining with invalid code."""
     response = await client.get("/api/events/join/INVALID")
     assert response.status_code == 404
+
+
+@pytest.mark.anyio
+async def test_save_and_get_event_draft(client):
+    """Saving a draft stores it without changing the published event."""
+    token = await create_test_user(client)
+    headers = {"Authorization": f"Bearer {token}"}
+    create_response = await client.post("/api/quizzes", json={"title": "Live"}, headers=headers)
+    event_id = create_response.json()["id"]
+
+    response = await client.patch(
+        f"/api/quizzes/{event_id}/draft",
+        json={"draft": {"title": "Draft Title", "time_per_question": 45}},
+        headers=headers,
+    )
+    assert response.status_code == 200
+
+    draft = await client.get(f"/api/quizzes/{event_id}/draft", headers=headers)
+    assert draft.json()["draft"] == {"title": "Draft Title", "time_per_question": 45}
+    assert draft.json()["draft_updated_at"] is not None
+
+    live = await client.get(f"/api/quizzes/{event_id}", headers=headers)
+    assert live.json()["title"] == "Live"
+
+
+@pytest.mark.anyio
+async def test_publish_event_draft(client):
+    """Publishing promotes the draft over the live event and clears it."""
+    token = await create_test_user(client)
+    headers = {"Authorization": f"Bearer {token}"}
+    create_response = await client.post("/api/quizzes", json={"title": "Live"}, headers=headers)
+    event_id = create_response.json()["id"]
+
+    await client.patch(
+        f"/api/quizzes/{event_id}/draft",
+        json={"draft": {"title": "Published Title", "time_per_question": 45}},
+        headers=headers,
+    )
+
+    response = await client.post(f"/api/quizzes/{event_id}/draft/publish", headers=headers)
+    assert response.status_code == 200
+    assert response.json()["title"] == "Published Title"
+    assert response.json()["time_per_question"] == 45
+
+    draft = await client.get(f"/api/quizzes/{event_id}/draft", headers=headers)
+    assert draft.json()["draft"] is None
+
+
+@pytest.mark.anyio
+async def test_create_event_with_idempotency_key_is_not_duplicated(client):
+    """Test retrying a create with the same Idempotency-Key returns the original event."""
+    token = await create_test_user(client)
+    headers = {"Authorization": f"Bearer {token}", "Idempotency-Key": "create-retry-1"}
+
+    first = await client.post("/api/quizzes", json={"title": "Retried"}, headers=headers)
+    second = await client.post("/api/quizzes", json={"title": "Retried"}, headers=headers)
+
+    assert first.status_code == 201
+    assert second.status_code == 200
+    assert first.json()["id"] == second.json()["id"]
+
+    listed = await client.get("/api/quizzes", headers={"Authorization": f"Bearer {token}"})
+    assert [e["title"] for e in listed.json()] == ["Retried"]
@@ -0,0 +1,72 @@
+"""Tests for reusing generated fake answers across identical questions."""
+
+from unittest.mock import AsyncMock, MagicMock
+
+import pytest
+
+from app.services.ai import fake_answers
+from app.services.ai.fake_answers import get_or_generate_fake_answers
+
+
+@pytest.mark.anyio
+async def test_identical_question_in_another_event_hits_cache(monkeypatch):
+    monkeypatch.setattr(fake_answers, "_cache", {})
+    provider = MagicMock()
+    provider.generate_fake_answers = AsyncMock(return_value=["Lyon", "Nice", "Lille"])
+
+    first = await get_or_generate_fake_answers(
+        "What is the capital of France?", "Paris", ai_provider=provider
+    )
+    # Same question reused in a different event, with cosmetic differences
+    second = await get_or_generate_fake_answers(
+        "  what is the capital of   France? ", "paris", ai_provider=provider
+    )
+
+    assert first == second == ["Lyon", "Nice", "Lille"]
+    provider.generate_fake_answers.assert_awaited_once()
+
+
+@pytest.mark.anyio
+async def test_expired_entry_regenerates(monkeypatch):
+    monkeypatch.setattr(fake_answers, "_cache", {})
+    clock = iter([1000.0, 1000.0 + 90000.0])
+    monkeypatch.setattr(fake_answers.time, "monotonic", lambda: next(clock))
+    provider = MagicMock()
+    provider.generate_fake_answers = AsyncMock(return_value=["3", "5", "6"])
+
+    await get_or_generate_fake_answers("2+2?", "4", ai_provider=provider)
+    await get_or_generate_fake_answers("2+2?", "4", ai_provider=provider)
+
+    assert provider.generate_fake_answers.await_count == 2
+
+
+@pytest.mark.anyio
+async def test_shortfall_retries_then_pads_without_caching(monkeypatch):
+    monkeypatch.setattr(fake_answers, "_cache", {})
+    provider = MagicMock()
+    provider.generate_fake_answers = AsyncMock(side_effect=[["Mars"], ["Mars", "Venus"]])
+
+    result = await get_or_generate_fake_answers(
+        "Which planet is largest?", "Jupiter", num_fakes=3, ai_provider=provider
+    )
+
+    assert provider.generate_fake_answers.await_count == 2
+    assert result == ["Mars", "Venus", "Option 3"]
+    assert fake_answers._cache == {}
+
+
+@pytest.mark.anyio
+async def test_shortfall_filled_by_retry_is_cached(monkeypatch):
+    monkeypatch.setattr(fake_answers, "_cache", {})
+    provider = MagicMock()
+    provider.generate_fake_answers = AsyncMock(
+        side_effect=[["Mars", "Jupiter"], ["Venus", "Saturn"]]
+    )
+
+    result = await get_or_generate_fake_answers(
+        "Which planet is largest?", "Jupiter", num_fakes=3, ai_provider=provider
+    )
+
+    # The correct answer echoed back by the provider is never a distractor
+    assert result == ["Mars", "Venus", "Saturn"]
+    assert len(fake_answers._cache) == 1
@@ -0,0 +1,82 @@
+"""Tests for previewing AI distractors for a manual question."""
+
+import uuid
+from unittest.mock import AsyncMock, patch
+from uuid import UUID
+
+import pytest
+from sqlalchemy import func, select
+
+from app.config import get_settings
+from app.models import ParticipantAnswer, Question
+from app.services.ai import fake_answers
+
+
+@pytest.mark.anyio
+async def test_preview_returns_distractors_without_saving(client, test_session, monkeypatch):
+    monkeypatch.setattr(fake_answers, "_cache", {})
+    monkeypatch.setattr(get_settings(), "default_ai_provider", "claude")
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes", json={"title": "Manual", "num_fake_answers": 3}, headers=headers
+    )
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    created = await client.post(
+        f"/api/segments/{segment.json()['id']}/questions",
+        json={"question_text": "What is the capital of France?", "correct_answer": "Paris"},
+        headers=headers,
+    )
+    question_id = created.json()["id"]
+
+    with patch("app.services.ai.ClaudeProvider") as MockProvider:
+        MockProvider.return_value.generate_fake_answers = AsyncMock(
+            return_value=["Lyon", "Nice", "Lille"]
+        )
+        response = await client.post(f"/api/questions/{question_id}/answers/preview", headers=headers)
+
+    assert response.status_code == 200
+    assert response.json() == {"question_id": question_id, "fake_answers": ["Lyon", "Nice", "Lille"]}
+    question = await test_session.get(Question, UUID(question_id))
+    await test_session.refresh(question)
+    assert not question.fake_answers
+    answer_count = await test_session.scalar(select(func.count()).select_from(ParticipantAnswer))
+    assert answer_count == 0
+
+
+@pytest.mark.anyio
+async def test_preview_is_limited_to_host_and_presenter(client):
+    host = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {host.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Manual"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    created = await client.post(
+        f"/api/segments/{segment.json()['id']}/questions",
+        json={"question_text": "2 + 2?", "correct_answer": "4"},
+        headers=headers,
+    )
+    other = await client.post(
+        "/api/auth/register",
+        json={"username": f"other_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+
+    response = await client.post(
+        f"/api/questions/{created.json()['id']}/answers/preview",
+        headers={"Authorization": f"Bearer {other.json()['token']}"},
+    )
+
+    assert response.status_code == 404
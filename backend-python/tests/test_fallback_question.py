@@ -0,0 +1,85 @@
+"""Tests for inserting a fallback question after repeated generation failures."""
+
+import uuid
+from unittest.mock import AsyncMock, patch
+from uuid import UUID
+
+import pytest
+
+from app.config import get_settings
+from app.models import Event, Segment
+from app.routes.segments import _generate_questions_for_transcript
+from app.services.ai.base import GeneratedQuestion, QualityAssessment
+from app.services.fallback_questions import DEFAULT_FALLBACK_QUESTIONS
+
+TRANSCRIPT = "We talked about Python, type hints and packaging for most of the afternoon."
+
+
+@pytest.mark.anyio
+async def test_fallback_question_after_configured_failures(client, test_session, monkeypatch):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "default_ai_provider", "openai")
+    monkeypatch.setattr(settings, "fallback_question_after_failures", 2)
+    monkeypatch.setattr(settings, "fallback_question_bank_path", "")
+
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event_response = await client.post(
+        "/api/quizzes", json={"title": "Quiet Talk", "num_fake_answers": 2}, headers=headers
+    )
+    segment_response = await client.post(
+        f"/api/quizzes/{event_response.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = UUID(segment_response.json()["id"])
+    event = await test_session.get(Event, UUID(event_response.json()["id"]))
+
+    low_quality = GeneratedQuestion(
+        question_text="What was discussed?",
+        correct_answer="Things",
+        fake_answers=["Stuff", "Other"],
+        source_transcript=TRANSCRIPT,
+    )
+    assessment = QualityAssessment(
+        clarity_score=0.1,
+        answerability_score=0.1,
+        factual_accuracy_score=0.1,
+        overall_score=0.1,
+        issues=["vague"],
+    )
+
+    with patch("app.services.ai.OpenAIProvider") as MockProvider:
+        provider = MockProvider.return_value
+        provider.generate_questions_batch = AsyncMock(return_value=[low_quality])
+        provider.analyze_and_generate_question = AsyncMock(return_value=low_quality)
+        provider.evaluate_question_quality = AsyncMock(return_value=assessment)
+
+        async def attempt():
+            return await _generate_questions_for_transcript(
+                db=test_session,
+                segment_id=segment_id,
+                transcript_text=TRANSCRIPT,
+                event=event,
+                settings=settings,
+                quality_scoring=True,
+            )
+
+        first = await attempt()
+        segment = await test_session.get(Segment, segment_id)
+        assert first == []
+        assert segment.generation_failures == 1
+
+        second = await attempt()
+
+    assert len(second) == 1
+    fallback = second[0]
+    assert fallback.is_ai_generated is False
+    bank = {question: (answer, fakes) for question, answer, fakes in DEFAULT_FALLBACK_QUESTIONS}
+    assert fallback.question_text in bank
+    assert fallback.correct_answer == bank[fallback.question_text][0]
+    assert len(fallback.fake_answers) == 2
+    assert segment.generation_failures == 0
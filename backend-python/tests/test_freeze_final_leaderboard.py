@@ -0,0 +1,75 @@
+"""Tests for withholding the leaderboard from a segment's final reveal."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question, Segment, SegmentStatus
+from app.ws.game_handler import (
+    _build_reveal_payload,
+    _freeze_final_leaderboard,
+    _reveal_final_leaderboard,
+)
+from app.ws.hub import hub
+
+
+def _reveal(question_id):
+    message = _build_reveal_payload(
+        question=Question(
+            id=question_id,
+            segment_id=uuid4(),
+            question_text="Last one?",
+            correct_answer="A",
+            order_index=1,
+        ),
+        question_index=1,
+        answers=["A"],
+    )
+    message.segment_leaderboard = [{"rank": 1}]
+    message.event_leaderboard = [{"rank": 1}]
+    return message
+
+
+@pytest.mark.anyio
+async def test_final_reveal_withholds_leaderboard_until_released(test_session, test_user, test_event):
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Presenter",
+        status=SegmentStatus.QUIZZING.value,
+    )
+    test_session.add(segment)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    session.game_state.current_segment_id = segment.id
+    session.game_state.questions = [{"id": uuid4()}, {"id": uuid4()}]
+
+    # Not the last question: leaderboard stays in the reveal
+    session.game_state.current_question_index = 0
+    early = _reveal(uuid4())
+    assert _freeze_final_leaderboard(early, session, freeze_enabled=True) is False
+    assert early.event_leaderboard == [{"rank": 1}]
+
+    session.game_state.current_question_index = 1
+    final = _reveal(uuid4())
+    assert _freeze_final_leaderboard(final, session, freeze_enabled=True) is True
+    assert final.segment_leaderboard == []
+    assert final.event_leaderboard == []
+
+    websocket = AsyncMock()
+    session.connections[uuid4()] = websocket
+    error = await _reveal_final_leaderboard(test_session, session, test_event.id, test_user.id)
+
+    assert error is None
+    payload = websocket.send_json.call_args.args[0]
+    assert payload["type"] == "final_leaderboard"
+    assert "event_leaderboard" in payload
+    assert session.game_state.final_leaderboard_frozen is False
+
+    # Nothing left to release
+    error = await _reveal_final_leaderboard(test_session, session, test_event.id, test_user.id)
+    assert error == "No leaderboard is waiting to be revealed"
+
+    hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,53 @@
+"""Tests for the Gemini AI provider."""
+
+import json
+
+import httpx
+import pytest
+
+from app.services.ai import GeminiProvider, create_ai_provider
+from app.services.ai.usage import collect_usage
+
+
+def _gemini_response(text: str) -> dict:
+    return {
+        "candidates": [{"content": {"role": "model", "parts": [{"text": text}]}}],
+        "usageMetadata": {"promptTokenCount": 42, "candidatesTokenCount": 7},
+    }
+
+
+@pytest.mark.anyio
+async def test_generate_fake_answers_calls_generative_language_api():
+    requests: list[httpx.Request] = []
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        requests.append(request)
+        return httpx.Response(
+            200, json=_gemini_response('{"answers": ["Lyon", "Marseille", "Nice", "Extra"]}')
+        )
+
+    provider = GeminiProvider(api_key="gemini-test-key", transport=httpx.MockTransport(handler))
+
+    with collect_usage() as usage:
+        answers = await provider.generate_fake_answers("Capital of France?", "Paris", num_fakes=3)
+
+    assert answers == ["Lyon", "Marseille", "Nice"]
+    request = requests[0]
+    assert request.url.path.endswith(f"/models/{provider.model}:generateContent")
+    assert request.headers["x-goog-api-key"] == "gemini-test-key"
+    body = json.loads(request.content)
+    assert "Capital of France?" in body["contents"][0]["parts"][0]["text"]
+    assert body["generationConfig"]["responseMimeType"] == "application/json"
+    assert [(u.provider, u.input_tokens, u.output_tokens) for u in usage] == [("gemini", 42, 7)]
+
+
+@pytest.mark.anyio
+async def test_unparseable_fake_answers_fall_back_to_placeholders():
+    transport = httpx.MockTransport(lambda request: httpx.Response(200, json=_gemini_response("not json")))
+    provider = GeminiProvider(api_key="gemini-test-key", transport=transport)
+
+    assert await provider.generate_fake_answers("Q?", "A", num_fakes=2) == ["Option 1", "Option 2"]
+
+
+def test_create_ai_provider_selects_gemini():
+    assert isinstance(create_ai_provider("gemini"), GeminiProvider)
@@ -0,0 +1,71 @@
+"""Tests for generation deferred by the manual-question cooldown."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock, patch
+from uuid import uuid4
+
+import pytest
+from sqlalchemy import select
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.config import get_settings
+from app.models import Question, Segment, SegmentStatus
+from app.routes import segments
+from app.services.ai.base import GeneratedQuestion
+
+
+@pytest.mark.anyio
+async def test_generation_deferred_by_the_cooldown_runs_once_it_ends(
+    test_engine, test_session, test_event, monkeypatch
+):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "manual_question_generation_cooldown_seconds", 1)
+    monkeypatch.setattr(settings, "default_ai_provider", "openai")
+    monkeypatch.setattr(settings, "enable_ai_quality_scoring", False)
+    monkeypatch.setattr(
+        segments,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Host",
+        status=SegmentStatus.RECORDING.value,
+        last_manual_question_at=datetime.now(timezone.utc),
+    )
+    test_session.add(segment)
+    await test_session.commit()
+    transcript = "This is a test transcript about Python programming and web development."
+    generated = [
+        GeneratedQuestion(
+            question_text="What language?",
+            correct_answer="Python",
+            fake_answers=["Ruby", "Go", "Rust"],
+            source_transcript=transcript,
+        )
+    ]
+
+    async def questions_in_segment() -> list[Question]:
+        result = await test_session.execute(select(Question).where(Question.segment_id == segment.id))
+        return result.scalars().all()
+
+    with patch("app.services.ai.OpenAIProvider") as MockProvider:
+        generate = MockProvider.return_value.generate_questions_batch = AsyncMock(
+            return_value=generated
+        )
+
+        # Woken up before the cooldown is over: nothing is generated, it waits again
+        segments._defer_generation(segment.id, transcript, 0)
+        await segments._deferred_generations[segment.id]
+        generate.assert_not_called()
+        assert await questions_in_segment() == []
+
+        await segments._deferred_generations[segment.id]
+
+    assert segment.id not in segments._deferred_generations
+    generate.assert_called_once()
+    test_session.expire_all()
+    assert [q.question_text for q in await questions_in_segment()] == ["What language?"]
+    await test_session.refresh(segment)
+    assert segment.status == SegmentStatus.QUIZ_READY.value
@@ -0,0 +1,208 @@
+"""Tests for the go_to_segment WebSocket command."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Event, Question, Segment, SegmentStatus, User
+from app.ws.game_handler import _switch_to_segment, start_event_game
+from app.ws.hub import hub
+from app.ws.messages import GoToSegmentMessage, ParticipantInfo, QuizPhase, parse_client_message
+
+
+def test_parse_go_to_segment_message():
+    segment_id = uuid4()
+    message = parse_client_message({"type": "go_to_segment", "segment_id": str(segment_id)})
+    assert isinstance(message, GoToSegmentMessage)
+    assert message.segment_id == segment_id
+
+
+@pytest.mark.anyio
+async def test_switch_to_valid_segment_resets_question_state(test_session, test_user, test_event):
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Second Presenter",
+        title="Second",
+        order_index=1,
+        status=SegmentStatus.QUIZ_READY.value,
+    )
+    test_session.add(segment)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    session.game_state.current_question_index = 3
+    session.game_state.answers_received[uuid4()] = "A"
+    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+
+    switched, error = await _switch_to_segment(
+        test_session, session, test_event.id, segment.id, test_user.id
+    )
+
+    assert error is None
+    assert switched.segment_id == segment.id
+    assert session.game_state.current_segment_id == segment.id
+    assert session.game_state.current_question_index == 0
+    assert session.game_state.answers_received == {}
+    assert session.game_state.quiz_phase == QuizPhase.NOT_STARTED
+
+    hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_switch_mid_question_stops_timers_and_drops_question_state(
+    test_session, test_user, test_event
+):
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Second Presenter",
+        order_index=1,
+        status=SegmentStatus.QUIZ_READY.value,
+    )
+    test_session.add(segment)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    question_id = uuid4()
+    state.current_question_id = question_id
+    state.question_started_at = datetime.now(timezone.utc)
+    state.quiz_phase = QuizPhase.BUZZING
+    state.provisional_answers[uuid4()] = ("A", state.question_started_at)
+    state.buzz_winner_id = uuid4()
+    state.buzz_deadline = state.question_started_at + timedelta(seconds=5)
+    state.revealed_question = (question_id, state.question_started_at)
+    reveal_task = asyncio.create_task(asyncio.sleep(60))
+    countdown_task = asyncio.create_task(asyncio.sleep(60))
+    session.reveal_task = reveal_task
+    session.countdown_task = countdown_task
+
+    switched, error = await _switch_to_segment(
+        test_session, session, test_event.id, segment.id, test_user.id
+    )
+    await asyncio.gather(reveal_task, countdown_task, return_exceptions=True)
+
+    assert error is None
+    assert reveal_task.cancelled() and countdown_task.cancelled()
+    assert session.reveal_task is None and session.countdown_task is None
+    assert state.provisional_answers == {}
+    assert (state.buzz_winner_id, state.buzz_deadline) == (None, None)
+    assert state.revealed_question is None
+
+    hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_segment_left_mid_quiz_can_be_started_again(test_session, test_user, test_event):
+    first, second = (
+        Segment(
+            id=uuid4(),
+            event_id=test_event.id,
+            presenter_name="Host",
+            presenter_user_id=test_user.id,
+            order_index=i,
+            status=SegmentStatus.QUIZ_READY.value,
+        )
+        for i in range(2)
+    )
+    test_session.add_all([first, second])
+    await test_session.flush()
+    test_session.add_all(
+        Question(id=uuid4(), segment_id=s.id, question_text="2+2?", correct_answer="4", order_index=0)
+        for s in (first, second)
+    )
+    await test_session.commit()
+
+    player_id = uuid4()
+    session = await hub.connect(test_event.id, player_id, AsyncMock())
+    session.game_state.participants[player_id] = ParticipantInfo(user_id=player_id, username="Player")
+    session.game_state.current_segment_id = first.id
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        await test_session.refresh(first)
+        assert first.status == SegmentStatus.QUIZZING.value
+
+        _, error = await _switch_to_segment(test_session, session, test_event.id, second.id, test_user.id)
+        assert error is None
+        await test_session.refresh(first)
+        assert first.status == SegmentStatus.QUIZ_READY.value
+
+        _, error = await _switch_to_segment(test_session, session, test_event.id, first.id, test_user.id)
+        assert error is None
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        assert session.game_state.current_segment_id == first.id
+        assert session.game_state.quiz_phase == QuizPhase.SHOWING_QUESTION
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_switch_rejects_completed_and_foreign_segments(test_session, test_user, test_event):
+    other_event = Event(
+        id=uuid4(),
+        host_id=test_user.id,
+        title="Other",
+        join_code="OTHER1",
+    )
+    test_session.add(other_event)
+    await test_session.flush()
+
+    completed = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Done",
+        status=SegmentStatus.COMPLETED.value,
+    )
+    foreign = Segment(
+        id=uuid4(),
+        event_id=other_event.id,
+        presenter_name="Elsewhere",
+    )
+    test_session.add_all([completed, foreign])
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+
+    switched, error = await _switch_to_segment(
+        test_session, session, test_event.id, completed.id, test_user.id
+    )
+    assert switched is None
+    assert error == "Cannot switch to a completed segment"
+
+    switched, error = await _switch_to_segment(
+        test_session, session, test_event.id, foreign.id, test_user.id
+    )
+    assert switched is None
+    assert error == "Segment not found"
+    assert session.game_state.current_segment_id is None
+
+    hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_switch_requires_host(test_session, test_event):
+    intruder = User(
+        id=uuid4(),
+        username="intruder",
+        display_name="intruder",
+        email="intruder@example.com",
+        password_hash="hash",
+    )
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="P")
+    test_session.add_all([intruder, segment])
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    switched, error = await _switch_to_segment(
+        test_session, session, test_event.id, segment.id, intruder.id
+    )
+
+    assert switched is None
+    assert error == "Only the host can switch segments"
+
+    hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,143 @@
+"""Tests for closing connections that stop answering heartbeat pings."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from fastapi import WebSocketDisconnect
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.config import get_settings
+from app.models import EventParticipant
+from app.ws import game_handler
+from app.ws.heartbeat import heartbeat_manager
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo, QuizPhase
+
+
+def _sent_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+def _silent_socket(join: dict) -> AsyncMock:
+    """A socket that joins and then stays silent until the server closes it."""
+    closed = asyncio.Event()
+    messages = iter([join])
+    websocket = AsyncMock()
+
+    async def close(code: int = 1000) -> None:
+        closed.set()
+
+    async def receive_json():
+        for message in messages:
+            return message
+        await closed.wait()
+        raise WebSocketDisconnect(code=1001)
+
+    websocket.close.side_effect = close
+    websocket.receive_json.side_effect = receive_json
+    return websocket
+
+
+@pytest.mark.anyio
+async def test_silent_client_is_reaped_and_announced_as_left(
+    test_engine, test_session, test_event, monkeypatch
+):
+    monkeypatch.setattr(get_settings(), "ws_reconnect_window_seconds", 0)
+    monkeypatch.setattr(get_settings(), "ws_heartbeat_timeout_seconds", 30)
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Silent",
+        session_token="silent-session-token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+
+    viewer_id = uuid4()
+    viewer = AsyncMock()
+    await hub.connect(test_event.id, viewer_id, viewer)
+
+    websocket = _silent_socket(
+        {"type": "join", "user_id": str(participant.id), "session_code": test_event.join_code}
+    )
+
+    handler = asyncio.create_task(
+        game_handler.websocket_event(
+            websocket, str(test_event.id), session_token="silent-session-token"
+        )
+    )
+    try:
+        while participant.id not in hub.event_sessions[test_event.id].connections:
+            await asyncio.sleep(0.01)
+        heartbeat_manager._active_heartbeats[participant.id] = datetime.now(
+            timezone.utc
+        ) - timedelta(seconds=31)
+
+        reaped = await hub.cleanup_stale_connections()
+        await asyncio.wait_for(handler, timeout=1)
+        await asyncio.sleep(0.05)
+
+        assert reaped == {test_event.id: [participant.id]}
+        websocket.close.assert_awaited_once()
+        assert participant.id not in hub.event_sessions[test_event.id].connections
+        assert viewer_id in hub.event_sessions[test_event.id].connections
+        assert "participant_left" in _sent_types(viewer)
+    finally:
+        handler.cancel()
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_client_answering_pings_is_kept():
+    event_id, player_id = uuid4(), uuid4()
+    websocket = AsyncMock()
+    await hub.connect(event_id, player_id, websocket)
+
+    try:
+        heartbeat_manager._active_heartbeats[player_id] = datetime.now(timezone.utc) - timedelta(
+            seconds=120
+        )
+        hub.handle_pong(player_id)
+
+        assert await hub.cleanup_stale_connections() == {}
+        websocket.close.assert_not_awaited()
+        assert player_id in hub.event_sessions[event_id].connections
+    finally:
+        await hub.close_session(event_id)
+
+
+@pytest.mark.anyio
+async def test_reaped_straggler_no_longer_holds_up_all_answered(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_reconnect_window_seconds", 0)
+    event_id, answered_id, silent_id = uuid4(), uuid4(), uuid4()
+    answered, silent = AsyncMock(), AsyncMock()
+    await hub.connect(event_id, answered_id, answered)
+    session = await hub.connect(event_id, silent_id, silent)
+    state = session.game_state
+    state.participants = {
+        user_id: ParticipantInfo(user_id=user_id, username=name)
+        for user_id, name in ((answered_id, "Answered"), (silent_id, "Silent"))
+    }
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+    state.answers_received[answered_id] = "4"
+
+    try:
+        heartbeat_manager._active_heartbeats[silent_id] = datetime.now(timezone.utc) - timedelta(
+            seconds=31
+        )
+        assert await hub.cleanup_stale_connections() == {event_id: [silent_id]}
+        await game_handler._announce_participant_left(event_id, silent_id, "Silent")
+
+        assert _sent_types(answered)[-2:] == ["participant_left", "all_answered"]
+    finally:
+        await hub.close_session(event_id)
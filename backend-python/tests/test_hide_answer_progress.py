@@ -0,0 +1,77 @@
+"""Tests for hiding per-answer progress while a question is open."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question
+from app.ws.game_handler import _announce_answer_received, _build_reveal_payload
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+def _sent_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+@pytest.mark.anyio
+async def test_hidden_progress_skips_answer_received_but_reveal_counts_all():
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+    state.time_limit_seconds = 30
+
+    players = [uuid4(), uuid4(), uuid4()]
+    sockets = {player: AsyncMock() for player in players}
+    session.connections.update(sockets)
+
+    try:
+        for player, answer in zip(players, ["A", "B", "A"]):
+            success, _ = await hub.record_answer(event_id, player, answer)
+            assert success
+            await _announce_answer_received(event_id, player, hide_answer_progress=True)
+
+        for player, websocket in sockets.items():
+            # Each participant only hears about their own answer
+            received = [
+                call.args[0]
+                for call in websocket.send_json.call_args_list
+                if call.args[0]["type"] == "answer_received"
+            ]
+            assert [message["user_id"] for message in received] == [player]
+
+        reveal = _build_reveal_payload(
+            question=Question(
+                id=uuid4(),
+                segment_id=uuid4(),
+                question_text="Pick one",
+                correct_answer="A",
+                order_index=0,
+            ),
+            question_index=0,
+            answers=state.answers_received.values(),
+            options=["A", "B"],
+        )
+        assert {d.answer: d.count for d in reveal.distribution} == {"A": 2, "B": 1}
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_visible_progress_broadcasts_answer_received():
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    answerer, watcher = uuid4(), uuid4()
+    watcher_socket = AsyncMock()
+    session.connections[answerer] = AsyncMock()
+    session.connections[watcher] = watcher_socket
+
+    try:
+        await _announce_answer_received(event_id, answerer, hide_answer_progress=False)
+        assert _sent_types(watcher_socket) == ["answer_received"]
+    finally:
+        hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,73 @@
+"""Tests for letting the presenter answer and score like a participant."""
+
+from datetime import datetime, timedelta, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question, Segment
+from app.ws.game_handler import _get_segment_leaderboard, _score_answer_submission
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+async def _open_question(test_session, test_event, host_can_answer: bool):
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    question = Question(
+        id=uuid4(),
+        segment_id=segment.id,
+        question_text="2+2?",
+        correct_answer="4",
+        fake_answers=["3", "5"],
+        order_index=0,
+    )
+    test_session.add_all([segment, question])
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.questions = [{"id": question.id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3", "5"]}]
+    state.current_question_id = question.id
+    state.current_segment_id = segment.id
+    state.current_presenter_id = test_event.host_id
+    state.host_can_answer = host_can_answer
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc) - timedelta(seconds=2)
+    return session, segment
+
+
+@pytest.mark.anyio
+async def test_presenter_is_scored_and_ranked_when_host_can_answer(test_session, test_event, test_user):
+    session, segment = await _open_question(test_session, test_event, host_can_answer=True)
+
+    try:
+        submitted_at = datetime.now(timezone.utc)
+        assert await hub.record_answer(test_event.id, test_user.id, "4", submitted_at) == (True, None)
+        answer = await _score_answer_submission(
+            test_session,
+            session=session,
+            participant_id=test_user.id,
+            selected_answer="4",
+            submitted_at=submitted_at,
+        )
+        await test_session.commit()
+
+        assert answer.is_correct is True
+        assert answer.points > 0
+        leaderboard = await _get_segment_leaderboard(test_session, segment.id)
+        assert [(entry["username"], entry["score"]) for entry in leaderboard] == [
+            (test_user.display_name, answer.points)
+        ]
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_presenter_cannot_answer_by_default(test_session, test_event, test_user):
+    await _open_question(test_session, test_event, host_can_answer=False)
+
+    try:
+        assert await hub.record_answer(test_event.id, test_user.id, "4") == (False, "presenter")
+        assert await hub.record_answer(test_event.id, uuid4(), "4") == (True, None)
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
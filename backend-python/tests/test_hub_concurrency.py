@@ -170,5 +170,5 @@ async def test_broadcast_creates_connection_snapshot():
     
     # All websockets should have received the message
     for ws in websockets:
-        ws.send_json.assert_called_once_with(message)
+        ws.send_json.assert_called_once_with({**message, "seq": 1})
 
@@ -0,0 +1,78 @@
+"""Tests for importing a saved quiz's questions into a segment."""
+
+import uuid
+
+import pytest
+
+
+async def _register(client) -> dict[str, str]:
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    return {"Authorization": f"Bearer {register.json()['token']}"}
+
+
+async def _create_segment(client, headers, title: str) -> tuple[str, str]:
+    event = await client.post("/api/quizzes", json={"title": title}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    return event.json()["id"], segment.json()["id"]
+
+
+@pytest.mark.anyio
+async def test_import_quiz_appends_questions_in_order(client):
+    headers = await _register(client)
+    quiz_id, quiz_segment_id = await _create_segment(client, headers, "Saved Quiz")
+    for i in range(3):
+        await client.post(
+            f"/api/segments/{quiz_segment_id}/questions",
+            json={
+                "question_text": f"Saved {i}?",
+                "correct_answer": f"Answer {i}",
+                "fake_answers": [f"Wrong {i}a", f"Wrong {i}b"],
+            },
+            headers=headers,
+        )
+
+    _, segment_id = await _create_segment(client, headers, "Live Talk")
+    await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Existing?", "correct_answer": "Yes", "fake_answers": ["No"]},
+        headers=headers,
+    )
+
+    response = await client.post(
+        f"/api/segments/{segment_id}/import-quiz/{quiz_id}", headers=headers
+    )
+
+    assert response.status_code == 200
+    assert response.json()["imported"] == 3
+
+    listed = await client.get(f"/api/segments/{segment_id}/questions", headers=headers)
+    items = listed.json()["items"]
+    assert [q["question_text"] for q in items] == ["Existing?", "Saved 0?", "Saved 1?", "Saved 2?"]
+    assert [q["order_index"] for q in items] == [0, 1, 2, 3]
+    assert items[1]["fake_answers"] == ["Wrong 0a", "Wrong 0b"]
+    assert all(q["segment_id"] == segment_id for q in items)
+
+    # The saved quiz keeps its own questions
+    original = await client.get(f"/api/segments/{quiz_segment_id}/questions", headers=headers)
+    assert original.json()["total"] == 3
+
+
+@pytest.mark.anyio
+async def test_import_quiz_requires_quiz_ownership(client):
+    owner_headers = await _register(client)
+    quiz_id, _ = await _create_segment(client, owner_headers, "Private Quiz")
+    headers = await _register(client)
+    _, segment_id = await _create_segment(client, headers, "Live Talk")
+
+    response = await client.post(
+        f"/api/segments/{segment_id}/import-quiz/{quiz_id}", headers=headers
+    )
+
+    assert response.status_code == 404
@@ -0,0 +1,59 @@
+"""Tests for privately telling an answerer whether they were right."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _score_answer_submission, _send_own_answer_result
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_only_the_answerer_receives_their_result():
+    event_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    question_id = uuid4()
+    state.questions = [{"id": question_id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3"]}]
+    state.current_question_id = question_id
+    state.current_segment_id = uuid4()
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc) - timedelta(seconds=3)
+
+    answerer, bystander = uuid4(), uuid4()
+    answerer_socket, bystander_socket = AsyncMock(), AsyncMock()
+    session.connections[answerer] = answerer_socket
+    session.connections[bystander] = bystander_socket
+
+    try:
+        with patch(
+            "app.ws.game_handler.record_answer", new=AsyncMock(side_effect=lambda db, answer: answer)
+        ), patch(
+            "app.ws.game_handler.current_streak", new=AsyncMock(return_value=0)
+        ):
+            answer = await _score_answer_submission(
+                MagicMock(),
+                session=session,
+                participant_id=answerer,
+                selected_answer="4",
+                submitted_at=datetime.now(timezone.utc),
+            )
+        assert answer is not None
+
+        await _send_own_answer_result(event_id, answer)
+
+        results = [
+            call.args[0]
+            for call in answerer_socket.send_json.call_args_list
+            if call.args[0]["type"] == "your_answer_result"
+        ]
+        assert len(results) == 1
+        assert results[0]["is_correct"] is True
+        assert results[0]["points"] > 0
+        assert results[0]["question_id"] == question_id
+        bystander_socket.send_json.assert_not_called()
+    finally:
+        hub.event_sessions.pop(event_id, None)
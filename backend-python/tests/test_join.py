@@ -279,3 +279,136 @@ async def test_unique_display_name_generation(client, test_event):
     )
     assert response3.status_code == 200
     assert response3.json()["displayName"] == "Alex 3"
+
+
+@pytest.mark.anyio
+async def test_public_event_discovery_lists_only_public(client, test_session, test_user, test_event):
+    """Only public events show up in the discovery list."""
+    from app.models import Event
+
+    public_event = Event(
+        id=uuid4(),
+        host_id=test_user.id,
+        title="Public Event",
+        join_code="PUBL01",
+        mode="listen_only",
+        status="waiting",
+        visibility="public",
+    )
+    private_event = Event(
+        id=uuid4(),
+        host_id=test_user.id,
+        title="Private Event",
+        join_code="PRIV01",
+        mode="listen_only",
+        status="waiting",
+        visibility="private",
+    )
+    test_session.add_all([public_event, private_event])
+    await test_session.commit()
+
+    response = await client.get("/api/events/public")
+    assert response.status_code == 200
+    assert [e["id"] for e in response.json()] == [str(public_event.id)]
+
+
+@pytest.mark.anyio
+async def test_private_event_cannot_be_joined_by_code(client, test_session, test_event):
+    """A private event rejects join-by-code without an invite."""
+    test_event.visibility = "private"
+    await test_session.commit()
+
+    response = await client.post(
+        "/api/events/join",
+        json={
+            "code": test_event.join_code,
+            "device_fingerprint": str(uuid4()),
+            "display_name": "Gatecrasher",
+        },
+    )
+    assert response.status_code == 403
+    assert response.json()["detail"] == "This event is invite-only"
+
+
+@pytest.mark.anyio
+async def test_private_event_join_with_invite(client):
+    """An invite lets one new device into a private event and is then used up."""
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes",
+        json={"title": "Invite Only", "visibility": "private"},
+        headers=headers,
+    )
+    event_id = event.json()["id"]
+    join_code = event.json()["join_code"]
+
+    invite = await client.post(
+        f"/api/events/{event_id}/invites", json={"max_uses": 1}, headers=headers
+    )
+    assert invite.status_code == 201
+    token = invite.json()["token"]
+
+    def join_body(name: str) -> dict:
+        return {"code": join_code, "device_fingerprint": str(uuid4()), "display_name": name}
+
+    without_invite = await client.post("/api/events/join", json=join_body("NoInvite"))
+    assert without_invite.status_code == 403
+
+    bad_invite = await client.post(
+        "/api/events/join", params={"invite": "not-a-token"}, json=join_body("BadInvite")
+    )
+    assert bad_invite.status_code == 403
+
+    joined = await client.post(
+        "/api/events/join", params={"invite": token}, json=join_body("Invited")
+    )
+    assert joined.status_code == 200
+    assert joined.json()["eventId"] == event_id
+
+    reused = await client.post(
+        "/api/events/join", params={"invite": token}, json=join_body("Second")
+    )
+    assert reused.status_code == 403
+    assert reused.json()["detail"] == "Invalid or used-up invite"
+
+
+@pytest.mark.anyio
+async def test_expired_unused_event_rejects_joins_and_is_cleaned_up(client, test_session):
+    """Test that an event never started before its expiry can't be joined and gets removed."""
+    from datetime import datetime, timedelta, timezone
+
+    from sqlalchemy import select
+
+    from app.models import Event
+    from app.services.event_expiry import delete_expired_events
+
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    created = await client.post("/api/quizzes", json={"title": "Stale"}, headers=headers)
+    assert created.json()["expires_at"] is not None
+    event_id = created.json()["id"]
+    join_code = created.json()["join_code"]
+
+    event = await test_session.scalar(select(Event).where(Event.id == event_id))
+    event.expires_at = datetime.now(timezone.utc) - timedelta(minutes=1)
+    await test_session.commit()
+
+    lookup = await client.get(f"/api/events/join/{join_code}")
+    assert lookup.status_code == 410
+    response = await client.post(
+        "/api/events/join",
+        json={"code": join_code, "device_fingerprint": str(uuid4()), "display_name": "Late"},
+    )
+    assert response.status_code == 410
+    assert response.json()["detail"] == "This join code has expired"
+
+    assert await delete_expired_events(test_session) == 1
+    test_session.expunge_all()
+    assert await test_session.scalar(select(Event).where(Event.id == event_id)) is None
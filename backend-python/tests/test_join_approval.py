@@ -0,0 +1,109 @@
+"""Tests for events that require the host to approve each joiner."""
+
+import uuid
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant, JoinStatus
+from app.ws.game_handler import _hold_for_host_approval, approve_join, reject_join
+from app.ws.hub import hub
+
+
+def _types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+@pytest.mark.anyio
+async def test_join_is_pending_when_event_requires_approval(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes", json={"title": "Exclusive", "require_join_approval": True}, headers=headers
+    )
+    assert event.json()["require_join_approval"] is True
+
+    joined = await client.post(
+        "/api/events/join",
+        json={
+            "code": event.json()["join_code"],
+            "device_fingerprint": str(uuid4()),
+            "display_name": "Hopeful",
+        },
+    )
+    assert joined.status_code == 200
+    assert joined.json()["pendingApproval"] is True
+
+
+@pytest.mark.anyio
+async def test_pending_joiner_counts_and_hears_game_only_after_approval(
+    test_session, test_user, test_event
+):
+    joiner = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Hopeful",
+        join_status=JoinStatus.PENDING_APPROVAL.value,
+    )
+    test_session.add(joiner)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    host_socket = AsyncMock()
+    session.connections[test_user.id] = host_socket
+    joiner_socket = AsyncMock()
+
+    try:
+        await _hold_for_host_approval(test_session, test_event.id, joiner, joiner_socket)
+
+        assert "join_requested" in _types(host_socket)
+        assert _types(joiner_socket) == ["join_pending"]
+        assert hub.get_participant_count(test_event.id) == 1  # just the host
+        await hub.broadcast(test_event.id, {"type": "game_started"})
+        assert "game_started" not in _types(joiner_socket)
+
+        error = await approve_join(test_session, test_event.id, joiner.id, joiner.id)
+        assert error == "Only the host can approve or reject joiners"
+
+        assert await approve_join(test_session, test_event.id, test_user.id, joiner.id) is None
+        assert hub.get_participant_count(test_event.id) == 2
+        assert joiner.id in session.game_state.participants
+        assert {"connected", "phase_changed"} <= set(_types(joiner_socket))
+        await hub.broadcast(test_event.id, {"type": "game_started"})
+        assert "game_started" in _types(joiner_socket)
+
+        await test_session.refresh(joiner)
+        assert joiner.join_status == JoinStatus.JOINED.value
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
+
+
+@pytest.mark.anyio
+async def test_rejected_joiner_is_disconnected(test_session, test_user, test_event):
+    joiner = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Gatecrasher",
+        join_status=JoinStatus.PENDING_APPROVAL.value,
+    )
+    test_session.add(joiner)
+    await test_session.commit()
+    joiner_socket = AsyncMock()
+
+    try:
+        await _hold_for_host_approval(test_session, test_event.id, joiner, joiner_socket)
+        assert await reject_join(test_session, test_event.id, test_user.id, joiner.id) is None
+
+        assert "join_rejected" in _types(joiner_socket)
+        joiner_socket.close.assert_awaited_once()
+        assert not hub.is_pending(test_event.id, joiner.id)
+        await test_session.refresh(joiner)
+        assert joiner.join_status == JoinStatus.REJECTED.value
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,102 @@
+"""Tests for merging duplicate user accounts."""
+
+import uuid
+from uuid import UUID, uuid4
+
+import pytest
+from sqlalchemy import select
+
+from app.auth import create_access_token
+from app.config import get_settings
+from app.models import EventParticipant, Segment, SegmentScore, User
+
+
+async def _register(client) -> tuple[UUID, dict[str, str]]:
+    username = f"user_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    body = register.json()
+    return UUID(body["user"]["id"]), {"Authorization": f"Bearer {body['token']}"}
+
+
+def _player(event_id, user_id, total_score: int) -> EventParticipant:
+    return EventParticipant(
+        id=uuid4(),
+        event_id=event_id,
+        user_id=user_id,
+        display_name="Player",
+        device_id=uuid4(),
+        total_score=total_score,
+        total_response_time_ms=1000,
+    )
+
+
+@pytest.mark.anyio
+async def test_merge_sums_scores_in_a_shared_event(
+    client, test_session, test_event, test_user, monkeypatch
+):
+    source_id, _ = await _register(client)
+    target_id, _ = await _register(client)
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    source_player = _player(test_event.id, source_id, 300)
+    target_player = _player(test_event.id, target_id, 500)
+    test_session.add_all([segment, source_player, target_player])
+    await test_session.flush()
+    test_session.add_all([
+        SegmentScore(
+            segment_id=segment.id,
+            participant_id=source_player.id,
+            score=300,
+            questions_answered=1,
+            questions_correct=1,
+        ),
+        SegmentScore(
+            segment_id=segment.id,
+            participant_id=target_player.id,
+            score=500,
+            questions_answered=2,
+            questions_correct=1,
+        ),
+    ])
+    await test_session.commit()
+
+    monkeypatch.setattr(get_settings(), "admin_usernames", test_user.username)
+
+    response = await client.post(
+        "/api/admin/users/merge",
+        json={"source_user_id": str(source_id), "target_user_id": str(target_id)},
+        headers={"Authorization": f"Bearer {create_access_token(test_user.id, test_user.role)}"},
+    )
+
+    assert response.status_code == 200
+    assert response.json()["event_participants"] == 1
+    test_session.expire_all()
+    participants = (
+        await test_session.execute(
+            select(EventParticipant).where(EventParticipant.event_id == test_event.id)
+        )
+    ).scalars().all()
+    assert [(p.user_id, p.total_score, p.total_response_time_ms) for p in participants] == [
+        (target_id, 800, 2000)
+    ]
+    score = (
+        await test_session.execute(select(SegmentScore).where(SegmentScore.segment_id == segment.id))
+    ).scalar_one()
+    assert (score.score, score.questions_answered, score.questions_correct) == (800, 3, 2)
+    assert await test_session.get(User, source_id) is None
+
+
+@pytest.mark.anyio
+async def test_merge_requires_admin(client):
+    source_id, headers = await _register(client)
+    target_id, _ = await _register(client)
+
+    response = await client.post(
+        "/api/admin/users/merge",
+        json={"source_user_id": str(source_id), "target_user_id": str(target_id)},
+        headers=headers,
+    )
+
+    assert response.status_code == 403
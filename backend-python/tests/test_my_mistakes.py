@@ -0,0 +1,73 @@
+"""Tests for reviewing a participant's incorrect answers."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant, ParticipantAnswer, Question, Segment
+
+
+@pytest.mark.anyio
+async def test_my_mistakes_returns_only_incorrect_answers(client, test_session, test_event):
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Presenter")
+    right = Question(
+        id=uuid4(), segment_id=segment.id, question_text="2+2?", correct_answer="4", order_index=0
+    )
+    wrong = Question(
+        id=uuid4(),
+        segment_id=segment.id,
+        question_text="Capital of Australia?",
+        correct_answer="Canberra",
+        order_index=1,
+    )
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Learner",
+        session_token="mistakes_token",
+    )
+    test_session.add_all([segment, participant])
+    await test_session.flush()
+    test_session.add_all([right, wrong])
+    await test_session.flush()
+    now = datetime.now(timezone.utc)
+    test_session.add_all([
+        ParticipantAnswer(
+            event_id=test_event.id,
+            segment_id=segment.id,
+            question_id=right.id,
+            participant_id=participant.id,
+            selected_answer="4",
+            is_correct=True,
+            points=900,
+            answered_at=now,
+        ),
+        ParticipantAnswer(
+            event_id=test_event.id,
+            segment_id=segment.id,
+            question_id=wrong.id,
+            participant_id=participant.id,
+            selected_answer="Sydney",
+            is_correct=False,
+            points=0,
+            answered_at=now,
+        ),
+    ])
+    await test_session.commit()
+
+    response = await client.get(
+        f"/api/events/{test_event.id}/my-mistakes",
+        headers={"X-Session-Token": "mistakes_token"},
+    )
+
+    assert response.status_code == 200
+    mistakes = response.json()
+    assert len(mistakes) == 1
+    assert mistakes[0]["question_id"] == str(wrong.id)
+    assert mistakes[0]["correct_answer"] == "Canberra"
+    assert mistakes[0]["selected_answer"] == "Sydney"
+
+    other = await client.get(f"/api/events/{test_event.id}/my-mistakes")
+    assert other.status_code == 403
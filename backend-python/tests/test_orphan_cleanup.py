@@ -0,0 +1,62 @@
+"""Tests for removing rows left behind by deleted segments."""
+
+import uuid
+from uuid import UUID
+
+import pytest
+from sqlalchemy import select
+
+from app.config import get_settings
+from app.models import PresentationTranscript, Question
+
+
+async def _register(client) -> tuple[str, dict[str, str]]:
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    return username, {"Authorization": f"Bearer {register.json()['token']}"}
+
+
+@pytest.mark.anyio
+async def test_deleting_segment_removes_its_transcripts_and_questions(client, test_session):
+    _, headers = await _register(client)
+    event = await client.post("/api/quizzes", json={"title": "Cleanup"}, headers=headers)
+    event_id = event.json()["id"]
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = UUID(segment.json()["id"])
+    await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Q?", "correct_answer": "A", "fake_answers": ["B", "C"]},
+        headers=headers,
+    )
+    test_session.add(PresentationTranscript(segment_id=segment_id, chunk_text="Hello"))
+    await test_session.commit()
+
+    response = await client.delete(f"/api/quizzes/{event_id}/questions/{segment_id}", headers=headers)
+    assert response.status_code == 204
+
+    transcripts = await test_session.execute(
+        select(PresentationTranscript).where(PresentationTranscript.segment_id == segment_id)
+    )
+    questions = await test_session.execute(select(Question).where(Question.segment_id == segment_id))
+    assert transcripts.scalars().all() == []
+    assert questions.scalars().all() == []
+
+
+@pytest.mark.anyio
+async def test_admin_cleanup_requires_admin(client, monkeypatch):
+    username, headers = await _register(client)
+
+    response = await client.post("/api/admin/cleanup", headers=headers)
+    assert response.status_code == 403
+
+    monkeypatch.setattr(get_settings(), "admin_usernames", username)
+    response = await client.post("/api/admin/cleanup", headers=headers)
+    assert response.status_code == 200
+    assert response.json()["transcripts"] == 0
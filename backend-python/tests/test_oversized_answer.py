@@ -0,0 +1,74 @@
+"""Tests for answers longer than participant_answers can store."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from fastapi import WebSocketDisconnect
+from pydantic import ValidationError
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.models import EventParticipant
+from app.ws import game_handler
+from app.ws.hub import hub
+from app.ws.messages import MAX_SELECTED_ANSWER_LENGTH, parse_client_message
+
+
+def test_answer_messages_cap_the_selected_answer_length():
+    for msg_type in ("answer", "select_answer"):
+        with pytest.raises(ValidationError):
+            parse_client_message(
+                {
+                    "type": msg_type,
+                    "question_id": str(uuid4()),
+                    "selected_answer": "x" * (MAX_SELECTED_ANSWER_LENGTH + 1),
+                    "response_time_ms": 1000,
+                }
+            )
+
+
+@pytest.mark.anyio
+async def test_oversized_answer_gets_an_error_and_keeps_the_socket(
+    test_engine, test_session, test_event, monkeypatch
+):
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Player",
+        session_token="long-answer-token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+
+    websocket = AsyncMock()
+    websocket.receive_json.side_effect = [
+        {"type": "join", "user_id": str(participant.id), "session_code": test_event.join_code},
+        {
+            "type": "answer",
+            "question_id": str(uuid4()),
+            "selected_answer": "x" * (MAX_SELECTED_ANSWER_LENGTH + 1),
+            "response_time_ms": 1000,
+        },
+        {"type": "get_status"},
+        WebSocketDisconnect(),
+    ]
+
+    try:
+        await game_handler.websocket_event(
+            websocket, str(test_event.id), session_token="long-answer-token"
+        )
+
+        sent = [call.args[0] for call in websocket.send_json.call_args_list]
+        errors = [message["message"] for message in sent if message["type"] == "error"]
+        assert errors == ["Invalid message"]
+        # The next message is still served on the same socket
+        sent_types = [message["type"] for message in sent]
+        assert sent_types.index("status") > sent_types.index("error")
+    finally:
+        await hub.close_session(test_event.id)
@@ -0,0 +1,46 @@
+"""Tests for ephemeral participant selecting indicators."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _broadcast_selecting
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase, SelectingAnswerMessage, parse_client_message
+
+
+def test_parse_selecting_answer_message():
+    question_id = uuid4()
+    message = parse_client_message({"type": "selecting_answer", "question_id": str(question_id)})
+    assert isinstance(message, SelectingAnswerMessage)
+    assert message.question_id == question_id
+
+
+@pytest.mark.anyio
+async def test_selecting_is_broadcast_rate_limited_and_not_recorded():
+    event_id = uuid4()
+    user_id = uuid4()
+    question_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    session.game_state.current_question_id = question_id
+    websocket = AsyncMock()
+    session.connections[uuid4()] = websocket
+    now = datetime.now(timezone.utc)
+
+    assert await _broadcast_selecting(event_id, session, user_id, question_id, now=now) is True
+    payload = websocket.send_json.call_args.args[0]
+    assert payload == {"type": "participant_selecting", "user_id": user_id}
+    assert session.game_state.answers_received == {}
+
+    # Within the rate limit window nothing more is sent
+    soon = now + timedelta(milliseconds=200)
+    assert await _broadcast_selecting(event_id, session, user_id, question_id, now=soon) is False
+    assert websocket.send_json.call_count == 1
+
+    later = now + timedelta(seconds=2)
+    assert await _broadcast_selecting(event_id, session, user_id, question_id, now=later) is True
+
+    hub.event_sessions.pop(event_id, None)
@@ -0,0 +1,44 @@
+"""Tests for per-segment presenter permissions."""
+
+from uuid import uuid4
+
+import pytest
+
+from app.models import Segment, SegmentStatus, User
+from app.ws.game_handler import _can_control_segment, end_event_game
+from app.ws.hub import hub
+
+
+@pytest.mark.anyio
+async def test_presenter_without_end_permission_can_advance_but_not_end(test_session, test_event):
+    presenter = User(
+        id=uuid4(),
+        username="limited_presenter",
+        display_name="Limited Presenter",
+        email="limited_presenter@example.com",
+        password_hash="dummy_hash",
+    )
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Limited Presenter",
+        presenter_user_id=presenter.id,
+        status=SegmentStatus.QUIZZING.value,
+        can_end=False,
+    )
+    test_session.add_all([presenter, segment])
+    await test_session.commit()
+
+    assert _can_control_segment(test_event, segment, presenter.id, "advance") is True
+    assert _can_control_segment(test_event, segment, presenter.id, "end") is False
+    assert _can_control_segment(test_event, segment, test_event.host_id, "end") is True
+
+    session = await hub.get_or_create_session(test_event.id)
+    session.game_state.current_segment_id = segment.id
+    try:
+        error = await end_event_game(test_session, test_event.id, presenter.id)
+        assert error == "Only the host or presenter can end the quiz"
+        await test_session.refresh(segment)
+        assert segment.status == SegmentStatus.QUIZZING.value
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,74 @@
+"""Tests for host takeover after the presenter disconnects."""
+
+from datetime import datetime, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question, Segment, SegmentStatus, User
+from app.ws.game_handler import _handle_presenter_disconnect, advance_question
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+@pytest.mark.anyio
+async def test_host_can_advance_after_presenter_disconnects(test_session, test_user, test_event):
+    presenter = User(
+        id=uuid4(),
+        username="dropped_presenter",
+        display_name="Dropped Presenter",
+        email="dropped_presenter@example.com",
+        password_hash="dummy_hash",
+    )
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Dropped Presenter",
+        presenter_user_id=presenter.id,
+        status=SegmentStatus.QUIZZING.value,
+    )
+    questions = [
+        Question(id=uuid4(), segment_id=segment.id, question_text=f"Q{i}?", correct_answer="A", order_index=i)
+        for i in range(2)
+    ]
+    test_session.add(presenter)
+    await test_session.flush()
+    test_session.add(segment)
+    await test_session.flush()
+    test_session.add_all(questions)
+    await test_session.commit()
+
+    session = await hub.get_or_create_session(test_event.id)
+    state = session.game_state
+    state.current_segment_id = segment.id
+    state.current_presenter_id = presenter.id
+    state.questions = [
+        {"id": q.id, "text": q.question_text, "correct_answer": "A", "fake_answers": ["B"]}
+        for q in questions
+    ]
+    state.total_questions = 2
+    state.current_question_index = 0
+    state.current_question_id = questions[0].id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.question_started_at = datetime.now(timezone.utc)
+    viewer = AsyncMock()
+    session.connections[uuid4()] = viewer
+
+    try:
+        assert await _handle_presenter_disconnect(test_session, test_event.id, presenter.id) is True
+        assert state.presenter_paused is True
+        sent = [call.args[0]["type"] for call in viewer.send_json.call_args_list]
+        assert "presenter_disconnected" in sent
+
+        error = await advance_question(test_session, test_event.id, test_user.id)
+
+        assert error is None
+        assert state.current_question_index == 1
+        assert state.quiz_phase == QuizPhase.SHOWING_QUESTION
+        assert state.presenter_paused is False
+        assert state.current_presenter_id == test_user.id
+        await test_session.refresh(segment)
+        assert segment.presenter_user_id == test_user.id
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
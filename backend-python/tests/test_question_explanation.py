@@ -0,0 +1,60 @@
+"""Tests for question explanations used in post-reveal review."""
+
+import uuid
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import UUID
+
+import pytest
+
+from app.models import Event
+from app.routes.segments import _generate_questions_for_transcript
+from app.services.ai.base import GeneratedQuestion
+
+
+@pytest.mark.anyio
+async def test_generated_question_explanation_is_stored_and_returned(client, test_session):
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Review"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = UUID(segment.json()["id"])
+
+    mock_event = MagicMock(spec=Event)
+    mock_event.questions_to_generate = 1
+    mock_settings = MagicMock()
+    mock_settings.default_ai_provider = "openai"
+    generated = GeneratedQuestion(
+        question_text="What language was discussed?",
+        correct_answer="Python",
+        fake_answers=["Ruby", "Go", "Rust"],
+        source_transcript="We talked about Python all afternoon.",
+        explanation="The speaker spent the talk on Python.",
+    )
+
+    with patch("app.services.ai.OpenAIProvider") as MockProvider:
+        MockProvider.return_value.generate_questions_batch = AsyncMock(return_value=[generated])
+        questions = await _generate_questions_for_transcript(
+            db=AsyncMock(),
+            segment_id=segment_id,
+            transcript_text="We talked about Python all afternoon.",
+            event=mock_event,
+            settings=mock_settings,
+        )
+
+    assert questions[0].explanation == "The speaker spent the talk on Python."
+    test_session.add(questions[0])
+    await test_session.commit()
+
+    response = await client.get(f"/api/questions/{questions[0].id}", headers=headers)
+
+    assert response.status_code == 200
+    assert response.json()["correct_answer"] == "Python"
+    assert response.json()["explanation"] == "The speaker spent the talk on Python."
@@ -0,0 +1,77 @@
+"""Tests for exporting a segment's questions."""
+
+import csv
+import io
+import json
+import uuid
+from uuid import uuid4
+
+import pytest
+
+from app.auth import create_access_token
+from app.models import Question, Segment
+
+
+async def _segment_with_questions(test_session, test_event) -> Segment:
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    test_session.add(segment)
+    await test_session.flush()
+    test_session.add_all([
+        Question(
+            id=uuid4(),
+            segment_id=segment.id,
+            question_text='Which city, famously, has "the Eye"?\nPick one.',
+            correct_answer="London",
+            fake_answers=["Paris", "Rome, Italy"],
+            order_index=0,
+            quality_score=0.9,
+        ),
+        Question(
+            id=uuid4(), segment_id=segment.id, question_text="2+2?", correct_answer="4", order_index=1
+        ),
+    ])
+    await test_session.commit()
+    return segment
+
+
+@pytest.mark.anyio
+async def test_csv_export_quotes_commas_and_newlines(client, test_session, test_event, test_user):
+    segment = await _segment_with_questions(test_session, test_event)
+
+    response = await client.get(
+        f"/api/segments/{segment.id}/questions/export",
+        params={"format": "csv"},
+        headers={"Authorization": f"Bearer {create_access_token(test_user.id, test_user.role)}"},
+    )
+
+    assert response.status_code == 200
+    assert response.headers["content-disposition"] == (
+        f'attachment; filename="segment_{segment.id}_questions.csv"'
+    )
+    rows = list(csv.DictReader(io.StringIO(response.text)))
+    assert [row["question_text"] for row in rows] == [
+        'Which city, famously, has "the Eye"?\nPick one.',
+        "2+2?",
+    ]
+    assert json.loads(rows[0]["fake_answers"]) == ["Paris", "Rome, Italy"]
+    assert (rows[0]["correct_answer"], rows[0]["order_index"], rows[0]["quality_score"]) == (
+        "London",
+        "0",
+        "0.9",
+    )
+
+
+@pytest.mark.anyio
+async def test_export_is_limited_to_host_and_presenter(client, test_session, test_event):
+    segment = await _segment_with_questions(test_session, test_event)
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"user_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+
+    response = await client.get(
+        f"/api/segments/{segment.id}/questions/export",
+        headers={"Authorization": f"Bearer {register.json()['token']}"},
+    )
+
+    assert response.status_code == 404
@@ -71,7 +71,8 @@ async def test_generate_questions_batch_mode(mock_db, mock_event, mock_settings)
     mock_provider_instance.generate_questions_batch.assert_called_once_with(
         transcript=transcript,
         num_questions=5,
-        existing_questions=[]
+        existing_questions=[],
+        notes=None,
     )
 
 
@@ -185,7 +186,168 @@ async def test_generate_questions_respects_event_setting(mock_db, mock_settings)
     mock_provider_instance.generate_questions_batch.assert_called_once_with(
         transcript=transcript,
         num_questions=10,
-        existing_questions=[]
+        existing_questions=[],
+        notes=None,
     )
     assert len(result) == 10
 
+
+
+@pytest.mark.asyncio
+async def test_generation_paused_during_manual_question_cooldown(mock_db, mock_event, mock_settings):
+    """Test no questions are generated until the cooldown after a manual question elapses."""
+    from datetime import datetime, timedelta, timezone
+
+    mock_settings.manual_question_generation_cooldown_seconds = 120
+    transcript = "This is a test transcript about Python programming and web development."
+    generated = [
+        GeneratedQuestion(
+            question_text="What language?",
+            correct_answer="Python",
+            fake_answers=["Ruby", "Go", "Rust"],
+            source_transcript=transcript,
+        )
+    ]
+
+    with patch('app.services.ai.OpenAIProvider') as MockProvider:
+        mock_provider_instance = MockProvider.return_value
+        mock_provider_instance.generate_questions_batch = AsyncMock(return_value=generated)
+
+        within_cooldown = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=mock_event,
+            settings=mock_settings,
+            last_manual_question_at=datetime.now(timezone.utc) - timedelta(seconds=30),
+        )
+        assert within_cooldown == []
+        mock_provider_instance.generate_questions_batch.assert_not_called()
+
+        after_cooldown = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=mock_event,
+            settings=mock_settings,
+            last_manual_question_at=datetime.now(timezone.utc) - timedelta(seconds=121),
+        )
+        assert len(after_cooldown) == 1
+
+
+@pytest.mark.asyncio
+async def test_per_event_quality_scoring_controls_low_quality_questions(mock_db, mock_settings):
+    """Test a low-quality question is kept with scoring off and dropped with it on."""
+    from app.routes.segments import _quality_scoring_enabled
+    from app.services.ai.base import QualityAssessment
+
+    mock_settings.enable_ai_quality_scoring = True
+    mock_settings.ai_quality_min_score = 0.6
+    transcript = "This is a test transcript about Python programming and web development."
+    low_quality = GeneratedQuestion(
+        question_text="What?",
+        correct_answer="Python",
+        fake_answers=["Ruby", "Go", "Rust"],
+        source_transcript=transcript,
+    )
+    assessment = QualityAssessment(
+        clarity_score=0.1,
+        answerability_score=0.2,
+        factual_accuracy_score=0.3,
+        overall_score=0.2,
+        issues=["vague"],
+    )
+
+    lenient_event = MagicMock(spec=Event)
+    lenient_event.questions_to_generate = 1
+    lenient_event.enable_ai_quality_scoring = False
+    strict_event = MagicMock(spec=Event)
+    strict_event.questions_to_generate = 1
+    strict_event.enable_ai_quality_scoring = True
+    strict_event.question_quality_threshold = None
+
+    with patch('app.services.ai.OpenAIProvider') as MockProvider:
+        provider = MockProvider.return_value
+        provider.generate_questions_batch = AsyncMock(return_value=[low_quality])
+        provider.analyze_and_generate_question = AsyncMock(return_value=None)
+        provider.evaluate_question_quality = AsyncMock(return_value=assessment)
+
+        kept = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=lenient_event,
+            settings=mock_settings,
+            quality_scoring=_quality_scoring_enabled(lenient_event, mock_settings),
+        )
+        rejected = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=strict_event,
+            settings=mock_settings,
+            quality_scoring=_quality_scoring_enabled(strict_event, mock_settings),
+        )
+
+    assert len(kept) == 1
+    assert rejected == []
+    provider.evaluate_question_quality.assert_called_once()
+
+
+@pytest.mark.asyncio
+async def test_per_event_quality_threshold_overrides_global(mock_db, mock_settings):
+    """Test an event's own threshold decides which scored questions are kept."""
+    from app.routes.segments import _quality_threshold
+    from app.services.ai.base import QualityAssessment
+
+    mock_settings.ai_quality_min_score = 0.6
+    transcript = "This is a test transcript about Python programming and web development."
+    borderline = GeneratedQuestion(
+        question_text="Which language was discussed?",
+        correct_answer="Python",
+        fake_answers=["Ruby", "Go", "Rust"],
+        source_transcript=transcript,
+    )
+    assessment = QualityAssessment(
+        clarity_score=0.5,
+        answerability_score=0.5,
+        factual_accuracy_score=0.5,
+        overall_score=0.5,
+        issues=[],
+    )
+
+    default_event = MagicMock(spec=Event)
+    default_event.questions_to_generate = 1
+    default_event.question_quality_threshold = None
+    technical_event = MagicMock(spec=Event)
+    technical_event.questions_to_generate = 1
+    technical_event.question_quality_threshold = 0.4
+
+    assert _quality_threshold(default_event, mock_settings) == 0.6
+    assert _quality_threshold(technical_event, mock_settings) == 0.4
+
+    with patch('app.services.ai.OpenAIProvider') as MockProvider:
+        provider = MockProvider.return_value
+        provider.generate_questions_batch = AsyncMock(return_value=[borderline])
+        provider.analyze_and_generate_question = AsyncMock(return_value=None)
+        provider.evaluate_question_quality = AsyncMock(return_value=assessment)
+
+        rejected = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=default_event,
+            settings=mock_settings,
+            quality_scoring=True,
+        )
+        kept = await _generate_questions_for_transcript(
+            db=mock_db,
+            segment_id=uuid4(),
+            transcript_text=transcript,
+            event=technical_event,
+            settings=mock_settings,
+            quality_scoring=True,
+        )
+
+    assert rejected == []
+    assert [q.quality_score for q in kept] == [0.5]
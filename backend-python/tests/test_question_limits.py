@@ -0,0 +1,73 @@
+"""Tests for question and answer length limits."""
+
+import uuid
+
+import pytest
+
+from app.config import get_settings
+from app.services.question_limits import QuestionTooLongError, enforce_question_limits
+
+
+async def _setup_segment(client) -> tuple[dict[str, str], str]:
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Limits"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    return headers, segment.json()["id"]
+
+
+def test_over_length_text_is_truncated(monkeypatch):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "question_length_policy", "truncate")
+    monkeypatch.setattr(settings, "max_question_text_length", 20)
+    monkeypatch.setattr(settings, "max_answer_length", 10)
+
+    question_text, correct_answer, fake_answers = enforce_question_limits(
+        "Q" * 50, "A" * 30, ["short", "F" * 30]
+    )
+
+    assert len(question_text) == 20
+    assert question_text.endswith("…")
+    assert len(correct_answer) == 10
+    assert fake_answers[0] == "short"
+    assert len(fake_answers[1]) == 10
+
+
+def test_over_length_text_is_rejected_under_reject_policy(monkeypatch):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "question_length_policy", "reject")
+    monkeypatch.setattr(settings, "max_question_text_length", 20)
+
+    with pytest.raises(QuestionTooLongError):
+        enforce_question_limits("Q" * 50, "A")
+
+
+@pytest.mark.anyio
+async def test_manual_create_applies_configured_policy(client, monkeypatch):
+    settings = get_settings()
+    monkeypatch.setattr(settings, "max_question_text_length", 20)
+    headers, segment_id = await _setup_segment(client)
+
+    monkeypatch.setattr(settings, "question_length_policy", "truncate")
+    response = await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Q" * 50, "correct_answer": "A", "fake_answers": ["B"]},
+        headers=headers,
+    )
+    assert response.status_code == 201
+    assert len(response.json()["question_text"]) == 20
+
+    monkeypatch.setattr(settings, "question_length_policy", "reject")
+    response = await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Q" * 50, "correct_answer": "A", "fake_answers": ["B"]},
+        headers=headers,
+    )
+    assert response.status_code == 422
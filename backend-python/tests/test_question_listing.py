@@ -0,0 +1,65 @@
+"""Tests for filtering and paginating a segment's questions."""
+
+import uuid
+from uuid import UUID
+
+import pytest
+
+from app.models import Question
+
+
+@pytest.mark.anyio
+async def test_manual_filter_excludes_generated_questions(client, test_session):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Listing"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    manual_ids = []
+    for i in range(3):
+        created = await client.post(
+            f"/api/segments/{segment_id}/questions",
+            json={"question_text": f"Manual {i}?", "correct_answer": "A", "fake_answers": ["B", "C"]},
+            headers=headers,
+        )
+        manual_ids.append(created.json()["id"])
+    test_session.add_all([
+        Question(
+            segment_id=UUID(segment_id),
+            question_text=f"Generated {i}?",
+            correct_answer="A",
+            order_index=10 + i,
+            is_ai_generated=True,
+        )
+        for i in range(2)
+    ])
+    await test_session.commit()
+
+    manual = await client.get(
+        f"/api/segments/{segment_id}/questions", params={"origin": "manual"}, headers=headers
+    )
+    assert manual.status_code == 200
+    assert manual.json()["total"] == 3
+    assert [q["id"] for q in manual.json()["items"]] == manual_ids
+
+    generated = await client.get(
+        f"/api/segments/{segment_id}/questions", params={"origin": "generated"}, headers=headers
+    )
+    assert generated.json()["total"] == 2
+    assert all(q["is_ai_generated"] for q in generated.json()["items"])
+
+    page = await client.get(
+        f"/api/segments/{segment_id}/questions", params={"limit": 2, "offset": 2}, headers=headers
+    )
+    body = page.json()
+    assert body["total"] == 5
+    assert (body["limit"], body["offset"]) == (2, 2)
+    assert [q["question_text"] for q in body["items"]] == ["Manual 2?", "Generated 0?"]
@@ -0,0 +1,75 @@
+"""Tests for question tags, per-tag accuracy and category leaderboards."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.auth import create_access_token
+from app.models import EventParticipant, ParticipantAnswer, Question, Segment
+from app.services.question_tags import normalize_tags
+
+
+def test_tags_are_trimmed_lowercased_and_deduplicated():
+    assert normalize_tags([" History ", "history", "World  War", ""]) == ["history", "world war"]
+    assert normalize_tags(["  "]) is None
+
+
+@pytest.mark.anyio
+async def test_tag_accuracy_and_category_leaderboard(client, test_session, test_event, test_user):
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    history = Question(
+        id=uuid4(), segment_id=segment.id, question_text="1066?", correct_answer="Hastings",
+        tags=["history"], order_index=0,
+    )
+    both = Question(
+        id=uuid4(), segment_id=segment.id, question_text="Who wrote On Computable Numbers?",
+        correct_answer="Turing", tags=["history", "science"], order_index=1,
+    )
+    untagged = Question(
+        id=uuid4(), segment_id=segment.id, question_text="2+2?", correct_answer="4", order_index=2
+    )
+    ada, bob = (
+        EventParticipant(id=uuid4(), event_id=test_event.id, display_name=name, device_id=uuid4())
+        for name in ("Ada", "Bob")
+    )
+    test_session.add_all([segment, history, both, untagged, ada, bob])
+    await test_session.flush()
+
+    def answer(question, participant, is_correct: bool, points: int) -> ParticipantAnswer:
+        return ParticipantAnswer(
+            event_id=test_event.id,
+            segment_id=segment.id,
+            question_id=question.id,
+            participant_id=participant.id,
+            selected_answer=question.correct_answer if is_correct else "?",
+            is_correct=is_correct,
+            points=points,
+            response_time_ms=1000,
+            answered_at=datetime.now(timezone.utc),
+        )
+
+    test_session.add_all([
+        answer(history, ada, True, 900),
+        answer(history, bob, False, 0),
+        answer(both, ada, True, 800),
+        answer(both, bob, True, 700),
+        answer(untagged, bob, True, 1000),
+    ])
+    await test_session.commit()
+
+    response = await client.get(
+        f"/api/events/{test_event.id}/tags/accuracy",
+        headers={"Authorization": f"Bearer {create_access_token(test_user.id, test_user.role)}"},
+    )
+
+    assert response.status_code == 200
+    assert response.json() == [
+        {"tag": "history", "question_count": 2, "answer_count": 4, "correct_count": 3, "accuracy": 0.75},
+        {"tag": "science", "question_count": 1, "answer_count": 2, "correct_count": 2, "accuracy": 1.0},
+    ]
+
+    leaderboard = await client.get(
+        f"/api/events/{test_event.id}/leaderboard", params={"tag": "History"}
+    )
+    assert [(e["username"], e["score"]) for e in leaderboard.json()] == [("Ada", 1700), ("Bob", 700)]
@@ -0,0 +1,62 @@
+"""Tests for per-question time limit overrides."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question, Segment, SegmentStatus
+from app.ws.game_handler import advance_question, start_event_game
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo
+
+
+def _question_time_limits(websocket: AsyncMock) -> list[int]:
+    return [
+        call.args[0]["time_limit"]
+        for call in websocket.send_json.call_args_list
+        if call.args[0]["type"] == "question"
+    ]
+
+
+@pytest.mark.anyio
+async def test_override_is_broadcast_while_siblings_use_event_default(
+    test_session, test_user, test_event
+):
+    test_event.time_per_question = 20
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Host",
+        presenter_user_id=test_user.id,
+        status=SegmentStatus.QUIZ_READY.value,
+    )
+    test_session.add(segment)
+    await test_session.flush()
+    test_session.add_all([
+        Question(
+            id=uuid4(), segment_id=segment.id, question_text="Hard?", correct_answer="A",
+            fake_answers=["B"], order_index=0, time_limit_seconds=60,
+        ),
+        Question(
+            id=uuid4(), segment_id=segment.id, question_text="Easy?", correct_answer="A",
+            fake_answers=["B"], order_index=1,
+        ),
+    ])
+    await test_session.commit()
+
+    player_id = uuid4()
+    player = AsyncMock()
+    session = await hub.connect(test_event.id, player_id, player)
+    session.game_state.participants[player_id] = ParticipantInfo(user_id=player_id, username="Player")
+
+    try:
+        assert await start_event_game(test_session, test_event.id, test_user.id) is None
+        assert session.game_state.time_limit_seconds == 60
+
+        assert await advance_question(test_session, test_event.id, test_user.id) is None
+        assert session.game_state.time_limit_seconds == 20
+
+        assert _question_time_limits(player) == [60, 20]
+    finally:
+        await hub.close_session(test_event.id)
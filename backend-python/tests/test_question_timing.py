@@ -0,0 +1,76 @@
+"""Tests for per-question response timing histograms."""
+
+import uuid
+from uuid import UUID, uuid4
+
+import pytest
+
+from app.models import EventParticipant, ParticipantAnswer
+
+
+@pytest.mark.anyio
+async def test_timing_histogram_and_percentiles(client, test_session):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Timing"}, headers=headers)
+    event_id = UUID(event.json()["id"])
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions", json={"presenter_name": "Presenter"}, headers=headers
+    )
+    segment_id = UUID(segment.json()["id"])
+    question = await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "2 + 2?", "correct_answer": "4"},
+        headers=headers,
+    )
+    question_id = UUID(question.json()["id"])
+
+    for response_time_ms in [4100, 500, 1800, 1200, 2500, None]:
+        participant = EventParticipant(
+            id=uuid4(), event_id=event_id, device_id=uuid4(), display_name="Player"
+        )
+        test_session.add(participant)
+        await test_session.flush()
+        test_session.add(
+            ParticipantAnswer(
+                id=uuid4(),
+                event_id=event_id,
+                segment_id=segment_id,
+                question_id=question_id,
+                participant_id=participant.id,
+                selected_answer="4",
+                is_correct=True,
+                points=100,
+                response_time_ms=response_time_ms,
+            )
+        )
+    await test_session.commit()
+
+    response = await client.get(f"/api/questions/{question_id}/timing?bucket_ms=1000", headers=headers)
+
+    assert response.status_code == 200
+    timing = response.json()
+    assert timing["response_count"] == 5
+    assert [(b["start_ms"], b["count"]) for b in timing["buckets"]] == [
+        (0, 1),
+        (1000, 2),
+        (2000, 1),
+        (3000, 0),
+        (4000, 1),
+    ]
+    assert timing["median_ms"] == 1800
+    # Interpolated between the 4th (2500) and 5th (4100) fastest responses
+    assert timing["p90_ms"] == pytest.approx(3460)
+
+    other = await client.post(
+        "/api/auth/register",
+        json={"username": f"other_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    forbidden = await client.get(
+        f"/api/questions/{question_id}/timing",
+        headers={"Authorization": f"Bearer {other.json()['token']}"},
+    )
+    assert forbidden.status_code == 404
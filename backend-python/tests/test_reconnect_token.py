@@ -0,0 +1,48 @@
+"""Tests for WebSocket reconnect tokens issued on join."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.hub import Hub
+
+
+@pytest.mark.anyio
+async def test_reconnect_token_restores_session():
+    hub = Hub()
+    event_id = uuid4()
+    presenter_id = uuid4()
+
+    await hub.connect(event_id, presenter_id, AsyncMock())
+    session = await hub.get_or_create_session(event_id)
+    session.game_state.current_presenter_id = presenter_id
+    token = hub.issue_reconnect_token(event_id, presenter_id)
+
+    await hub.disconnect(event_id, presenter_id)
+
+    assert hub.redeem_reconnect_token(event_id, token) == presenter_id
+    await hub.reconnect(event_id, presenter_id, AsyncMock())
+
+    assert hub.get_connection_state(event_id, presenter_id) == "connected"
+    assert session.game_state.current_presenter_id == presenter_id
+    # Tokens are single use
+    assert hub.redeem_reconnect_token(event_id, token) is None
+
+    await hub.disconnect(event_id, presenter_id, permanent=True)
+
+
+def test_expired_or_invalid_reconnect_token_rejected():
+    hub = Hub()
+    event_id = uuid4()
+    user_id = uuid4()
+
+    token = hub.issue_reconnect_token(event_id, user_id)
+    hub.reconnect_tokens[token].expires_at = datetime.now(timezone.utc) - timedelta(seconds=1)
+
+    assert hub.redeem_reconnect_token(event_id, token) is None
+    assert hub.redeem_reconnect_token(event_id, "not-a-token") is None
+
+    other_token = hub.issue_reconnect_token(event_id, user_id)
+    assert hub.redeem_reconnect_token(uuid4(), other_token) is None
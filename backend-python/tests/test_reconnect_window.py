@@ -0,0 +1,64 @@
+"""Tests for the reconnect window before a dropped participant counts as left."""
+
+import asyncio
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.config import get_settings
+from app.ws.game_handler import _announce_participant_left
+from app.ws.hub import hub
+
+
+def _sent_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+async def _drop(event_id, user_id):
+    await hub.disconnect(event_id, user_id)
+    hub.schedule_departure(
+        event_id, user_id, lambda: _announce_participant_left(event_id, user_id, "Presenter")
+    )
+
+
+@pytest.mark.anyio
+async def test_reconnect_inside_window_is_not_announced(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_reconnect_window_seconds", 0.05)
+    event_id, viewer_id, player_id = uuid4(), uuid4(), uuid4()
+    viewer = AsyncMock()
+    await hub.connect(event_id, viewer_id, viewer)
+    await hub.connect(event_id, player_id, AsyncMock())
+
+    try:
+        await _drop(event_id, player_id)
+        assert hub.get_participant_count(event_id) == 2
+
+        await hub.reconnect(event_id, player_id, AsyncMock())
+        await asyncio.sleep(0.1)
+
+        assert "participant_left" not in _sent_types(viewer)
+        assert hub.get_participant_count(event_id) == 2
+        assert hub.get_connection_state(event_id, player_id) == "connected"
+    finally:
+        await hub.close_session(event_id)
+
+
+@pytest.mark.anyio
+async def test_departure_is_announced_after_window(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_reconnect_window_seconds", 0.05)
+    event_id, viewer_id, player_id = uuid4(), uuid4(), uuid4()
+    viewer = AsyncMock()
+    await hub.connect(event_id, viewer_id, viewer)
+    await hub.connect(event_id, player_id, AsyncMock())
+
+    try:
+        await _drop(event_id, player_id)
+        await asyncio.sleep(0.1)
+
+        assert _sent_types(viewer).count("participant_left") == 1
+        assert hub.get_participant_count(event_id) == 1
+        # A later join without a token is treated as a fresh join
+        assert hub.get_connection_state(event_id, player_id) == "disconnected"
+    finally:
+        await hub.close_session(event_id)
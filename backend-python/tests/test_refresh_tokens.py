@@ -0,0 +1,68 @@
+"""Tests for refresh token rotation and logout."""
+
+import uuid
+
+import pytest
+
+
+async def _register(client) -> dict:
+    response = await client.post(
+        "/api/auth/register",
+        json={"username": f"user_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    assert response.status_code == 201
+    return response.json()
+
+
+@pytest.mark.anyio
+async def test_refresh_rotates_token(client):
+    data = await _register(client)
+    assert data["refresh_token"]
+
+    response = await client.post(
+        "/api/auth/refresh", json={"refresh_token": data["refresh_token"]}
+    )
+    assert response.status_code == 200
+    rotated = response.json()
+    assert rotated["refresh_token"] != data["refresh_token"]
+
+    me = await client.get(
+        "/api/auth/me", headers={"Authorization": f"Bearer {rotated['token']}"}
+    )
+    assert me.status_code == 200
+    assert me.json()["id"] == data["user"]["id"]
+
+    # The used token is spent, and replaying it revokes its replacement too
+    replay = await client.post(
+        "/api/auth/refresh", json={"refresh_token": data["refresh_token"]}
+    )
+    assert replay.status_code == 401
+    after_replay = await client.post(
+        "/api/auth/refresh", json={"refresh_token": rotated["refresh_token"]}
+    )
+    assert after_replay.status_code == 401
+
+
+@pytest.mark.anyio
+async def test_logout_revokes_refresh_token(client):
+    data = await _register(client)
+
+    response = await client.post(
+        "/api/auth/logout", json={"refresh_token": data["refresh_token"]}
+    )
+    assert response.status_code == 204
+
+    refresh = await client.post(
+        "/api/auth/refresh", json={"refresh_token": data["refresh_token"]}
+    )
+    assert refresh.status_code == 401
+
+
+@pytest.mark.anyio
+async def test_refresh_token_is_not_an_access_token(client):
+    data = await _register(client)
+
+    response = await client.get(
+        "/api/auth/me", headers={"Authorization": f"Bearer {data['refresh_token']}"}
+    )
+    assert response.status_code == 401
@@ -0,0 +1,77 @@
+"""Tests for regenerating a segment's question set from its transcript."""
+
+import uuid
+from unittest.mock import patch
+from uuid import UUID
+
+import pytest
+
+from app.models import PresentationTranscript, Question
+
+
+@pytest.mark.anyio
+async def test_regenerate_all_replaces_generated_and_keeps_manual(test_session, client):
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Regenerate"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    manual = await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Manual?", "correct_answer": "Yes", "fake_answers": ["No"]},
+        headers=headers,
+    )
+    manual_id = manual.json()["id"]
+
+    test_session.add_all([
+        Question(
+            segment_id=UUID(segment_id),
+            question_text="Old generated?",
+            correct_answer="Old",
+            order_index=1,
+            is_ai_generated=True,
+        ),
+        PresentationTranscript(
+            segment_id=UUID(segment_id),
+            chunk_text="Edited transcript about the water cycle.",
+            chunk_index=0,
+        ),
+    ])
+    await test_session.commit()
+
+    async def fake_generate(**kwargs):
+        assert kwargs["transcript_text"] == "Edited transcript about the water cycle."
+        return [
+            Question(
+                id=uuid.uuid4(),
+                segment_id=kwargs["segment_id"],
+                question_text="What drives evaporation?",
+                correct_answer="The sun",
+                fake_answers=["The moon", "Wind"],
+                order_index=0,
+                is_ai_generated=True,
+            )
+        ]
+
+    with patch("app.routes.segments._generate_questions_for_transcript", side_effect=fake_generate):
+        response = await client.post(
+            f"/api/segments/{segment_id}/questions/regenerate-all",
+            headers=headers,
+        )
+
+    assert response.status_code == 200
+    assert [q["question_text"] for q in response.json()] == ["What drives evaporation?"]
+
+    questions = await client.get(f"/api/segments/{segment_id}/questions", headers=headers)
+    texts = [q["question_text"] for q in questions.json()["items"]]
+    assert texts == ["Manual?", "What drives evaporation?"]
+    assert questions.json()["items"][0]["id"] == manual_id
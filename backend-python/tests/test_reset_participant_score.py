@@ -0,0 +1,108 @@
+"""Tests for resetting a participant's score."""
+
+import uuid
+from uuid import UUID, uuid4
+
+import pytest
+from sqlalchemy import select
+
+from app.models import EventParticipant, JoinStatus, ParticipantAnswer, Question
+from app.services.scoring import apply_score, current_streak
+
+
+@pytest.mark.anyio
+async def test_reset_score_zeroes_participant_and_drops_rank(client, test_session):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Reset"}, headers=headers)
+    event_id = UUID(event.json()["id"])
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = UUID(segment.json()["id"])
+
+    leader = EventParticipant(
+        id=uuid4(),
+        event_id=event_id,
+        display_name="Leader",
+        device_id=uuid4(),
+        join_status=JoinStatus.ACTIVE_IN_QUIZ.value,
+    )
+    runner_up = EventParticipant(
+        id=uuid4(),
+        event_id=event_id,
+        display_name="Runner Up",
+        device_id=uuid4(),
+        join_status=JoinStatus.ACTIVE_IN_QUIZ.value,
+    )
+    test_session.add_all([leader, runner_up])
+    await test_session.commit()
+    await apply_score(test_session, segment_id, leader.id, 900, True, 1000)
+    await apply_score(test_session, segment_id, runner_up.id, 400, True, 1000)
+    question = Question(
+        id=uuid4(), segment_id=segment_id, question_text="2+2?", correct_answer="4", order_index=0
+    )
+    test_session.add(question)
+    await test_session.flush()
+    test_session.add(
+        ParticipantAnswer(
+            event_id=event_id,
+            segment_id=segment_id,
+            question_id=question.id,
+            participant_id=leader.id,
+            selected_answer="4",
+            is_correct=True,
+            points=900,
+            response_time_ms=1000,
+        )
+    )
+    await test_session.commit()
+
+    response = await client.post(
+        f"/api/events/{event_id}/participants/{leader.id}/reset-score",
+        headers=headers,
+    )
+
+    assert response.status_code == 200
+    assert response.json()["total_score"] == 0
+
+    leaderboard = await client.get(f"/api/events/{event_id}/leaderboard")
+    rankings = leaderboard.json()
+    assert rankings[0]["user_id"] == str(runner_up.id)
+    assert rankings[1]["user_id"] == str(leader.id)
+    assert rankings[1]["score"] == 0
+
+    segment_board = await client.get(f"/api/segments/{segment_id}/leaderboard")
+    scores = {entry["user_id"]: entry["score"] for entry in segment_board.json()}
+    assert scores[str(leader.id)] == 0
+
+    test_session.expire_all()
+    assert await current_streak(test_session, segment_id, leader.id) == 0
+    answers = await test_session.execute(
+        select(ParticipantAnswer).where(ParticipantAnswer.participant_id == leader.id)
+    )
+    # Kept as history, but no longer part of the score
+    assert [(a.selected_answer, a.counts_toward_score) for a in answers.scalars().all()] == [
+        ("4", False)
+    ]
+
+
+@pytest.mark.anyio
+async def test_reset_score_requires_host(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"other_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+
+    response = await client.post(
+        f"/api/events/{uuid4()}/participants/{uuid4()}/reset-score",
+        headers=headers,
+    )
+
+    assert response.status_code == 404
@@ -0,0 +1,103 @@
+"""Tests for starting and ending an event over REST."""
+
+import uuid
+from unittest.mock import AsyncMock
+from uuid import UUID
+
+import pytest
+
+from app.ws.hub import hub
+
+
+async def _setup_ready_segment(client) -> tuple[dict[str, str], str, str]:
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "REST Control"}, headers=headers)
+    event_id = event.json()["id"]
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+    await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Q?", "correct_answer": "A", "fake_answers": ["B", "C"]},
+        headers=headers,
+    )
+    await client.patch(f"/api/segments/{segment_id}", json={"status": "quiz_ready"}, headers=headers)
+    return headers, event_id, segment_id
+
+
+@pytest.mark.anyio
+async def test_rest_start_broadcasts_game_started_to_connected_clients(client):
+    headers, event_id, segment_id = await _setup_ready_segment(client)
+    websocket = AsyncMock()
+    viewer_id = uuid.uuid4()
+    await hub.connect(UUID(event_id), viewer_id, websocket)
+
+    response = await client.post(f"/api/events/{event_id}/start", headers=headers)
+
+    assert response.status_code == 200
+    assert response.json()["segment_id"] == segment_id
+    sent_types = [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+    assert "game_started" in sent_types
+
+    response = await client.post(f"/api/events/{event_id}/end", headers=headers)
+
+    assert response.status_code == 200
+    sent_types = [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+    assert "game_ended" in sent_types
+
+    await hub.disconnect(UUID(event_id), viewer_id, permanent=True)
+    hub.event_sessions.pop(UUID(event_id), None)
+
+
+@pytest.mark.anyio
+async def test_rest_start_runs_a_pending_segment_with_manual_questions(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Manual"}, headers=headers)
+    event_id = event.json()["id"]
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+    assert segment.json()["status"] == "pending"
+    await client.post(
+        f"/api/segments/{segment_id}/questions",
+        json={"question_text": "Q?", "correct_answer": "A", "fake_answers": ["B", "C"]},
+        headers=headers,
+    )
+
+    response = await client.post(f"/api/events/{event_id}/start", headers=headers)
+
+    assert response.status_code == 200
+    assert response.json()["segment_id"] == segment_id
+    started = await client.get(f"/api/events/{event_id}/segments/{segment_id}", headers=headers)
+    assert started.json()["status"] == "quizzing"
+
+    hub.event_sessions.pop(UUID(event_id), None)
+
+
+@pytest.mark.anyio
+async def test_rest_start_requires_host(client):
+    _, event_id, _ = await _setup_ready_segment(client)
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"other_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    other_headers = {"Authorization": f"Bearer {register.json()['token']}"}
+
+    response = await client.post(f"/api/events/{event_id}/start", headers=other_headers)
+
+    assert response.status_code == 403
@@ -0,0 +1,107 @@
+"""Tests for trimming reveal leaderboards to the top N plus changed entries."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Question
+from app.ws.game_handler import (
+    _build_reveal_payload,
+    _compact_reveal_leaderboards,
+    _send_leaderboard_snapshot,
+)
+from app.ws.hub import hub
+
+
+def _board(order: list, scores: dict) -> list[dict]:
+    return [
+        {"rank": idx + 1, "user_id": user_id, "username": str(user_id), "score": scores[user_id]}
+        for idx, user_id in enumerate(order)
+    ]
+
+
+def _reveal(segment_board: list[dict], event_board: list[dict]):
+    reveal = _build_reveal_payload(
+        question=Question(
+            id=uuid4(), segment_id=uuid4(), question_text="Q?", correct_answer="A", order_index=0
+        ),
+        question_index=0,
+        answers=[],
+        options=["A", "B"],
+    )
+    reveal.segment_leaderboard = segment_board
+    reveal.event_leaderboard = event_board
+    return reveal
+
+
+@pytest.mark.anyio
+async def test_later_reveals_only_carry_top_n_and_changed_entries():
+    event_id, segment_id = uuid4(), uuid4()
+    session = await hub.get_or_create_session(event_id)
+    a, b, c, d = uuid4(), uuid4(), uuid4(), uuid4()
+
+    try:
+        first_scores = {a: 400, b: 300, c: 200, d: 100}
+        first_board = _board([a, b, c, d], first_scores)
+        first = _reveal(first_board, first_board)
+        _compact_reveal_leaderboards(first, session, segment_id, top_n=1)
+
+        assert first.segment_leaderboard == first_board
+        assert first.event_leaderboard == first_board
+        assert not first.segment_leaderboard_is_delta and not first.event_leaderboard_is_delta
+
+        # d overtakes c; a and b keep their rank and score
+        second_scores = {a: 400, b: 300, c: 200, d: 250}
+        second_board = _board([a, b, d, c], second_scores)
+        second = _reveal(second_board, second_board)
+        _compact_reveal_leaderboards(second, session, segment_id, top_n=1)
+
+        assert [e["user_id"] for e in second.segment_leaderboard] == [a, d, c]
+        assert [e["user_id"] for e in second.event_leaderboard] == [a, d, c]
+        assert second.segment_leaderboard_is_delta and second.event_leaderboard_is_delta
+
+        # A new segment starts its own baseline while the event board keeps diffing
+        third = _reveal(second_board, second_board)
+        _compact_reveal_leaderboards(third, session, uuid4(), top_n=1)
+
+        assert third.segment_leaderboard == second_board
+        assert not third.segment_leaderboard_is_delta
+        assert [e["user_id"] for e in third.event_leaderboard] == [a]
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_zero_top_n_sends_full_leaderboards():
+    event_id, segment_id = uuid4(), uuid4()
+    session = await hub.get_or_create_session(event_id)
+    users = [uuid4(), uuid4()]
+    board = _board(users, {users[0]: 2, users[1]: 1})
+
+    try:
+        for _ in range(2):
+            reveal = _reveal(board, board)
+            _compact_reveal_leaderboards(reveal, session, segment_id, top_n=0)
+            assert reveal.event_leaderboard == board
+            assert not reveal.event_leaderboard_is_delta
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_snapshot_sends_full_leaderboards(test_session, test_event):
+    session = await hub.get_or_create_session(test_event.id)
+    websocket = AsyncMock()
+
+    try:
+        assert await _send_leaderboard_snapshot(test_session, session, test_event.id, websocket) is None
+        sent = websocket.send_json.call_args.args[0]
+        assert sent["type"] == "leaderboard_snapshot"
+        assert sent["segment_leaderboard"] == []
+
+        session.game_state.final_leaderboard_frozen = True
+        error = await _send_leaderboard_snapshot(test_session, session, test_event.id, websocket)
+        assert error == "The leaderboard is hidden until the host reveals it"
+    finally:
+        hub.event_sessions.pop(test_event.id, None)
@@ -0,0 +1,171 @@
+"""Tests for configurable reveal distribution ordering."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.models import Question, Segment, SegmentStatus
+from app.ws import game_handler
+from app.ws.game_handler import _build_reveal_payload, _send_reveal
+from app.ws.hub import hub
+from app.ws.messages import ParticipantInfo
+
+
+def _question() -> Question:
+    return Question(
+        id=uuid4(),
+        segment_id=uuid4(),
+        question_text="Pick one",
+        correct_answer="A",
+        order_index=0,
+    )
+
+
+def test_reveal_sorted_by_count_when_enabled():
+    answers = ["A", "B", "B", "C", "C", "C"]
+
+    message = _build_reveal_payload(
+        question=_question(),
+        question_index=0,
+        answers=answers,
+        reveal_order="count",
+        options=["A", "B", "C"],
+    )
+
+    assert [d.answer for d in message.distribution] == ["C", "B", "A"]
+    assert [d.count for d in message.distribution] == [3, 2, 1]
+
+
+def test_reveal_keeps_option_order_by_default():
+    answers = ["C", "C", "C", "B", "B", "A"]
+
+    message = _build_reveal_payload(
+        question=_question(),
+        question_index=0,
+        answers=answers,
+        options=["A", "B", "C"],
+    )
+
+    assert [d.answer for d in message.distribution] == ["A", "B", "C"]
+
+
+def test_reveal_groups_answers_differing_in_case_and_whitespace():
+    message = _build_reveal_payload(
+        question=_question(),
+        question_index=0,
+        answers=["Paris", "paris ", "Lyon"],
+        options=["Paris", "Lyon"],
+        normalize_text=True,
+    )
+
+    assert [(d.answer, d.count) for d in message.distribution] == [("Paris", 2), ("Lyon", 1)]
+
+    free_text = _build_reveal_payload(
+        question=_question(), question_index=0, answers=[" new  york", "New York"], normalize_text=True
+    )
+    assert [(d.answer, d.count) for d in free_text.distribution] == [("new york", 2)]
+
+
+@pytest.mark.anyio
+async def test_correct_only_reveal_omits_distribution():
+    event_id = uuid4()
+    viewer_id = uuid4()
+    session = await hub.get_or_create_session(event_id)
+    websocket = AsyncMock()
+    session.connections[viewer_id] = websocket
+    message = _build_reveal_payload(
+        question=_question(), question_index=0, answers=["A", "B"], options=["A", "B"]
+    )
+
+    await _send_reveal(event_id, session, message, {"correct_answer": "A"}, "correct_only")
+
+    payload = websocket.send_json.call_args.args[0]
+    assert payload["type"] == "reveal"
+    assert payload["correct_answer"] == "A"
+    assert payload["distribution"] == []
+
+    hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_per_user_reveal_sends_private_correctness_only():
+    event_id = uuid4()
+    right, wrong = uuid4(), uuid4()
+    session = await hub.get_or_create_session(event_id)
+    sockets = {right: AsyncMock(), wrong: AsyncMock()}
+    for user_id, websocket in sockets.items():
+        session.connections[user_id] = websocket
+        session.game_state.participants[user_id] = ParticipantInfo(
+            user_id=user_id, username=str(user_id)
+        )
+    session.game_state.answers_received = {right: "A", wrong: "B"}
+    message = _build_reveal_payload(
+        question=_question(), question_index=0, answers=["A", "B"], options=["A", "B"]
+    )
+
+    await _send_reveal(event_id, session, message, {"correct_answer": "A"}, "per_user")
+
+    right_payload = sockets[right].send_json.call_args.args[0]
+    wrong_payload = sockets[wrong].send_json.call_args.args[0]
+    assert right_payload["type"] == "answer_result"
+    assert right_payload["is_correct"] is True
+    assert wrong_payload["is_correct"] is False
+    assert "correct_answer" not in wrong_payload
+    assert sockets[right].send_json.call_count == 1
+
+    hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+@pytest.mark.parametrize("rng_seed", [None, 7])
+async def test_reveal_follows_the_option_order_participants_saw(
+    rng_seed, test_engine, test_session, test_user, test_event, monkeypatch
+):
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    test_event.rng_seed = rng_seed
+    segment = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Host",
+        presenter_user_id=test_user.id,
+        order_index=0,
+        status=SegmentStatus.QUIZ_READY.value,
+    )
+    options = ["4", "3", "5", "22", "8", "13"]
+    question = Question(
+        id=uuid4(),
+        segment_id=segment.id,
+        question_text="2+2?",
+        correct_answer="4",
+        fake_answers=options[1:],
+        order_index=0,
+    )
+    test_session.add_all([segment, question])
+    await test_session.commit()
+
+    viewer_id = uuid4()
+    viewer = AsyncMock()
+    session = await hub.connect(test_event.id, viewer_id, viewer)
+    session.game_state.participants[viewer_id] = ParticipantInfo(user_id=viewer_id, username="Player")
+    session.game_state.current_segment_id = segment.id
+
+    try:
+        assert await game_handler.start_event_game(test_session, test_event.id, test_user.id) is None
+        sent = [call.args[0] for call in viewer.send_json.call_args_list]
+        shown = next(message for message in sent if message["type"] == "question")["answers"]
+        assert sorted(shown) == sorted(options)
+
+        session.game_state.answers_received = {uuid4(): option for option in reversed(options)}
+        await game_handler.reveal_current_question(test_event.id, session)
+
+        sent = [call.args[0] for call in viewer.send_json.call_args_list]
+        reveal = next(message for message in sent if message["type"] == "reveal")
+        assert [d["answer"] for d in reveal["distribution"]] == shown
+    finally:
+        await hub.close_session(test_event.id)
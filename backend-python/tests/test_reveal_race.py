@@ -0,0 +1,74 @@
+"""Tests for revealing a question once when several reveal triggers coincide."""
+
+import asyncio
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.config import get_settings
+from app.models import Question, Segment
+from app.ws import game_handler
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+def _sent_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+@pytest.mark.anyio
+async def test_time_up_and_all_answered_reveal_once(test_engine, test_session, test_event, monkeypatch):
+    monkeypatch.setattr(get_settings(), "auto_reveal_on_time_up", True)
+    monkeypatch.setattr(get_settings(), "answer_timeout_grace_ms", 0)
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Host", order_index=0)
+    question = Question(
+        id=uuid4(),
+        segment_id=segment.id,
+        question_text="2+2?",
+        correct_answer="4",
+        fake_answers=["3", "5"],
+        order_index=0,
+    )
+    test_session.add_all([segment, question])
+    await test_session.commit()
+
+    viewer = AsyncMock()
+    await hub.connect(test_event.id, uuid4(), viewer)
+    session = hub.event_sessions[test_event.id]
+    state = session.game_state
+    state.questions = [{"id": question.id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3", "5"]}]
+    state.total_questions = 1
+    state.current_question_id = question.id
+    state.current_segment_id = segment.id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.time_limit_seconds = 1
+    # The timer runs out just as the last answer comes in
+    state.question_started_at = datetime.now(timezone.utc) - timedelta(seconds=1)
+
+    try:
+        game_handler._schedule_question_cues(test_event.id, session)
+        time_up = session.reveal_task
+        # Whichever reveal starts second may be cancelled by the first; neither may error
+        results = await asyncio.gather(
+            time_up,
+            game_handler.reveal_current_question(test_event.id, session),
+            return_exceptions=True,
+        )
+
+        assert not [r for r in results if r is not None and not isinstance(r, asyncio.CancelledError)]
+        assert _sent_types(viewer).count("reveal") == 1
+        assert state.quiz_phase == QuizPhase.REVEALING_ANSWER
+
+        # A late manual reveal of the same question is ignored too
+        await game_handler.reveal_current_question(test_event.id, session)
+        assert _sent_types(viewer).count("reveal") == 1
+    finally:
+        await hub.close_session(test_event.id)
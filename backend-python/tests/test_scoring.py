@@ -5,20 +5,36 @@ from uuid import uuid4
 
 import pytest
 from sqlalchemy import select
+from sqlalchemy.exc import IntegrityError
 
 from app.models import (
     Event,
     EventParticipant,
     JoinStatus,
+    ParticipantAnswer,
+    Question,
     Segment,
     SegmentScore,
     SegmentStatus,
 )
-from app.services.scoring import apply_score
+from app.services.scoring import (
+    apply_score,
+    calculate_numeric_score,
+    calculate_proximity_score,
+    calculate_speed_based_score_with_streak,
+    current_streak,
+    parse_numeric_answer,
+    record_answer,
+    reset_participant_score,
+    revoke_question_scores,
+    wrong_answer_points,
+)
 from app.ws.game_handler import (
     _apply_zero_scores_for_unanswered,
+    _build_reveal_payload,
     _get_event_leaderboard,
     _get_segment_leaderboard,
+    _rank_numeric_guesses,
 )
 from app.ws.hub import hub
 from app.ws.messages import ParticipantInfo
@@ -79,6 +95,250 @@ async def test_apply_score_updates_totals(test_session, test_user):
     assert participant_row.total_response_time_ms == 1500
 
 
+async def _answering_participant(test_session, test_user, join_code: str):
+    event = Event(id=uuid4(), host_id=test_user.id, title="Retry Event", join_code=join_code, status="active")
+    segment = Segment(
+        id=uuid4(), event_id=event.id, presenter_name="Host", status=SegmentStatus.QUIZZING.value
+    )
+    question = Question(
+        id=uuid4(), segment_id=segment.id, question_text="2+2?", correct_answer="4", order_index=0
+    )
+    participant = EventParticipant(
+        id=uuid4(), event_id=event.id, display_name="Retry", device_id=uuid4(), session_token=join_code
+    )
+    test_session.add_all([event, segment, question, participant])
+    await test_session.commit()
+    return event, segment, question, participant
+
+
+def _answer(event, segment, question, participant, selected: str, points: int) -> ParticipantAnswer:
+    return ParticipantAnswer(
+        event_id=event.id,
+        segment_id=segment.id,
+        question_id=question.id,
+        participant_id=participant.id,
+        selected_answer=selected,
+        is_correct=selected == question.correct_answer,
+        points=points,
+        response_time_ms=2000,
+        answered_at=datetime.now(timezone.utc),
+    )
+
+
+def test_streak_bonus_grows_per_correct_answer_up_to_cap():
+    scores = [calculate_speed_based_score_with_streak(10_000, 0, streak) for streak in range(8)]
+
+    assert scores == [1000, 1100, 1200, 1300, 1400, 1500, 1500, 1500]
+
+
+@pytest.mark.anyio
+async def test_apply_score_tracks_and_resets_streak(test_session, test_user):
+    _, segment, _, participant = await _answering_participant(test_session, test_user, "SC-STREAK")
+
+    for _ in range(3):
+        await apply_score(test_session, segment.id, participant.id, 100, True, 1000)
+    assert await current_streak(test_session, segment.id, participant.id) == 3
+
+    await apply_score(test_session, segment.id, participant.id, 0, False, None)
+    assert await current_streak(test_session, segment.id, participant.id) == 0
+
+
+@pytest.mark.anyio
+async def test_record_answer_twice_does_not_inflate_totals(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-RETRY-1"
+    )
+
+    for _ in range(2):
+        await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+        await test_session.commit()
+
+    answers = (
+        await test_session.execute(
+            select(ParticipantAnswer).where(ParticipantAnswer.participant_id == participant.id)
+        )
+    ).scalars().all()
+    score_row = (
+        await test_session.execute(
+            select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+        )
+    ).scalar_one()
+    participant_row = await test_session.get(EventParticipant, participant.id)
+
+    assert len(answers) == 1
+    assert participant_row.total_score == 800
+    assert participant_row.total_response_time_ms == 2000
+    assert (score_row.score, score_row.questions_answered, score_row.questions_correct) == (800, 1, 1)
+
+
+@pytest.mark.anyio
+async def test_second_answer_row_for_a_question_is_rejected(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-UNIQUE"
+    )
+    await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    await test_session.commit()
+
+    test_session.add(_answer(event, segment, question, participant, "5", 0))
+    with pytest.raises(IntegrityError):
+        await test_session.commit()
+    await test_session.rollback()
+
+
+@pytest.mark.anyio
+async def test_record_answer_resubmission_applies_only_the_difference(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-RETRY-2"
+    )
+
+    await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    stored = await record_answer(test_session, _answer(event, segment, question, participant, "5", 0))
+    await test_session.commit()
+
+    score_row = (
+        await test_session.execute(
+            select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+        )
+    ).scalar_one()
+    participant_row = await test_session.get(EventParticipant, participant.id)
+
+    assert (stored.selected_answer, stored.is_correct, stored.points) == ("5", False, 0)
+    assert participant_row.total_score == 0
+    assert (score_row.score, score_row.questions_answered, score_row.questions_correct) == (0, 1, 0)
+
+
+@pytest.mark.anyio
+async def test_answer_after_a_score_reset_is_scored_afresh(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-RESET-1"
+    )
+    await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    await test_session.commit()
+    await reset_participant_score(test_session, participant)
+
+    stored = await record_answer(test_session, _answer(event, segment, question, participant, "5", 0))
+    await test_session.commit()
+
+    score_row = (
+        await test_session.execute(
+            select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+        )
+    ).scalar_one()
+    assert (stored.selected_answer, stored.points, stored.counts_toward_score) == ("5", 0, True)
+    assert participant.total_score == 0
+    assert (score_row.score, score_row.questions_answered, score_row.questions_correct) == (0, 1, 0)
+
+
+@pytest.mark.anyio
+async def test_revoking_an_answer_from_before_a_reset_leaves_totals_alone(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-RESET-2"
+    )
+    await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    await test_session.commit()
+    await reset_participant_score(test_session, participant)
+    await apply_score(test_session, segment.id, participant.id, 300, True, 1000)
+
+    assert await revoke_question_scores(test_session, question.id) == 1
+    await test_session.commit()
+
+    assert participant.total_score == 300
+
+
+def test_wrong_answer_points_only_penalize_when_allowed():
+    assert wrong_answer_points(False, 50) == 0
+    assert wrong_answer_points(True, 50) == -50
+    assert wrong_answer_points(True, 0) == 0
+
+
+@pytest.mark.anyio
+async def test_penalties_clamp_totals_at_zero(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-PENALTY"
+    )
+
+    await apply_score(test_session, segment.id, participant.id, 30, True, 1000)
+    await record_answer(
+        test_session,
+        _answer(event, segment, question, participant, "5", wrong_answer_points(True, 50)),
+    )
+    await test_session.commit()
+
+    score_row = (
+        await test_session.execute(
+            select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+        )
+    ).scalar_one()
+    participant_row = await test_session.get(EventParticipant, participant.id)
+
+    assert (score_row.score, participant_row.total_score) == (0, 0)
+
+    await apply_score(test_session, segment.id, participant.id, 40, True, 1000)
+    assert (score_row.score, participant_row.total_score) == (40, 40)
+
+
+async def _clamped_penalty(test_session, test_user, join_code: str):
+    """Bank 30 points, then take a 50-point penalty that clamps at zero."""
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, join_code
+    )
+    await apply_score(test_session, segment.id, participant.id, 30, True, 1000)
+    stored = await record_answer(
+        test_session,
+        _answer(event, segment, question, participant, "5", wrong_answer_points(True, 50)),
+    )
+    await test_session.commit()
+    score_row = (
+        await test_session.execute(
+            select(SegmentScore).where(SegmentScore.participant_id == participant.id)
+        )
+    ).scalar_one()
+    participant_row = await test_session.get(EventParticipant, participant.id)
+    return event, segment, question, participant, stored, score_row, participant_row
+
+
+@pytest.mark.anyio
+async def test_revoking_a_clamped_penalty_restores_only_what_was_lost(test_session, test_user):
+    _, _, question, _, stored, score_row, participant_row = await _clamped_penalty(
+        test_session, test_user, "SC-CLAMP-1"
+    )
+    assert stored.points == -30
+
+    await revoke_question_scores(test_session, question.id)
+    await test_session.commit()
+
+    assert (score_row.score, participant_row.total_score) == (30, 30)
+
+
+@pytest.mark.anyio
+async def test_revoking_a_question_restarts_the_streak(test_session, test_user):
+    event, segment, question, participant = await _answering_participant(
+        test_session, test_user, "SC-REVOKE-STREAK"
+    )
+    await apply_score(test_session, segment.id, participant.id, 100, True, 1000)
+    await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    await test_session.commit()
+    assert await current_streak(test_session, segment.id, participant.id) == 2
+
+    await revoke_question_scores(test_session, question.id)
+    await test_session.commit()
+
+    assert await current_streak(test_session, segment.id, participant.id) == 0
+
+
+@pytest.mark.anyio
+async def test_changing_a_clamped_answer_adds_only_the_new_points(test_session, test_user):
+    event, segment, question, participant, _, score_row, participant_row = await _clamped_penalty(
+        test_session, test_user, "SC-CLAMP-2"
+    )
+
+    stored = await record_answer(test_session, _answer(event, segment, question, participant, "4", 800))
+    await test_session.commit()
+
+    assert stored.points == 800
+    assert (score_row.score, participant_row.total_score) == (830, 830)
+
+
 @pytest.mark.anyio
 async def test_zero_fill_marks_waiting_and_is_idempotent(test_session, test_user):
     hub.event_sessions.clear()
@@ -250,3 +510,159 @@ async def test_leaderboard_orders_by_score_then_time(test_session, test_user):
     assert event_lb[0]["user_id"] == fast.id
     assert event_lb[1]["user_id"] == slow.id
 
+
+
+def test_numeric_answer_within_tolerance_scores_full():
+    score, is_correct = calculate_numeric_score(
+        guess=37_000_000,
+        target=37_400_000,
+        tolerance=5,
+        mode="percent",
+        base_score=800,
+        partial_credit=True,
+    )
+    assert is_correct is True
+    assert score == 800
+
+
+def test_numeric_answer_outside_tolerance_scores_partial_or_zero():
+    partial, is_correct = calculate_numeric_score(
+        guess=115, target=100, tolerance=10, mode="absolute", base_score=1000, partial_credit=True
+    )
+    assert is_correct is False
+    assert 0 < partial < 1000
+
+    zero, _ = calculate_numeric_score(
+        guess=115, target=100, tolerance=10, mode="absolute", base_score=1000, partial_credit=False
+    )
+    assert zero == 0
+
+
+def test_parse_numeric_answer_rejects_non_numeric_input():
+    assert parse_numeric_answer("37,400,000") == 37_400_000
+    assert parse_numeric_answer(" 37.4m ") == 37_400_000
+    assert parse_numeric_answer("about a lot") is None
+    assert parse_numeric_answer("") is None
+
+
+def test_closest_guess_earns_most_points_and_reveal_orders_by_distance():
+    target = 1000.0
+    guesses = {uuid4(): "1,300", uuid4(): "990", uuid4(): "1.1k"}
+    scores = {
+        user_id: calculate_proximity_score(parse_numeric_answer(raw), target, base_score=1000)
+        for user_id, raw in guesses.items()
+    }
+    closest, middle, farthest = list(guesses)[1], list(guesses)[2], list(guesses)[0]
+    assert scores[closest] > scores[middle] > scores[farthest]
+
+    question = Question(
+        id=uuid4(),
+        segment_id=uuid4(),
+        question_text="How many?",
+        correct_answer="1000",
+        order_index=0,
+        question_type="numeric",
+    )
+    message = _build_reveal_payload(
+        question=question,
+        question_index=0,
+        answers=guesses.values(),
+        numeric_target=target,
+    )
+    assert [d.answer for d in message.distribution] == ["990", "1100", "1300"]
+    assert [d.distance for d in message.distribution] == [10, 100, 300]
+
+    ranked = _rank_numeric_guesses(guesses, target)
+    assert [g.user_id for g in ranked] == [closest, middle, farthest]
+
+
+@pytest.mark.anyio
+async def test_event_leaderboard_applies_segment_weights(test_session, test_user):
+    hub.event_sessions.clear()
+    event = Event(
+        id=uuid4(),
+        host_id=test_user.id,
+        title="Weighted Event",
+        join_code="SC-WT-1",
+        status="active",
+    )
+    opener = Segment(id=uuid4(), event_id=event.id, presenter_name="Opener", weight=1.0)
+    finale = Segment(id=uuid4(), event_id=event.id, presenter_name="Finale", weight=2.0, order_index=1)
+    early = EventParticipant(
+        id=uuid4(),
+        event_id=event.id,
+        display_name="Early",
+        device_id=uuid4(),
+        session_token="token-early",
+        join_status=JoinStatus.ACTIVE_IN_QUIZ.value,
+    )
+    closer = EventParticipant(
+        id=uuid4(),
+        event_id=event.id,
+        display_name="Closer",
+        device_id=uuid4(),
+        session_token="token-closer",
+        join_status=JoinStatus.ACTIVE_IN_QUIZ.value,
+    )
+    test_session.add_all([event, opener, finale, early, closer])
+    await test_session.commit()
+
+    # Early leads on flat totals (900 vs 800) but the finale counts double
+    await apply_score(test_session, opener.id, early.id, 700, True, 1000)
+    await apply_score(test_session, finale.id, early.id, 200, True, 1000)
+    await apply_score(test_session, opener.id, closer.id, 200, True, 1000)
+    await apply_score(test_session, finale.id, closer.id, 600, True, 1000)
+
+    event_lb = await _get_event_leaderboard(test_session, event.id)
+
+    assert [entry["user_id"] for entry in event_lb] == [closer.id, early.id]
+    assert event_lb[0]["score"] == 1400
+    assert event_lb[1]["score"] == 1100
+
+
+@pytest.mark.anyio
+async def test_bonus_question_doubles_points_at_same_response_time():
+    from datetime import timedelta
+    from types import SimpleNamespace
+    from unittest.mock import AsyncMock, MagicMock, patch
+
+    from app.ws.game_handler import _score_answer_submission
+    from app.ws.hub import GameState
+
+    started_at = datetime.now(timezone.utc)
+    submitted_at = started_at + timedelta(seconds=5)
+
+    async def score_with_multiplier(multiplier: float) -> int:
+        state = GameState(
+            questions=[{
+                "id": uuid4(),
+                "text": "Bonus?",
+                "correct_answer": "A",
+                "fake_answers": ["B"],
+                "bonus_multiplier": multiplier,
+            }],
+            current_question_index=0,
+            question_started_at=started_at,
+            time_limit_seconds=30,
+            current_segment_id=uuid4(),
+        )
+        with patch(
+            "app.ws.game_handler.record_answer", new=AsyncMock(side_effect=lambda db, answer: answer)
+        ) as mock_record, patch(
+            "app.ws.game_handler.current_streak", new=AsyncMock(return_value=0)
+        ):
+            scored = await _score_answer_submission(
+                MagicMock(),
+                session=SimpleNamespace(event_id=uuid4(), game_state=state),
+                participant_id=uuid4(),
+                selected_answer="A",
+                submitted_at=submitted_at,
+            )
+        assert scored
+        return mock_record.call_args.args[1].points
+
+    normal = await score_with_multiplier(1.0)
+    bonus = await score_with_multiplier(2.0)
+
+    assert normal > 0
+    assert bonus == normal * 2
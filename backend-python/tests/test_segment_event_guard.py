@@ -0,0 +1,66 @@
+"""Tests that segment routes verify the segment belongs to the claimed event."""
+
+import uuid
+
+import pytest
+
+
+async def _register(client) -> dict[str, str]:
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    return {"Authorization": f"Bearer {register.json()['token']}"}
+
+
+@pytest.mark.anyio
+async def test_segment_from_other_event_returns_404(client):
+    headers = await _register(client)
+    event_a = await client.post("/api/quizzes", json={"title": "Event A"}, headers=headers)
+    event_b = await client.post("/api/quizzes", json={"title": "Event B"}, headers=headers)
+    event_a_id = event_a.json()["id"]
+    event_b_id = event_b.json()["id"]
+    segment = await client.post(
+        f"/api/quizzes/{event_a_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    response = await client.get(f"/api/events/{event_b_id}/segments/{segment_id}", headers=headers)
+    assert response.status_code == 404
+
+    response = await client.put(
+        f"/api/quizzes/{event_b_id}/questions/{segment_id}",
+        json={"title": "Hijacked"},
+        headers=headers,
+    )
+    assert response.status_code == 404
+
+    response = await client.delete(f"/api/quizzes/{event_b_id}/questions/{segment_id}", headers=headers)
+    assert response.status_code == 404
+
+    # The segment is untouched under its real event
+    response = await client.get(f"/api/events/{event_a_id}/segments/{segment_id}", headers=headers)
+    assert response.status_code == 200
+    assert response.json()["title"] is None
+
+
+@pytest.mark.anyio
+async def test_segment_update_by_non_host_returns_403(client):
+    headers = await _register(client)
+    other_headers = await _register(client)
+    event = await client.post("/api/quizzes", json={"title": "Event"}, headers=headers)
+    event_id = event.json()["id"]
+    segment = await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+
+    response = await client.put(
+        f"/api/quizzes/{event_id}/questions/{segment.json()['id']}",
+        json={"title": "Hijacked"},
+        headers=other_headers,
+    )
+    assert response.status_code == 403
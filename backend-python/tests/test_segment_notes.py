@@ -0,0 +1,78 @@
+"""Tests for using presenter slide notes as question generation context."""
+
+import json
+import uuid
+from types import SimpleNamespace
+from unittest.mock import MagicMock
+
+import pytest
+
+from app.services.ai import ClaudeProvider, OpenAIProvider
+
+TRANSCRIPT = "Today we covered how the Eiffel Tower was built for the 1889 World's Fair in Paris."
+NOTES = "Slide 3: Gustave Eiffel's company designed and built the tower."
+
+
+@pytest.mark.anyio
+async def test_notes_are_sent_separately_from_transcript():
+    prompts = []
+
+    async def fake_create(**kwargs):
+        prompts.append(kwargs["messages"][0]["content"])
+        return SimpleNamespace(content=[SimpleNamespace(text=json.dumps({"skip": True}))])
+
+    provider = ClaudeProvider(api_key="test-key")
+    provider.client = MagicMock()
+    provider.client.messages.create = fake_create
+
+    await provider.analyze_and_generate_question(transcript=TRANSCRIPT, notes=NOTES)
+    await provider.analyze_and_generate_question(transcript=TRANSCRIPT)
+
+    with_notes, without_notes = prompts
+    assert TRANSCRIPT in with_notes
+    assert f"<notes>\n{NOTES}\n</notes>" in with_notes
+    assert with_notes.index(TRANSCRIPT) < with_notes.index("<notes>")
+    assert "<notes>" not in without_notes
+
+
+@pytest.mark.anyio
+async def test_batch_generation_includes_notes():
+    captured = {}
+
+    async def fake_create(**kwargs):
+        captured["prompt"] = kwargs["messages"][0]["content"]
+        return SimpleNamespace(
+            choices=[SimpleNamespace(message=SimpleNamespace(content='{"questions": []}'))]
+        )
+
+    provider = OpenAIProvider(api_key="test-key")
+    provider.client = MagicMock()
+    provider.client.chat.completions.create = fake_create
+
+    await provider.generate_questions_batch(transcript=TRANSCRIPT, num_questions=2, notes=NOTES)
+
+    assert TRANSCRIPT in captured["prompt"]
+    assert f"<notes>\n{NOTES}\n</notes>" in captured["prompt"]
+
+
+@pytest.mark.anyio
+async def test_segment_notes_round_trip(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Slides"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter", "notes": NOTES},
+        headers=headers,
+    )
+    assert segment.json()["notes"] == NOTES
+
+    cleared = await client.patch(
+        f"/api/segments/{segment.json()['id']}",
+        json={"notes": ""},
+        headers=headers,
+    )
+    assert cleared.json()["notes"] is None
@@ -0,0 +1,66 @@
+"""Tests for segment status transition validation."""
+
+import uuid
+
+import pytest
+
+from app.models import SegmentStatus, can_transition_segment
+
+
+async def _auth_headers(client) -> dict[str, str]:
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    response = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    return {"Authorization": f"Bearer {response.json()['token']}"}
+
+
+async def _create_segment(client, headers) -> str:
+    event = await client.post("/api/quizzes", json={"title": "Transitions"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    return segment.json()["id"]
+
+
+def test_valid_transitions_are_allowed():
+    assert SegmentStatus.PENDING.can_transition_to(SegmentStatus.RECORDING)
+    assert SegmentStatus.RECORDING.can_transition_to(SegmentStatus.QUIZ_READY)
+    assert SegmentStatus.QUIZ_READY.can_transition_to(SegmentStatus.QUIZZING)
+    assert SegmentStatus.PENDING.can_transition_to(SegmentStatus.QUIZZING)
+    assert SegmentStatus.QUIZZING.can_transition_to(SegmentStatus.COMPLETED)
+    # Re-applying the current status is a no-op
+    assert SegmentStatus.RECORDING.can_transition_to(SegmentStatus.RECORDING)
+
+
+def test_completed_cannot_return_to_recording():
+    assert not SegmentStatus.COMPLETED.can_transition_to(SegmentStatus.RECORDING)
+    assert not can_transition_segment("completed", "recording")
+    assert not can_transition_segment("pending", "not-a-status")
+
+
+@pytest.mark.anyio
+async def test_start_recording_succeeds_from_pending(client):
+    headers = await _auth_headers(client)
+    segment_id = await _create_segment(client, headers)
+
+    response = await client.post(f"/api/segments/{segment_id}/recording/start", headers=headers)
+
+    assert response.status_code == 200
+    assert response.json()["status"] == SegmentStatus.RECORDING.value
+
+
+@pytest.mark.anyio
+async def test_start_recording_rejected_for_completed_segment(client):
+    headers = await _auth_headers(client)
+    segment_id = await _create_segment(client, headers)
+
+    complete = await client.post(f"/api/segments/{segment_id}/complete", headers=headers)
+    assert complete.status_code == 200
+
+    response = await client.post(f"/api/segments/{segment_id}/recording/start", headers=headers)
+
+    assert response.status_code == 409
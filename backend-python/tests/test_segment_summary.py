@@ -0,0 +1,48 @@
+"""Tests for the segment transcript summary endpoint."""
+
+import uuid
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import UUID
+
+import pytest
+
+from app.models import PresentationTranscript
+
+
+@pytest.mark.anyio
+async def test_summary_uses_full_transcript_and_is_cached(test_session, client):
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Summary Event"}, headers=headers)
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    test_session.add_all([
+        PresentationTranscript(segment_id=UUID(segment_id), chunk_text="Second part.", chunk_index=1),
+        PresentationTranscript(segment_id=UUID(segment_id), chunk_text="First part.", chunk_index=0),
+    ])
+    await test_session.commit()
+
+    provider = MagicMock()
+    provider.summarize_transcript = AsyncMock(return_value="A short summary.")
+    with patch("app.services.ai.OpenAIProvider", return_value=provider), \
+            patch("app.services.ai.ClaudeProvider", return_value=provider):
+        first = await client.post(f"/api/segments/{segment_id}/summary", headers=headers)
+        assert first.status_code == 200
+        assert first.json()["summary"] == "A short summary."
+        assert first.json()["cached"] is False
+        provider.summarize_transcript.assert_awaited_once_with("First part. Second part.")
+
+        second = await client.post(f"/api/segments/{segment_id}/summary", headers=headers)
+        assert second.status_code == 200
+        assert second.json()["summary"] == "A short summary."
+        assert second.json()["cached"] is True
+        assert provider.summarize_transcript.await_count == 1
@@ -0,0 +1,48 @@
+"""Tests for audience-specific segment responses."""
+
+import uuid
+
+import pytest
+
+
+@pytest.mark.anyio
+async def test_participant_segment_list_omits_host_only_fields(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post("/api/quizzes", json={"title": "Audiences"}, headers=headers)
+    event_id = event.json()["id"]
+    await client.post(
+        f"/api/quizzes/{event_id}/questions",
+        json={"presenter_name": "Presenter", "title": "Opening"},
+        headers=headers,
+    )
+    joined = await client.post(
+        "/api/events/join",
+        json={
+            "code": event.json()["join_code"],
+            "device_fingerprint": str(uuid.uuid4()),
+            "display_name": "Viewer",
+        },
+    )
+    session_token = joined.json()["sessionToken"]
+
+    host_view = await client.get(f"/api/events/{event_id}/segments", headers=headers)
+    assert host_view.status_code == 200
+    assert "presenter_user_id" in host_view.json()[0]
+    assert "can_advance" in host_view.json()[0]
+
+    participant_view = await client.get(
+        f"/api/events/{event_id}/segments", headers={"X-Session-Token": session_token}
+    )
+    assert participant_view.status_code == 200
+    segment = participant_view.json()[0]
+    assert segment["presenter_name"] == "Presenter"
+    assert segment["title"] == "Opening"
+    for host_only in ("presenter_user_id", "can_advance", "store_transcripts", "previous_status"):
+        assert host_only not in segment
+
+    anonymous = await client.get(f"/api/events/{event_id}/segments")
+    assert anonymous.status_code == 403
@@ -0,0 +1,61 @@
+"""Tests for ordering segment winners in the event_complete payload."""
+
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant, Segment, SegmentScore, SegmentStatus, SegmentWinnerOrder
+from app.ws.game_handler import _get_segment_winners
+
+
+async def _completed_segments(test_session, test_event, winning_scores: list[int]) -> None:
+    player = EventParticipant(
+        id=uuid4(), event_id=test_event.id, device_id=uuid4(), display_name="Player"
+    )
+    test_session.add(player)
+    for index, score in enumerate(winning_scores):
+        segment = Segment(
+            id=uuid4(),
+            event_id=test_event.id,
+            presenter_name=f"Presenter {index}",
+            title=f"Segment {index}",
+            order_index=index,
+            status=SegmentStatus.COMPLETED.value,
+        )
+        test_session.add(segment)
+        await test_session.flush()
+        test_session.add(
+            SegmentScore(id=uuid4(), segment_id=segment.id, participant_id=player.id, score=score)
+        )
+    # A completed segment nobody scored in has no winner
+    test_session.add(
+        Segment(
+            id=uuid4(),
+            event_id=test_event.id,
+            presenter_name="Quiet",
+            order_index=len(winning_scores),
+            status=SegmentStatus.COMPLETED.value,
+        )
+    )
+    await test_session.commit()
+
+
+@pytest.mark.anyio
+async def test_segment_winners_follow_segment_order_by_default(test_session, test_event):
+    await _completed_segments(test_session, test_event, [200, 500, 300])
+
+    winners = await _get_segment_winners(test_session, test_event.id)
+
+    assert [w.segment_title for w in winners] == ["Segment 0", "Segment 1", "Segment 2"]
+
+
+@pytest.mark.anyio
+async def test_segment_winners_by_highest_score(test_session, test_event):
+    await _completed_segments(test_session, test_event, [200, 500, 300])
+    test_event.segment_winner_order = SegmentWinnerOrder.SCORE.value
+    await test_session.commit()
+
+    winners = await _get_segment_winners(test_session, test_event.id)
+
+    assert [w.winner_score for w in winners] == [500, 300, 200]
+    assert [w.segment_title for w in winners] == ["Segment 1", "Segment 2", "Segment 0"]
@@ -0,0 +1,82 @@
+"""Tests for team mode and aggregated team scores."""
+
+import uuid
+from uuid import uuid4
+
+import pytest
+
+from app.models import EventParticipant, Segment, SegmentScore, Team
+from app.ws.game_handler import _get_team_leaderboard
+
+
+@pytest.mark.anyio
+async def test_team_scores_sum_members_and_rank(test_session, test_event):
+    segment = Segment(id=uuid4(), event_id=test_event.id, presenter_name="Presenter", weight=2.0)
+    owls = Team(id=uuid4(), event_id=test_event.id, name="Owls")
+    foxes = Team(id=uuid4(), event_id=test_event.id, name="Foxes")
+    empty = Team(id=uuid4(), event_id=test_event.id, name="Badgers")
+    test_session.add_all([segment, owls, foxes, empty])
+    await test_session.flush()
+
+    members = {
+        "owl_1": (owls.id, 300),
+        "owl_2": (owls.id, 200),
+        "fox_1": (foxes.id, 450),
+        "solo": (None, 900),
+    }
+    for name, (team_id, score) in members.items():
+        participant = EventParticipant(
+            id=uuid4(),
+            event_id=test_event.id,
+            device_id=uuid4(),
+            display_name=name,
+            team_id=team_id,
+        )
+        test_session.add(participant)
+        await test_session.flush()
+        test_session.add(
+            SegmentScore(id=uuid4(), segment_id=segment.id, participant_id=participant.id, score=score)
+        )
+    await test_session.commit()
+
+    segment_standings = await _get_team_leaderboard(test_session, test_event.id, segment.id)
+    assert [(t["name"], t["score"], t["rank"]) for t in segment_standings] == [
+        ("Owls", 500, 1),
+        ("Foxes", 450, 2),
+        ("Badgers", 0, 3),
+    ]
+
+    # Event standings use the weighted segment scores, like the individual leaderboard
+    event_standings = await _get_team_leaderboard(test_session, test_event.id)
+    assert [(t["name"], t["score"]) for t in event_standings] == [
+        ("Owls", 1000),
+        ("Foxes", 900),
+        ("Badgers", 0),
+    ]
+
+
+@pytest.mark.anyio
+async def test_teams_can_only_be_added_in_team_mode(client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+
+    solo = await client.post("/api/quizzes", json={"title": "Solo"}, headers=headers)
+    rejected = await client.post(
+        f"/api/events/{solo.json()['id']}/teams", json={"name": "Owls"}, headers=headers
+    )
+    assert rejected.status_code == 400
+
+    teams = await client.post(
+        "/api/quizzes", json={"title": "Teams", "team_mode": True}, headers=headers
+    )
+    event_id = teams.json()["id"]
+    created = await client.post(f"/api/events/{event_id}/teams", json={"name": "Owls"}, headers=headers)
+    assert created.status_code == 201
+    duplicate = await client.post(f"/api/events/{event_id}/teams", json={"name": "Owls"}, headers=headers)
+    assert duplicate.status_code == 409
+
+    listed = await client.get(f"/api/events/{event_id}/teams")
+    assert [team["name"] for team in listed.json()] == ["Owls"]
@@ -0,0 +1,45 @@
+"""Tests for the question timer display mode."""
+
+from datetime import datetime, timezone
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _build_question_payload
+
+
+@pytest.mark.parametrize("mode", ["count_down", "count_up"])
+def test_question_carries_start_and_limit_in_either_mode(mode):
+    started_at = datetime(2025, 1, 1, 12, 0, tzinfo=timezone.utc)
+
+    message = _build_question_payload(
+        question_id=uuid4(),
+        question_text="Capital of France?",
+        correct_answer="Paris",
+        fake_answers=["Lyon", "Nice"],
+        total_questions=3,
+        time_limit=20,
+        index=0,
+        question_started_at=started_at,
+        timer_display_mode=mode,
+    ).model_dump()
+
+    assert message["timer_display_mode"] == mode
+    assert message["question_started_at"] == started_at
+    assert message["time_limit"] == 20
+
+
+def test_question_without_start_reference_uses_broadcast_time():
+    before = datetime.now(timezone.utc)
+    message = _build_question_payload(
+        question_id=uuid4(),
+        question_text="2 + 2?",
+        correct_answer="4",
+        fake_answers=["3"],
+        total_questions=1,
+        time_limit=30,
+        index=0,
+    )
+
+    assert message.question_started_at >= before
+    assert message.timer_display_mode == "count_down"
@@ -0,0 +1,76 @@
+"""Tests for masking PII in transcripts before storage and AI generation."""
+
+import uuid
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import UUID
+
+import pytest
+from sqlalchemy import select
+
+from app.models import PresentationTranscript, Question
+from app.services.redaction import redact_pii
+
+
+def test_redact_pii_masks_emails_and_phone_numbers():
+    text = "Write to jane.doe@example.com or call +1 (555) 123-4567 about the 1889 fair."
+
+    assert redact_pii(text) == "Write to [email] or call [phone] about the 1889 fair."
+
+
+@pytest.mark.anyio
+async def test_transcript_is_redacted_before_storage_and_ai(test_session, client):
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": f"host_{uuid.uuid4().hex[:8]}", "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes",
+        json={"title": "Regulated Talk", "redact_pii": True},
+        headers=headers,
+    )
+    assert event.json()["redact_pii"] is True
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter", "notes": "Questions to jane.doe@example.com"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    whisper = MagicMock()
+    whisper.transcribe_audio = AsyncMock(
+        return_value="The Eiffel Tower was completed in 1889. Email jane.doe@example.com for the slides."
+    )
+    ai_bound = {}
+
+    async def fake_generation(segment_id, transcript_text, event, settings, *args):
+        ai_bound["transcript"] = transcript_text
+        ai_bound["notes"] = args[-1]
+        return [
+            Question(
+                id=uuid.uuid4(),
+                segment_id=segment_id,
+                question_text="When was the Eiffel Tower completed?",
+                correct_answer="1889",
+                source_transcript=transcript_text,
+                order_index=0,
+            )
+        ]
+
+    with patch("app.services.transcription.WhisperTranscriptionService", return_value=whisper), \
+            patch("app.routes.segments._run_question_generation", side_effect=fake_generation):
+        response = await client.post(
+            f"/api/segments/{segment_id}/transcribe",
+            files={"audio_file": ("talk.webm", b"fake audio", "audio/webm")},
+            headers=headers,
+        )
+
+    assert response.status_code == 200
+    expected = "The Eiffel Tower was completed in 1889. Email [email] for the slides."
+    stored = await test_session.scalar(
+        select(PresentationTranscript.chunk_text).where(
+            PresentationTranscript.segment_id == UUID(segment_id)
+        )
+    )
+    assert stored == expected
+    assert ai_bound == {"transcript": expected, "notes": "Questions to [email]"}
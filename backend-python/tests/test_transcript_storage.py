@@ -0,0 +1,73 @@
+"""Tests for the transcript storage privacy toggle."""
+
+import uuid
+from unittest.mock import AsyncMock, MagicMock, patch
+from uuid import UUID
+
+import pytest
+from sqlalchemy import func, select
+
+from app.models import PresentationTranscript, Question
+
+
+@pytest.mark.anyio
+async def test_transcripts_not_stored_when_disabled(test_session, client):
+    username = f"host_{uuid.uuid4().hex[:8]}"
+    register = await client.post(
+        "/api/auth/register",
+        json={"username": username, "password": "testpass123"},
+    )
+    headers = {"Authorization": f"Bearer {register.json()['token']}"}
+    event = await client.post(
+        "/api/quizzes",
+        json={"title": "Private Talk", "store_transcripts": False},
+        headers=headers,
+    )
+    assert event.json()["store_transcripts"] is False
+    segment = await client.post(
+        f"/api/quizzes/{event.json()['id']}/questions",
+        json={"presenter_name": "Presenter"},
+        headers=headers,
+    )
+    segment_id = segment.json()["id"]
+
+    transcript_text = "The Eiffel Tower was completed in 1889 for the World's Fair in Paris."
+    whisper = MagicMock()
+    whisper.transcribe_audio = AsyncMock(return_value=transcript_text)
+
+    async def fake_generate(**kwargs):
+        assert kwargs["transcript_text"] == transcript_text
+        return [
+            Question(
+                id=uuid.uuid4(),
+                segment_id=kwargs["segment_id"],
+                question_text="When was the Eiffel Tower completed?",
+                correct_answer="1889",
+                source_transcript=transcript_text,
+                order_index=0,
+            )
+        ]
+
+    with patch("app.services.transcription.WhisperTranscriptionService", return_value=whisper), \
+            patch("app.routes.segments._generate_questions_for_transcript", side_effect=fake_generate):
+        response = await client.post(
+            f"/api/segments/{segment_id}/transcribe",
+            files={"audio_file": ("talk.webm", b"fake audio", "audio/webm")},
+            headers=headers,
+        )
+
+    assert response.status_code == 200
+    assert response.json()["questions_generated"] == 1
+
+    transcript_count = await test_session.scalar(
+        select(func.count()).select_from(PresentationTranscript).where(
+            PresentationTranscript.segment_id == UUID(segment_id)
+        )
+    )
+    assert transcript_count == 0
+
+    stored = (await test_session.execute(
+        select(Question).where(Question.segment_id == UUID(segment_id))
+    )).scalars().all()
+    assert len(stored) == 1
+    assert stored[0].source_transcript is None
@@ -0,0 +1,77 @@
+"""Tests for retrying and buffering transcript inserts that fail."""
+
+from contextlib import asynccontextmanager
+from uuid import uuid4
+
+import pytest
+from sqlalchemy.exc import OperationalError
+
+from app.config import get_settings
+from app.services import transcript_store
+from app.services.transcript_store import flush_pending_transcripts, store_transcript
+
+
+class FlakySession:
+    """Stands in for AsyncSession; the first ``failures`` savepoint commits raise."""
+
+    def __init__(self, failures: int):
+        self.failures = failures
+        self.staged = None
+        self.persisted = []
+
+    def add(self, obj):
+        self.staged = obj
+
+    @asynccontextmanager
+    async def begin_nested(self):
+        yield
+        if self.failures:
+            self.failures -= 1
+            raise OperationalError("INSERT INTO transcripts", {}, Exception("connection reset"))
+        self.persisted.append(self.staged)
+
+
+@pytest.fixture(autouse=True)
+def _fast_retries(monkeypatch):
+    monkeypatch.setattr(transcript_store, "_pending", {})
+    monkeypatch.setattr(get_settings(), "transcript_store_retry_delay_ms", 0)
+
+
+@pytest.mark.anyio
+async def test_failed_insert_is_retried(monkeypatch):
+    monkeypatch.setattr(get_settings(), "transcript_store_retries", 1)
+    db = FlakySession(failures=1)
+
+    assert await store_transcript(db, uuid4(), "The tower opened in 1889.") is True
+
+    assert [chunk.chunk_text for chunk in db.persisted] == ["The tower opened in 1889."]
+
+
+@pytest.mark.anyio
+async def test_chunk_buffered_until_next_store(monkeypatch):
+    monkeypatch.setattr(get_settings(), "transcript_store_retries", 0)
+    segment_id = uuid4()
+    db = FlakySession(failures=1)
+
+    assert await store_transcript(db, segment_id, "First chunk.", chunk_index=0) is False
+    assert db.persisted == []
+
+    assert await store_transcript(db, segment_id, "Second chunk.", chunk_index=1) is True
+
+    assert [(c.chunk_index, c.chunk_text) for c in db.persisted] == [
+        (0, "First chunk."),
+        (1, "Second chunk."),
+    ]
+    assert transcript_store._pending == {}
+
+
+@pytest.mark.anyio
+async def test_flush_retries_buffer_before_reads(monkeypatch):
+    monkeypatch.setattr(get_settings(), "transcript_store_retries", 0)
+    segment_id = uuid4()
+    db = FlakySession(failures=1)
+    await store_transcript(db, segment_id, "Buffered chunk.")
+
+    assert await flush_pending_transcripts(db, segment_id) is True
+
+    assert [c.chunk_text for c in db.persisted] == ["Buffered chunk."]
@@ -0,0 +1,57 @@
+"""Tests for the next-presenter preview broadcast."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.models import Segment, SegmentStatus
+from app.ws.game_handler import _announce_upcoming_presenter
+from app.ws.hub import hub
+
+
+@pytest.mark.anyio
+async def test_final_question_broadcasts_next_segment_presenter(test_session, test_user, test_event):
+    current = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Current Presenter",
+        order_index=0,
+        status=SegmentStatus.QUIZZING.value,
+    )
+    finished = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Already Done",
+        order_index=1,
+        status=SegmentStatus.COMPLETED.value,
+    )
+    upcoming = Segment(
+        id=uuid4(),
+        event_id=test_event.id,
+        presenter_name="Next Presenter",
+        presenter_user_id=test_user.id,
+        title="Next Up",
+        order_index=2,
+    )
+    test_session.add_all([current, finished, upcoming])
+    await test_session.commit()
+
+    websocket = AsyncMock()
+    viewer_id = uuid4()
+    await hub.connect(test_event.id, viewer_id, websocket)
+
+    # Not the final question yet: nothing announced
+    await _announce_upcoming_presenter(test_session, test_event.id, current, 1, 3)
+    assert websocket.send_json.call_count == 0
+
+    await _announce_upcoming_presenter(test_session, test_event.id, current, 2, 3)
+
+    message = websocket.send_json.call_args.args[0]
+    assert message["type"] == "upcoming_presenter"
+    assert message["segment_id"] == upcoming.id
+    assert message["presenter_name"] == "Next Presenter"
+    assert message["presenter_id"] == test_user.id
+
+    await hub.disconnect(test_event.id, viewer_id, permanent=True)
+    hub.event_sessions.pop(test_event.id, None)
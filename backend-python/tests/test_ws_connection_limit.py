@@ -0,0 +1,63 @@
+"""Tests for the server-wide cap on open WebSocket connections."""
+
+import asyncio
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from fastapi import WebSocketDisconnect
+
+from app.config import get_settings
+from app.ws import game_handler
+from app.ws.hub import hub
+
+
+def _idle_socket() -> tuple[AsyncMock, asyncio.Event]:
+    """A socket that never sends anything until ``hang_up`` is set."""
+    hang_up = asyncio.Event()
+    websocket = AsyncMock()
+    websocket.scope = {"extensions": {"websocket.http.response": {}}}
+
+    async def receive_json():
+        await hang_up.wait()
+        raise WebSocketDisconnect(code=1000)
+
+    websocket.receive_json.side_effect = receive_json
+    return websocket, hang_up
+
+
+@pytest.mark.anyio
+async def test_upgrades_past_the_cap_are_refused_until_one_disconnects(monkeypatch):
+    monkeypatch.setattr(get_settings(), "ws_max_connections", 2)
+    monkeypatch.setattr(hub, "open_sockets", 0)
+    event_id = str(uuid4())
+
+    sockets = [_idle_socket() for _ in range(2)]
+    handlers = [
+        asyncio.create_task(game_handler.websocket_event(websocket, event_id))
+        for websocket, _ in sockets
+    ]
+    try:
+        while hub.open_sockets < 2:
+            await asyncio.sleep(0.01)
+
+        refused, _ = _idle_socket()
+        await game_handler.websocket_event(refused, event_id)
+        refused.accept.assert_not_awaited()
+        denial = refused.send_denial_response.await_args.args[0]
+        assert denial.status_code == 503
+
+        sockets[0][1].set()
+        await asyncio.wait_for(handlers[0], timeout=1)
+        assert hub.open_sockets == 1
+
+        admitted, hang_up = _idle_socket()
+        handlers.append(asyncio.create_task(game_handler.websocket_event(admitted, event_id)))
+        while hub.open_sockets < 2:
+            await asyncio.sleep(0.01)
+        admitted.accept.assert_awaited_once()
+        hang_up.set()
+    finally:
+        for _, hang_up in sockets:
+            hang_up.set()
+        await asyncio.gather(*handlers, return_exceptions=True)
@@ -0,0 +1,129 @@
+"""Tests for tying a WebSocket join to the socket's authenticated identity."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+from fastapi import WebSocketDisconnect
+from sqlalchemy.ext.asyncio import AsyncSession, async_sessionmaker
+
+from app.auth import create_access_token
+from app.models import EventParticipant
+from app.ws import game_handler
+from app.ws.hub import hub
+
+
+def _sent_types(websocket: AsyncMock) -> list[str]:
+    return [call.args[0]["type"] for call in websocket.send_json.call_args_list]
+
+
+@pytest.mark.anyio
+async def test_join_as_another_user_is_rejected(test_engine, test_session, test_event, test_user, monkeypatch):
+    victim = EventParticipant(
+        id=uuid4(), event_id=test_event.id, device_id=uuid4(), display_name="Victim"
+    )
+    test_session.add(victim)
+    await test_session.commit()
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+
+    websocket = AsyncMock()
+    websocket.receive_json.side_effect = [
+        {"type": "join", "user_id": str(victim.id), "session_code": test_event.join_code},
+        WebSocketDisconnect(),
+    ]
+
+    try:
+        await game_handler.websocket_event(
+            websocket,
+            str(test_event.id),
+            token=create_access_token(test_user.id, test_user.role),
+        )
+
+        assert _sent_types(websocket) == ["error"]
+        assert websocket.send_json.call_args.args[0]["message"] == "You can only join as yourself"
+        session = hub.event_sessions.get(test_event.id)
+        assert session is None or victim.id not in session.connections
+    finally:
+        await hub.close_session(test_event.id)
+
+
+@pytest.mark.anyio
+async def test_join_with_own_session_token_is_accepted(test_engine, test_session, test_event, monkeypatch):
+    participant = EventParticipant(
+        id=uuid4(),
+        event_id=test_event.id,
+        device_id=uuid4(),
+        display_name="Player",
+        session_token="player-session-token",
+    )
+    test_session.add(participant)
+    await test_session.commit()
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+
+    websocket = AsyncMock()
+    websocket.receive_json.side_effect = [
+        {"type": "join", "user_id": str(participant.id), "session_code": test_event.join_code},
+        WebSocketDisconnect(),
+    ]
+
+    try:
+        await game_handler.websocket_event(
+            websocket, str(test_event.id), session_token="player-session-token"
+        )
+
+        assert "connected" in _sent_types(websocket)
+    finally:
+        await hub.close_session(test_event.id)
+
+
+async def _join_with(test_engine, test_session, test_event, monkeypatch, **credentials) -> AsyncMock:
+    """Send one join for an existing participant over a socket with the given credentials."""
+    participant = EventParticipant(
+        id=uuid4(), event_id=test_event.id, device_id=uuid4(), display_name="Player"
+    )
+    test_session.add(participant)
+    await test_session.commit()
+    monkeypatch.setattr(
+        game_handler,
+        "async_session_maker",
+        async_sessionmaker(test_engine, class_=AsyncSession, expire_on_commit=False),
+    )
+
+    websocket = AsyncMock()
+    websocket.receive_json.side_effect = [
+        {"type": "join", "user_id": str(participant.id), "session_code": test_event.join_code},
+        WebSocketDisconnect(),
+    ]
+    try:
+        await game_handler.websocket_event(websocket, str(test_event.id), **credentials)
+        session = hub.event_sessions.get(test_event.id)
+        assert session is None or participant.id not in session.connections
+    finally:
+        await hub.close_session(test_event.id)
+    return websocket
+
+
+@pytest.mark.anyio
+async def test_join_without_credentials_is_rejected(test_engine, test_session, test_event, monkeypatch):
+    websocket = await _join_with(test_engine, test_session, test_event, monkeypatch)
+
+    assert _sent_types(websocket) == ["error"]
+    websocket.close.assert_awaited_once_with(code=1008)
+
+
+@pytest.mark.anyio
+async def test_join_with_invalid_token_is_rejected(test_engine, test_session, test_event, monkeypatch):
+    websocket = await _join_with(
+        test_engine, test_session, test_event, monkeypatch, token="not-a-valid-token"
+    )
+
+    assert _sent_types(websocket) == ["error"]
+    websocket.close.assert_awaited_once_with(code=1008)
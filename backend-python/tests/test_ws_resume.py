@@ -0,0 +1,71 @@
+"""Tests for resuming the open question after a reconnect."""
+
+from datetime import datetime, timedelta, timezone
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _send_resume
+from app.ws.hub import hub
+from app.ws.messages import QuizPhase
+
+
+def _sent(websocket: AsyncMock) -> list[dict]:
+    return [call.args[0] for call in websocket.send_json.call_args_list]
+
+
+async def _open_question(event_id, started_seconds_ago: int):
+    session = await hub.get_or_create_session(event_id)
+    state = session.game_state
+    question_id = uuid4()
+    state.questions = [{"id": question_id, "text": "2+2?", "correct_answer": "4", "fake_answers": ["3", "5"]}]
+    state.total_questions = 1
+    state.current_question_id = question_id
+    state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    state.time_limit_seconds = 30
+    state.question_started_at = datetime.now(timezone.utc) - timedelta(seconds=started_seconds_ago)
+    return session, question_id
+
+
+@pytest.mark.anyio
+async def test_resume_mid_question_resends_question_with_remaining_time():
+    event_id = uuid4()
+    _, question_id = await _open_question(event_id, started_seconds_ago=12)
+    participant = uuid4()
+    websocket = AsyncMock()
+
+    try:
+        await _send_resume(event_id, websocket, participant, last_question_id=uuid4())
+
+        resumed, question = _sent(websocket)
+        assert resumed["type"] == "resumed"
+        assert resumed["phase"] == QuizPhase.SHOWING_QUESTION.value
+        assert resumed["has_answered"] is False
+        assert resumed["question_changed"] is True
+        assert question["type"] == "question"
+        assert question["question_id"] == str(question_id)
+        assert 0 < question["time_limit"] <= 18
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_resume_reports_an_existing_answer():
+    event_id = uuid4()
+    _, question_id = await _open_question(event_id, started_seconds_ago=5)
+    participant = uuid4()
+    assert await hub.record_answer(event_id, participant, "4") == (True, None)
+    websocket = AsyncMock()
+
+    try:
+        await _send_resume(event_id, websocket, participant, last_question_id=question_id)
+
+        resumed = _sent(websocket)[0]
+        assert resumed["has_answered"] is True
+        assert resumed["your_answer"] == "4"
+        assert resumed["question_changed"] is False
+        # Answering again after the resume is still refused
+        assert await hub.record_answer(event_id, participant, "3") == (False, "duplicate")
+    finally:
+        hub.event_sessions.pop(event_id, None)
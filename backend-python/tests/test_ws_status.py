@@ -0,0 +1,54 @@
+"""Tests for the get_status WebSocket poll."""
+
+from unittest.mock import AsyncMock
+from uuid import uuid4
+
+import pytest
+
+from app.ws.game_handler import _send_status
+from app.ws.hub import hub
+from app.ws.messages import GetStatusMessage, QuizPhase, parse_client_message
+
+
+def test_get_status_is_parsed():
+    assert isinstance(parse_client_message({"type": "get_status"}), GetStatusMessage)
+
+
+@pytest.mark.anyio
+async def test_status_reflects_hub_state_and_goes_to_requester_only():
+    event_id = uuid4()
+    requester_id, other_id = uuid4(), uuid4()
+    requester, other = AsyncMock(), AsyncMock()
+    session = await hub.get_or_create_session(event_id)
+    session.connections[requester_id] = requester
+    session.connections[other_id] = other
+    session.game_state.quiz_phase = QuizPhase.SHOWING_QUESTION
+    session.game_state.current_question_index = 2
+    session.game_state.total_questions = 5
+
+    try:
+        await _send_status(event_id, requester)
+
+        requester.send_json.assert_called_once_with(
+            {
+                "type": "status",
+                "phase": "showing_question",
+                "participant_count": 2,
+                "question_index": 2,
+                "total_questions": 5,
+            }
+        )
+        other.send_json.assert_not_called()
+    finally:
+        hub.event_sessions.pop(event_id, None)
+
+
+@pytest.mark.anyio
+async def test_status_before_any_session():
+    websocket = AsyncMock()
+
+    await _send_status(uuid4(), websocket)
+
+    payload = websocket.send_json.call_args.args[0]
+    assert payload["phase"] == "not_started"
+    assert payload["participant_count"] == 0
@@ -369,7 +369,15 @@ describe('API Endpoints', () => {
 
       getCanvasStrokes(eventId);
 
-      expect(mockedClient.get).toHaveBeenCalledWith('/events/event-123/canvas');
+      expect(mockedClient.get).toHaveBeenCalledWith('/events/event-123/canvas', { params: undefined });
+    });
+
+    it('getCanvasStrokes should pass the page cursor', () => {
+      getCanvasStrokes('event-123', { after: 'stroke-9', limit: 50 });
+
+      expect(mockedClient.get).toHaveBeenCalledWith('/events/event-123/canvas', {
+        params: { after: 'stroke-9', limit: 50 },
+      });
     });
 
     it('clearCanvas should call client.delete with correct endpoint', () => {
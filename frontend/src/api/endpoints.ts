@@ -15,9 +15,15 @@ export interface LoginRequest {
 
 export interface AuthResponse {
   token: string
+  refresh_token: string
   user: User
 }
 
+export interface RefreshResponse {
+  token: string
+  refresh_token: string
+}
+
 export interface UpdateProfileRequest {
   username?: string
   avatar_url?: string
@@ -32,6 +38,12 @@ export const authAPI = {
   login: (data: LoginRequest) =>
     client.post<AuthResponse>('/auth/login', data),
 
+  refresh: (refreshToken: string) =>
+    client.post<RefreshResponse>('/auth/refresh', { refresh_token: refreshToken }),
+
+  logout: (refreshToken: string) =>
+    client.post('/auth/logout', { refresh_token: refreshToken }),
+
   getMe: () =>
     client.get<User>('/auth/me'),
 
@@ -140,6 +152,8 @@ export type QuizPhase =
   | 'mega_quiz'
   | 'event_complete'
   | 'presenter_paused'
+  | 'buzzing'
+  | 'waiting_for_questions'
 
 // Event endpoints
 export const listEvents = () =>
@@ -332,8 +346,15 @@ export const clearEventResumeState = (eventId: string) =>
   client.post<Event>(`/quizzes/${eventId}/clear-resume`)
 
 // Question endpoints
+export interface QuestionPage {
+  items: Question[]
+  total: number
+  limit: number
+  offset: number
+}
+
 export const getSegmentQuestions = (segmentId: string) =>
-  client.get<Question[]>(`/segments/${segmentId}/questions`)
+  client.get<QuestionPage>(`/segments/${segmentId}/questions`)
 
 export interface CreateQuestionRequest {
   question_text: string
@@ -367,6 +388,9 @@ export interface BulkImportResponse {
 export const bulkImportQuestions = (segmentId: string, data: BulkImportRequest) =>
   client.post<BulkImportResponse>(`/segments/${segmentId}/questions/bulk`, data)
 
+export const importQuizIntoSegment = (segmentId: string, quizId: string) =>
+  client.post<BulkImportResponse>(`/segments/${segmentId}/import-quiz/${quizId}`)
+
 // Leaderboard endpoints
 export const getMasterLeaderboard = (eventId: string) =>
   client.get<LeaderboardEntry[]>(`/events/${eventId}/leaderboard`)
@@ -378,8 +402,23 @@ export const getSegment = (eventId: string, segmentId: string) =>
   client.get<Segment>(`/events/${eventId}/segments/${segmentId}`)
 
 // Canvas endpoints
-export const getCanvasStrokes = (eventId: string) =>
-  client.get(`/events/${eventId}/canvas`)
+export interface CanvasStroke {
+  id: string
+  user_id: string
+  stroke_data: { points: { x: number; y: number }[]; color: string; width: number }
+  stroke_order: number
+  color: string | null
+  created_at: string
+}
+
+export interface CanvasStrokePage {
+  strokes: CanvasStroke[]
+  has_more: boolean
+  next_cursor: string | null
+}
+
+export const getCanvasStrokes = (eventId: string, params?: { after?: string; limit?: number }) =>
+  client.get<CanvasStrokePage>(`/events/${eventId}/canvas`, { params })
 
 export const clearCanvas = (eventId: string) =>
   client.delete(`/events/${eventId}/canvas`)
@@ -433,6 +472,14 @@ export const exportEventResults = async (eventId: string, format: ExportFormat =
   return response
 }
 
+export const exportSegmentQuestions = async (segmentId: string, format: ExportFormat = 'json') => {
+  const response = await client.get(`/segments/${segmentId}/questions/export`, {
+    params: { format },
+    responseType: 'blob',
+  })
+  return response
+}
+
 // Utility to trigger download from blob response
 export const downloadExport = (blob: Blob, filename: string) => {
   const url = URL.createObjectURL(blob)
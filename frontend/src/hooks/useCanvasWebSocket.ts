@@ -18,10 +18,13 @@ export type CanvasServerMessage =
   | { type: 'stroke_added'; user_id: string; username: string; stroke: StrokeData }
   | { type: 'canvas_cleared' }
   | { type: 'canvas_sync'; strokes: StrokeData[] }
+  | { type: 'stroke_removed'; stroke_id: string }
 
 export type CanvasMessage =
   | { type: 'draw_stroke'; stroke: StrokeData }
   | { type: 'clear_canvas' }
+  | { type: 'undo_stroke' }
+  | { type: 'delete_stroke'; stroke_id: string }
 
 interface UseCanvasWebSocketOptions {
   eventId: string
@@ -58,7 +61,12 @@ export function useCanvasWebSocket(options: UseCanvasWebSocketOptions) {
       try {
         const data = JSON.parse(event.data)
         // Filter for canvas messages
-        if (data.type === 'stroke_added' || data.type === 'canvas_cleared' || data.type === 'canvas_sync') {
+        if (
+          data.type === 'stroke_added' ||
+          data.type === 'canvas_cleared' ||
+          data.type === 'canvas_sync' ||
+          data.type === 'stroke_removed'
+        ) {
           const message: CanvasServerMessage = data
           onMessage?.(message)
         }
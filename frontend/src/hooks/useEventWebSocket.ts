@@ -56,6 +56,8 @@ export type QuizPhase =
   | 'mega_quiz'
   | 'event_complete'
   | 'presenter_paused'
+  | 'buzzing'
+  | 'waiting_for_questions'
 
 export type ServerMessage =
   | { type: 'connected'; participants: Participant[] }
@@ -64,9 +66,9 @@ export type ServerMessage =
   | { type: 'participant_name_changed'; user_id: string; old_name: string; new_name: string }
   | { type: 'join_lock_status_changed'; event_id: string; join_locked: boolean; locked_at?: string; message: string }
   | { type: 'game_started' }
-  | { type: 'question'; question_id: string; question_number: number; total_questions: number; text: string; answers: string[]; time_limit: number }
+  | { type: 'question'; question_id: string; question_number: number; total_questions: number; text: string; answers: string[]; time_limit: number; question_started_at?: string; timer_display_mode?: 'count_down' | 'count_up' }
   | { type: 'time_update'; remaining_seconds: number }
-  | { type: 'answer_received'; user_id: string }
+  | { type: 'answer_received'; user_id: string; streak?: number | null; streak_bonus?: number | null }
   | { type: 'reveal'; question_id: string; question_number: number; question_text: string; correct_answer: string; distribution: AnswerDistribution[]; segment_leaderboard: LeaderboardEntry[]; event_leaderboard: LeaderboardEntry[] }
   | { type: 'scores_update'; scores: Array<{ user_id: string; username: string; score: number; delta: number }> }
   | { type: 'leaderboard'; rankings: LeaderboardEntry[] }
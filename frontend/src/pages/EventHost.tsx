@@ -200,7 +200,7 @@ export function EventHostPage() {
       const segmentRes = await getSegment(eventId, segmentId)
       setSegment(segmentRes.data)
 
-      setQuestions(segmentQuestions.data)
+      setQuestions(segmentQuestions.data.items)
     } catch (error) {
       console.error('Failed to load host view:', error)
       navigate('/events')
@@ -404,7 +404,7 @@ export function EventHostPage() {
         setIsGeneratingQuiz(false)
         // Refresh questions
         if (segmentId) {
-          void getSegmentQuestions(segmentId).then((res) => setQuestions(res.data))
+          void getSegmentQuestions(segmentId).then((res) => setQuestions(res.data.items))
         }
       }
     },
@@ -566,7 +566,7 @@ export function EventHostPage() {
       await eventAPI.updateQuestion(questionId, partial)
       if (segmentId) {
         const res = await getSegmentQuestions(segmentId)
-        setQuestions(res.data)
+        setQuestions(res.data.items)
       }
       setEditingQuestionId(null)
     } catch (error) {
@@ -587,7 +587,7 @@ export function EventHostPage() {
   const handleQuestionAdded = async () => {
     if (segmentId) {
       const res = await getSegmentQuestions(segmentId)
-      setQuestions(res.data)
+      setQuestions(res.data.items)
     }
     if (event?.mode === 'listen_only') {
       setShowManualQuestionForm(false)
@@ -1354,7 +1354,7 @@ export function EventHostPage() {
                           <h3 className="text-lg font-semibold text-white">Questions</h3>
                           <Button
                             variant="secondary"
-                            onClick={() => void getSegmentQuestions(segment.id).then((res) => setQuestions(res.data))}
+                            onClick={() => void getSegmentQuestions(segment.id).then((res) => setQuestions(res.data.items))}
                           >
                             Refresh
                           </Button>
@@ -1391,7 +1391,7 @@ export function EventHostPage() {
                   <h2 className="text-lg font-semibold text-white">Generated Questions</h2>
                   <Button
                     variant="secondary"
-                    onClick={() => void getSegmentQuestions(segment.id).then((res) => setQuestions(res.data))}
+                    onClick={() => void getSegmentQuestions(segment.id).then((res) => setQuestions(res.data.items))}
                   >
                     Refresh
                   </Button>
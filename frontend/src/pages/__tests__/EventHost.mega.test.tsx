@@ -138,7 +138,7 @@ describe('EventHost mega quiz handling', () => {
     const endpoints = await import('@/api/endpoints')
     vi.mocked(endpoints.eventAPI.get).mockResolvedValue({ data: mockEvent } as any)
     vi.mocked(endpoints.getSegment).mockResolvedValue({ data: mockSegment } as any)
-    vi.mocked(endpoints.getSegmentQuestions).mockResolvedValue({ data: [] } as any)
+    vi.mocked(endpoints.getSegmentQuestions).mockResolvedValue({ data: { items: [] } } as any)
     vi.mocked(endpoints.getSegmentLeaderboard).mockResolvedValue({ data: [] } as any)
     vi.mocked(endpoints.getMasterLeaderboard).mockResolvedValue({ data: [] } as any)
   })
@@ -177,7 +177,9 @@ describe('EventHost mega quiz handling', () => {
   it('shows pause banner for no participants and disables start controls', async () => {
     const endpoints = await import('@/api/endpoints')
     vi.mocked(endpoints.getSegmentQuestions).mockResolvedValue({
-      data: [{ id: 'q1', question_text: 'Q1', correct_answer: 'A', order_index: 0, segment_id: 'segment-1' }],
+      data: {
+        items: [{ id: 'q1', question_text: 'Q1', correct_answer: 'A', order_index: 0, segment_id: 'segment-1' }],
+      },
     } as any)
 
     renderHost()
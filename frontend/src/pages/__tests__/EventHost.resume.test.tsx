@@ -181,7 +181,7 @@ describe('EventHost Resume Functionality', () => {
     // Set default mock implementations
     vi.mocked(endpoints.eventAPI.get).mockResolvedValue({ data: mockEventWithoutResume } as any)
     vi.mocked(endpoints.getSegment).mockResolvedValue({ data: mockSegmentWithoutResume } as any)
-    vi.mocked(endpoints.getSegmentQuestions).mockResolvedValue({ data: [] } as any)
+    vi.mocked(endpoints.getSegmentQuestions).mockResolvedValue({ data: { items: [] } } as any)
     vi.mocked(endpoints.getSegmentLeaderboard).mockResolvedValue({ data: [] } as any)
     vi.mocked(endpoints.getMasterLeaderboard).mockResolvedValue({ data: [] } as any)
   })